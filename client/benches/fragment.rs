@@ -0,0 +1,19 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+use hazelcast_rust_client::bench::{assemble, frames};
+
+const MAX_FRAME_SIZE: usize = 8 * 1024;
+
+fn assemble_messages(c: &mut Criterion) {
+    let mut group = c.benchmark_group("assemble");
+    for payload_length in [64, 4 * 1024, 64 * 1024, 1024 * 1024].iter() {
+        let frames = frames(*payload_length, MAX_FRAME_SIZE);
+        group.bench_with_input(BenchmarkId::from_parameter(payload_length), &frames, |b, frames| {
+            b.iter(|| assemble(black_box(frames)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, assemble_messages);
+criterion_main!(benches);
@@ -1,6 +1,9 @@
 use std::{convert::TryInto, mem};
 
 use bytes::{Buf, BufMut, Bytes, BytesMut};
+use thiserror::Error;
+
+use crate::HazelcastClientError;
 
 pub(crate) trait Writer {
     fn length(&self) -> usize;
@@ -26,28 +29,56 @@ pub(crate) trait Writeable {
     fn write_slice(&mut self, value: &[u8]);
 }
 
-pub(crate) trait Reader {
-    fn read_from(readable: &mut dyn Readable) -> Self;
+pub(crate) type DecodeResult<T> = std::result::Result<T, DecodeError>;
+
+#[derive(Error, Debug, Eq, PartialEq)]
+#[error("{0}")]
+pub(crate) struct DecodeError(String);
+
+impl DecodeError {
+    pub(crate) fn new(cause: &str) -> Self {
+        DecodeError(cause.to_string())
+    }
+}
+
+impl From<DecodeError> for HazelcastClientError {
+    fn from(e: DecodeError) -> Self {
+        HazelcastClientError::MalformedMessage(Box::new(e))
+    }
+}
+
+pub(crate) trait Reader: Sized {
+    fn read_from(readable: &mut dyn Readable) -> DecodeResult<Self>;
 }
 
 pub(crate) trait Readable {
-    fn read_bool(&mut self) -> bool;
+    fn read_bool(&mut self) -> DecodeResult<bool>;
 
-    fn read_u8(&mut self) -> u8;
+    fn read_u8(&mut self) -> DecodeResult<u8>;
 
-    fn read_u16(&mut self) -> u16;
+    fn read_u16(&mut self) -> DecodeResult<u16>;
 
-    fn read_i32(&mut self) -> i32;
+    fn read_i32(&mut self) -> DecodeResult<i32>;
 
-    fn read_u32(&mut self) -> u32;
+    fn read_u32(&mut self) -> DecodeResult<u32>;
 
-    fn read_i64(&mut self) -> i64;
+    fn read_i64(&mut self) -> DecodeResult<i64>;
 
-    fn read_u64(&mut self) -> u64;
+    fn read_u64(&mut self) -> DecodeResult<u64>;
 
-    fn read_slice(&mut self, len: usize) -> Bytes;
+    fn read_slice(&mut self, len: usize) -> DecodeResult<Bytes>;
 
-    fn skip(&mut self, len: usize);
+    fn skip(&mut self, len: usize) -> DecodeResult<()>;
+
+    fn is_exhausted(&self) -> bool;
+}
+
+// a field added in a later protocol version is only read when the message still carries bytes for it
+pub(crate) fn read_if_present<T: Reader>(readable: &mut dyn Readable) -> DecodeResult<Option<T>> {
+    if readable.is_exhausted() {
+        return Ok(None);
+    }
+    T::read_from(readable).map(Some)
 }
 
 impl Writer for bool {
@@ -70,6 +101,26 @@ impl Writer for u8 {
     }
 }
 
+impl Writer for i8 {
+    fn length(&self) -> usize {
+        mem::size_of::<i8>()
+    }
+
+    fn write_to(&self, writeable: &mut dyn Writeable) {
+        writeable.write_u8(*self as u8);
+    }
+}
+
+impl Writer for i16 {
+    fn length(&self) -> usize {
+        mem::size_of::<i16>()
+    }
+
+    fn write_to(&self, writeable: &mut dyn Writeable) {
+        writeable.write_u16(*self as u16);
+    }
+}
+
 impl Writer for u16 {
     fn length(&self) -> usize {
         mem::size_of::<u16>()
@@ -120,6 +171,26 @@ impl Writer for u64 {
     }
 }
 
+impl Writer for f32 {
+    fn length(&self) -> usize {
+        mem::size_of::<f32>()
+    }
+
+    fn write_to(&self, writeable: &mut dyn Writeable) {
+        writeable.write_u32(self.to_bits());
+    }
+}
+
+impl Writer for f64 {
+    fn length(&self) -> usize {
+        mem::size_of::<f64>()
+    }
+
+    fn write_to(&self, writeable: &mut dyn Writeable) {
+        writeable.write_u64(self.to_bits());
+    }
+}
+
 impl Writer for [u8] {
     fn length(&self) -> usize {
         self.len()
@@ -154,25 +225,44 @@ impl Writer for String {
     }
 }
 
+const NOT_NULL: u8 = 0;
+const NULL: u8 = 1;
+
+// nullable fields are preceded by a presence byte, which is not a boolean field in its own right
+fn write_presence(present: bool, writeable: &mut dyn Writeable) {
+    writeable.write_u8(if present { NOT_NULL } else { NULL });
+}
+
+fn read_presence(readable: &mut dyn Readable) -> DecodeResult<bool> {
+    Ok(readable.read_u8()? == NOT_NULL)
+}
+
 impl<T: Writer> Writer for Option<T> {
     fn length(&self) -> usize {
         mem::size_of::<u8>() + self.as_ref().map(|v| v.length()).unwrap_or(0)
     }
 
     fn write_to(&self, writeable: &mut dyn Writeable) {
-        match self {
-            Some(value) => {
-                false.write_to(writeable);
-                value.write_to(writeable);
-            }
-            None => true.write_to(writeable),
+        write_presence(self.is_some(), writeable);
+        if let Some(value) = self {
+            value.write_to(writeable);
         }
     }
 }
 
+impl<T: Writer> Writer for &T {
+    fn length(&self) -> usize {
+        (*self).length()
+    }
+
+    fn write_to(&self, writeable: &mut dyn Writeable) {
+        (*self).write_to(writeable);
+    }
+}
+
 impl<T: Writer> Writer for &[T] {
     fn length(&self) -> usize {
-        mem::size_of::<u32>() + self.len() * self.first().map(|v| v.length()).unwrap_or(0)
+        mem::size_of::<u32>() + self.iter().map(|v| v.length()).sum::<usize>()
     }
 
     fn write_to(&self, writeable: &mut dyn Writeable) {
@@ -184,78 +274,137 @@ impl<T: Writer> Writer for &[T] {
     }
 }
 
+// written like a slice, so a Vec<u8> goes out length-prefixed the way Vec::<u8>::read_from expects it back
+impl<T: Writer> Writer for Vec<T> {
+    fn length(&self) -> usize {
+        self.as_slice().length()
+    }
+
+    fn write_to(&self, writeable: &mut dyn Writeable) {
+        Writer::write_to(&self.as_slice(), writeable);
+    }
+}
+
+impl<K: Writer, V: Writer> Writer for (K, V) {
+    fn length(&self) -> usize {
+        self.0.length() + self.1.length()
+    }
+
+    fn write_to(&self, writeable: &mut dyn Writeable) {
+        self.0.write_to(writeable);
+        self.1.write_to(writeable);
+    }
+}
+
 impl Reader for bool {
-    fn read_from(readable: &mut dyn Readable) -> Self {
+    fn read_from(readable: &mut dyn Readable) -> DecodeResult<Self> {
         readable.read_bool()
     }
 }
 
 impl Reader for u8 {
-    fn read_from(readable: &mut dyn Readable) -> Self {
+    fn read_from(readable: &mut dyn Readable) -> DecodeResult<Self> {
         readable.read_u8()
     }
 }
 
+impl Reader for i8 {
+    fn read_from(readable: &mut dyn Readable) -> DecodeResult<Self> {
+        Ok(readable.read_u8()? as i8)
+    }
+}
+
+impl Reader for i16 {
+    fn read_from(readable: &mut dyn Readable) -> DecodeResult<Self> {
+        Ok(readable.read_u16()? as i16)
+    }
+}
+
 impl Reader for u16 {
-    fn read_from(readable: &mut dyn Readable) -> Self {
+    fn read_from(readable: &mut dyn Readable) -> DecodeResult<Self> {
         readable.read_u16()
     }
 }
 
 impl Reader for i32 {
-    fn read_from(readable: &mut dyn Readable) -> Self {
+    fn read_from(readable: &mut dyn Readable) -> DecodeResult<Self> {
         readable.read_i32()
     }
 }
 
 impl Reader for u32 {
-    fn read_from(readable: &mut dyn Readable) -> Self {
+    fn read_from(readable: &mut dyn Readable) -> DecodeResult<Self> {
         readable.read_u32()
     }
 }
 
 impl Reader for i64 {
-    fn read_from(readable: &mut dyn Readable) -> Self {
+    fn read_from(readable: &mut dyn Readable) -> DecodeResult<Self> {
         readable.read_i64()
     }
 }
 
 impl Reader for u64 {
-    fn read_from(readable: &mut dyn Readable) -> Self {
+    fn read_from(readable: &mut dyn Readable) -> DecodeResult<Self> {
         readable.read_u64()
     }
 }
 
+impl Reader for f32 {
+    fn read_from(readable: &mut dyn Readable) -> DecodeResult<Self> {
+        Ok(f32::from_bits(readable.read_u32()?))
+    }
+}
+
+impl Reader for f64 {
+    fn read_from(readable: &mut dyn Readable) -> DecodeResult<Self> {
+        Ok(f64::from_bits(readable.read_u64()?))
+    }
+}
+
 impl Reader for String {
-    fn read_from(readable: &mut dyn Readable) -> Self {
-        let len = readable.read_u32().try_into().expect("unable to convert!");
-        std::str::from_utf8(&readable.read_slice(len))
-            .expect("unable to parse utf8 string!")
-            .to_string()
+    fn read_from(readable: &mut dyn Readable) -> DecodeResult<Self> {
+        let len = read_length(readable)?;
+        std::str::from_utf8(&readable.read_slice(len)?)
+            .map(str::to_string)
+            .map_err(|e| DecodeError(format!("invalid utf8 string - {}", e)))
     }
 }
 
 impl<T: Reader> Reader for Option<T> {
-    fn read_from(readable: &mut dyn Readable) -> Self {
-        if !bool::read_from(readable) {
-            Some(T::read_from(readable))
+    fn read_from(readable: &mut dyn Readable) -> DecodeResult<Self> {
+        if read_presence(readable)? {
+            Ok(Some(T::read_from(readable)?))
         } else {
-            None
+            Ok(None)
         }
     }
 }
 
 impl<T: Reader> Reader for Vec<T> {
-    fn read_from(readable: &mut dyn Readable) -> Self {
-        let len = u32::read_from(readable).try_into().expect("unable to convert!");
-        let mut items = Vec::with_capacity(len);
+    fn read_from(readable: &mut dyn Readable) -> DecodeResult<Self> {
+        let len = read_length(readable)?;
+        let mut items = Vec::new();
         for _ in 0..len {
-            items.push(T::read_from(readable));
+            items.push(T::read_from(readable)?);
         }
-        items
+        Ok(items)
+    }
+}
+
+impl<K: Reader, V: Reader> Reader for (K, V) {
+    fn read_from(readable: &mut dyn Readable) -> DecodeResult<Self> {
+        Ok((K::read_from(readable)?, V::read_from(readable)?))
     }
 }
 
+fn read_length(readable: &mut dyn Readable) -> DecodeResult<usize> {
+    readable
+        .read_u32()?
+        .try_into()
+        .map_err(|_| DecodeError::new("length out of range"))
+}
+
 impl Writeable for BytesMut {
     fn write_bool(&mut self, value: bool) {
         if value {
@@ -295,41 +444,66 @@ impl Writeable for BytesMut {
 }
 
 impl Readable for Bytes {
-    fn read_bool(&mut self) -> bool {
-        self.read_u8() > 0
+    fn read_bool(&mut self) -> DecodeResult<bool> {
+        Ok(self.read_u8()? > 0)
     }
 
-    fn read_u8(&mut self) -> u8 {
-        self.get_u8()
+    fn read_u8(&mut self) -> DecodeResult<u8> {
+        ensure_remaining(self, mem::size_of::<u8>())?;
+        Ok(self.get_u8())
     }
 
-    fn read_u16(&mut self) -> u16 {
-        self.get_u16_le()
+    fn read_u16(&mut self) -> DecodeResult<u16> {
+        ensure_remaining(self, mem::size_of::<u16>())?;
+        Ok(self.get_u16_le())
     }
 
-    fn read_i32(&mut self) -> i32 {
-        self.get_i32_le()
+    fn read_i32(&mut self) -> DecodeResult<i32> {
+        ensure_remaining(self, mem::size_of::<i32>())?;
+        Ok(self.get_i32_le())
     }
 
-    fn read_u32(&mut self) -> u32 {
-        self.get_u32_le()
+    fn read_u32(&mut self) -> DecodeResult<u32> {
+        ensure_remaining(self, mem::size_of::<u32>())?;
+        Ok(self.get_u32_le())
     }
 
-    fn read_i64(&mut self) -> i64 {
-        self.get_i64_le()
+    fn read_i64(&mut self) -> DecodeResult<i64> {
+        ensure_remaining(self, mem::size_of::<i64>())?;
+        Ok(self.get_i64_le())
     }
 
-    fn read_u64(&mut self) -> u64 {
-        self.get_u64_le()
+    fn read_u64(&mut self) -> DecodeResult<u64> {
+        ensure_remaining(self, mem::size_of::<u64>())?;
+        Ok(self.get_u64_le())
     }
 
-    fn read_slice(&mut self, len: usize) -> Bytes {
-        self.split_to(len)
+    fn read_slice(&mut self, len: usize) -> DecodeResult<Bytes> {
+        ensure_remaining(self, len)?;
+        Ok(self.split_to(len))
     }
 
-    fn skip(&mut self, len: usize) {
+    fn skip(&mut self, len: usize) -> DecodeResult<()> {
+        ensure_remaining(self, len)?;
         self.advance(len);
+        Ok(())
+    }
+
+    fn is_exhausted(&self) -> bool {
+        !self.has_remaining()
+    }
+}
+
+// a truncated message fails to decode instead of panicking the task that reads it
+fn ensure_remaining(readable: &Bytes, len: usize) -> DecodeResult<()> {
+    if readable.remaining() < len {
+        return Err(DecodeError(format!(
+            "expected {} more bytes but only {} remain",
+            len,
+            readable.remaining()
+        )));
     }
+    Ok(())
 }
 
 #[cfg(test)]
@@ -347,8 +521,18 @@ mod tests {
         false.write_to(writeable);
 
         let readable = &mut writeable.to_bytes();
-        assert_eq!(bool::read_from(readable), true);
-        assert_eq!(bool::read_from(readable), false);
+        assert!(bool::read_from(readable).unwrap());
+        assert!(!bool::read_from(readable).unwrap());
+    }
+
+    #[test]
+    fn should_fail_to_read_truncated_string() {
+        let writeable = &mut BytesMut::new();
+        "some-string".write_to(writeable);
+        writeable.truncate(8);
+
+        let readable = &mut writeable.to_bytes();
+        assert!(String::read_from(readable).is_err());
     }
 
     #[test]
@@ -358,8 +542,34 @@ mod tests {
         0u8.write_to(writeable);
 
         let readable = &mut writeable.to_bytes();
-        assert_eq!(u8::read_from(readable), 1);
-        assert_eq!(u8::read_from(readable), 0);
+        assert_eq!(u8::read_from(readable).unwrap(), 1);
+        assert_eq!(u8::read_from(readable).unwrap(), 0);
+    }
+
+    #[test]
+    fn should_write_and_read_i8() {
+        let writeable = &mut BytesMut::new();
+        i8::MIN.write_to(writeable);
+        i8::MAX.write_to(writeable);
+        (-1i8).write_to(writeable);
+        assert_eq!(&writeable[..], &[0x80, 0x7F, 0xFF]);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(i8::read_from(readable).unwrap(), i8::MIN);
+        assert_eq!(i8::read_from(readable).unwrap(), i8::MAX);
+        assert_eq!(i8::read_from(readable).unwrap(), -1);
+    }
+
+    #[test]
+    fn should_write_and_read_i16() {
+        let writeable = &mut BytesMut::new();
+        i16::MIN.write_to(writeable);
+        i16::MAX.write_to(writeable);
+        assert_eq!(&writeable[..], &[0x00, 0x80, 0xFF, 0x7F]);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(i16::read_from(readable).unwrap(), i16::MIN);
+        assert_eq!(i16::read_from(readable).unwrap(), i16::MAX);
     }
 
     #[test]
@@ -367,10 +577,12 @@ mod tests {
         let writeable = &mut BytesMut::new();
         1u16.write_to(writeable);
         0u16.write_to(writeable);
+        u16::MAX.write_to(writeable);
 
         let readable = &mut writeable.to_bytes();
-        assert_eq!(u16::read_from(readable), 1);
-        assert_eq!(u16::read_from(readable), 0);
+        assert_eq!(u16::read_from(readable).unwrap(), 1);
+        assert_eq!(u16::read_from(readable).unwrap(), 0);
+        assert_eq!(u16::read_from(readable).unwrap(), u16::MAX);
     }
 
     #[test]
@@ -380,8 +592,8 @@ mod tests {
         1i32.write_to(writeable);
 
         let readable = &mut writeable.to_bytes();
-        assert_eq!(i32::read_from(readable), -1);
-        assert_eq!(i32::read_from(readable), 1);
+        assert_eq!(i32::read_from(readable).unwrap(), -1);
+        assert_eq!(i32::read_from(readable).unwrap(), 1);
     }
 
     #[test]
@@ -391,8 +603,8 @@ mod tests {
         0u32.write_to(writeable);
 
         let readable = &mut writeable.to_bytes();
-        assert_eq!(u32::read_from(readable), 1);
-        assert_eq!(u32::read_from(readable), 0);
+        assert_eq!(u32::read_from(readable).unwrap(), 1);
+        assert_eq!(u32::read_from(readable).unwrap(), 0);
     }
 
     #[test]
@@ -402,8 +614,8 @@ mod tests {
         1i64.write_to(writeable);
 
         let readable = &mut writeable.to_bytes();
-        assert_eq!(i64::read_from(readable), -1);
-        assert_eq!(i64::read_from(readable), 1);
+        assert_eq!(i64::read_from(readable).unwrap(), -1);
+        assert_eq!(i64::read_from(readable).unwrap(), 1);
     }
 
     #[test]
@@ -413,8 +625,38 @@ mod tests {
         0u64.write_to(writeable);
 
         let readable = &mut writeable.to_bytes();
-        assert_eq!(u64::read_from(readable), 1);
-        assert_eq!(u64::read_from(readable), 0);
+        assert_eq!(u64::read_from(readable).unwrap(), 1);
+        assert_eq!(u64::read_from(readable).unwrap(), 0);
+    }
+
+    #[test]
+    fn should_write_and_read_f32() {
+        let values = [10.5f32, -0.0, f32::NAN, f32::MIN_POSITIVE, f32::INFINITY];
+        let writeable = &mut BytesMut::new();
+        for value in &values {
+            value.write_to(writeable);
+        }
+        assert_eq!(writeable.len(), values.len() * 4);
+
+        let readable = &mut writeable.to_bytes();
+        for value in &values {
+            assert_eq!(f32::read_from(readable).unwrap().to_bits(), value.to_bits());
+        }
+    }
+
+    #[test]
+    fn should_write_and_read_f64() {
+        let values = [10.5f64, -0.0, f64::NAN, f64::MIN_POSITIVE, f64::NEG_INFINITY];
+        let writeable = &mut BytesMut::new();
+        for value in &values {
+            value.write_to(writeable);
+        }
+        assert_eq!(&writeable[..8], &10.5f64.to_le_bytes());
+
+        let readable = &mut writeable.to_bytes();
+        for value in &values {
+            assert_eq!(f64::read_from(readable).unwrap().to_bits(), value.to_bits());
+        }
     }
 
     #[test]
@@ -423,8 +665,36 @@ mod tests {
         [1, 0].write_to(writeable);
 
         let readable = &mut writeable.to_bytes();
-        assert_eq!(readable.read_slice(1)[..], [1]);
-        assert_eq!(readable.read_slice(1)[..], [0]);
+        assert_eq!(readable.read_slice(1).unwrap()[..], [1]);
+        assert_eq!(readable.read_slice(1).unwrap()[..], [0]);
+    }
+
+    #[test]
+    fn should_write_and_read_byte_array() {
+        let bytes: &[u8] = &[0, 255, 0, 0, 7, 0];
+        let writeable = &mut BytesMut::new();
+        Writer::write_to(&bytes, writeable);
+        Some(bytes).write_to(writeable);
+        assert_eq!(&writeable[..10], &[6, 0, 0, 0, 0, 255, 0, 0, 7, 0]);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(Vec::<u8>::read_from(readable).unwrap(), bytes);
+        assert_eq!(Option::<Vec<u8>>::read_from(readable).unwrap().as_deref(), Some(bytes));
+        assert_eq!(readable.remaining(), 0);
+    }
+
+    #[test]
+    fn should_write_and_read_byte_vec() {
+        for bytes in [Vec::new(), (0..=255u8).cycle().take(100_000).collect()] {
+            let writeable = &mut BytesMut::new();
+            bytes.write_to(writeable);
+            assert_eq!(writeable.len(), bytes.length());
+            assert_eq!(writeable[..4], (bytes.len() as i32).to_le_bytes());
+
+            let readable = &mut writeable.to_bytes();
+            assert_eq!(Vec::<u8>::read_from(readable).unwrap(), bytes);
+            assert_eq!(readable.remaining(), 0);
+        }
     }
 
     #[test]
@@ -433,8 +703,8 @@ mod tests {
         [1, 0, 1].write_to(writeable);
 
         let readable = &mut writeable.to_bytes();
-        readable.skip(1);
-        assert_eq!(readable.read_slice(2)[..], [0, 1]);
+        readable.skip(1).unwrap();
+        assert_eq!(readable.read_slice(2).unwrap()[..], [0, 1]);
     }
 
     #[test]
@@ -443,7 +713,7 @@ mod tests {
         "10".write_to(writeable);
 
         let readable = &mut writeable.to_bytes();
-        assert_eq!(String::read_from(readable), "10");
+        assert_eq!(String::read_from(readable).unwrap(), "10");
     }
 
     #[test]
@@ -453,8 +723,45 @@ mod tests {
         Option::<u32>::None.write_to(writeable);
 
         let readable = &mut writeable.to_bytes();
-        assert_eq!(Option::read_from(readable), Some(1u32));
-        assert_eq!(Option::<u32>::read_from(readable), None);
+        assert_eq!(Option::read_from(readable).unwrap(), Some(1u32));
+        assert_eq!(Option::<u32>::read_from(readable).unwrap(), None);
+    }
+
+    #[test]
+    fn should_write_and_read_presence() {
+        let writeable = &mut BytesMut::new();
+        write_presence(true, writeable);
+        write_presence(false, writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(readable.bytes(), [NOT_NULL, NULL]);
+        assert!(read_presence(readable).unwrap());
+        assert!(!read_presence(readable).unwrap());
+    }
+
+    #[test]
+    fn should_write_and_read_present_nullable_uuid() {
+        let uuid = Some("3f8c9d1e-4b2a-4c6f-9e7d-1a2b3c4d5e6f");
+
+        let writeable = &mut BytesMut::new();
+        uuid.write_to(writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(readable.bytes()[0], NOT_NULL);
+        assert_eq!(Option::<String>::read_from(readable).unwrap().as_deref(), uuid);
+        assert_eq!(readable.remaining(), 0);
+    }
+
+    #[test]
+    fn should_write_and_read_absent_nullable_uuid() {
+        let writeable = &mut BytesMut::new();
+        Option::<&str>::None.write_to(writeable);
+        Option::<i64>::None.write_to(writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(readable.bytes(), [NULL, NULL]);
+        assert_eq!(Option::<String>::read_from(readable).unwrap(), None);
+        assert_eq!(Option::<i64>::read_from(readable).unwrap(), None);
     }
 
     #[test]
@@ -463,6 +770,64 @@ mod tests {
         vec![1u32].deref().write_to(writeable);
 
         let readable = &mut writeable.to_bytes();
-        assert_eq!(Vec::<u32>::read_from(readable), vec!(1u32));
+        assert_eq!(Vec::<u32>::read_from(readable).unwrap(), vec!(1u32));
+    }
+
+    #[test]
+    fn should_write_and_read_string_list() {
+        let strings = ["first", "", "third"];
+        let writeable = &mut BytesMut::new();
+        Writer::write_to(&&strings[..], writeable);
+
+        assert_eq!(writeable[..9], [3, 0, 0, 0, 5, 0, 0, 0, b'f']);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(Vec::<String>::read_from(readable).unwrap(), strings);
+        assert_eq!(readable.remaining(), 0);
+    }
+
+    #[test]
+    fn should_write_and_read_entry() {
+        let writeable = &mut BytesMut::new();
+        (1u32, "value").write_to(writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(
+            <(u32, String)>::read_from(readable).unwrap(),
+            (1u32, "value".to_string())
+        );
+    }
+
+    #[test]
+    fn should_write_and_read_entry_list() {
+        let entries = vec![
+            ("zone".to_string(), "eu-west-1a".to_string()),
+            ("rack".to_string(), String::new()),
+        ];
+        let writeable = &mut BytesMut::new();
+        entries.write_to(writeable);
+        assert_eq!(writeable.len(), entries.length());
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(Vec::<(String, String)>::read_from(readable).unwrap(), entries);
+        assert_eq!(readable.remaining(), 0);
+    }
+
+    #[test]
+    fn should_read_entry_list() {
+        let writeable = &mut BytesMut::new();
+        2u32.write_to(writeable);
+        for (key, value) in &[([1u8, 2], [3u8]), ([4u8, 0], [0u8])] {
+            2u32.write_to(writeable);
+            key.write_to(writeable);
+            1u32.write_to(writeable);
+            value.write_to(writeable);
+        }
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(
+            Vec::<(Vec<u8>, Vec<u8>)>::read_from(readable).unwrap(),
+            vec![(vec![1, 2], vec![3]), (vec![4, 0], vec![0])]
+        );
     }
 }
@@ -0,0 +1,391 @@
+#[cfg(feature = "tls")]
+use std::path::{Path, PathBuf};
+use std::{net::SocketAddr, time::Duration};
+
+use crate::remote::{Backoff, Heartbeat, Retries, RoutingMode, Timeouts, DEFAULT_MAX_FRAME_SIZE};
+
+const MIN_FRAME_SIZE: usize = 1024;
+// the length delimited codec refuses to write frames beyond its default limit of 8 MiB
+const MAX_FRAME_SIZE: usize = 8 * 1024 * 1024;
+const MIN_IDLE_TIMEOUT: Duration = Duration::from_millis(1);
+const MIN_HEARTBEAT_INTERVAL: Duration = Duration::from_millis(1);
+
+#[derive(Clone, Debug)]
+pub struct ClientConfig {
+    pub(crate) addresses: Vec<SocketAddr>,
+    pub(crate) username: String,
+    pub(crate) password: String,
+    pub(crate) cluster_name: Option<String>,
+    pub(crate) local_zone: Option<String>,
+    pub(crate) routing_mode: RoutingMode,
+    pub(crate) connect_timeout: Duration,
+    pub(crate) timeouts: Timeouts,
+    pub(crate) retries: Retries,
+    pub(crate) heartbeat: Heartbeat,
+    pub(crate) idle_timeout: Option<Duration>,
+    pub(crate) reconnect_backoff: Backoff,
+    pub(crate) max_concurrent_invocations: Option<usize>,
+    pub(crate) max_frame_size: usize,
+    pub(crate) map: MapConfig,
+    #[cfg(feature = "tls")]
+    pub(crate) tls: Option<TlsConfig>,
+}
+
+impl ClientConfig {
+    pub fn builder() -> ClientConfigBuilder {
+        ClientConfigBuilder {
+            config: ClientConfig::default(),
+        }
+    }
+
+    // clusters speaking protocol 1.x authenticate clients by their group name, sent as the username
+    pub(crate) fn group_name(&self) -> &str {
+        self.cluster_name.as_deref().unwrap_or(&self.username)
+    }
+}
+
+impl Default for ClientConfig {
+    fn default() -> Self {
+        ClientConfig {
+            addresses: vec![SocketAddr::from(([127, 0, 0, 1], 5701))],
+            username: "dev".to_string(),
+            password: "dev-pass".to_string(),
+            cluster_name: None,
+            local_zone: None,
+            routing_mode: RoutingMode::Smart,
+            connect_timeout: Duration::from_secs(5),
+            timeouts: Timeouts::default(),
+            retries: Retries::default(),
+            heartbeat: Heartbeat::default(),
+            idle_timeout: None,
+            reconnect_backoff: Backoff::default(),
+            max_concurrent_invocations: None,
+            max_frame_size: DEFAULT_MAX_FRAME_SIZE,
+            map: MapConfig::default(),
+            #[cfg(feature = "tls")]
+            tls: None,
+        }
+    }
+}
+
+pub struct ClientConfigBuilder {
+    config: ClientConfig,
+}
+
+impl ClientConfigBuilder {
+    pub fn addresses(mut self, addresses: &[SocketAddr]) -> Self {
+        self.config.addresses = addresses.to_vec();
+        self
+    }
+
+    pub fn credentials(mut self, username: &str, password: &str) -> Self {
+        self.config.username = username.to_string();
+        self.config.password = password.to_string();
+        self
+    }
+
+    // the cluster name is sent in place of the username of the credentials, in whatever order the two are set
+    pub fn cluster_name(mut self, name: &str) -> Self {
+        self.config.cluster_name = Some(name.to_string());
+        self
+    }
+
+    pub fn routing_mode(mut self, routing_mode: RoutingMode) -> Self {
+        self.config.routing_mode = routing_mode;
+        self
+    }
+
+    pub fn local_zone(mut self, zone: &str) -> Self {
+        self.config.local_zone = Some(zone.to_string());
+        self
+    }
+
+    // opening a connection to a member and authenticating on it have to complete within the timeout
+    pub fn connect_timeout(mut self, connect_timeout: Duration) -> Self {
+        self.config.connect_timeout = connect_timeout;
+        self
+    }
+
+    // dispatched invocations are retried until the first timeout, those forwarded to an address until the second
+    pub fn invocation_timeouts(mut self, dispatch: Duration, forward: Duration) -> Self {
+        self.config.timeouts = Timeouts { dispatch, forward };
+        self
+    }
+
+    // invocations failing with a retryable server error are repeated up to the count, after a delay doubling from
+    // the base up to the maximum
+    pub fn invocation_retries(mut self, count: u32, base: Duration, max: Duration) -> Self {
+        self.config.retries = Retries {
+            count,
+            backoff: Backoff {
+                base,
+                max: max.max(base),
+            },
+        };
+        self
+    }
+
+    // every connection is pinged once per interval, kept above zero, and one whose ping goes unanswered for the
+    // timeout is dropped
+    pub fn heartbeat(mut self, interval: Duration, timeout: Duration) -> Self {
+        self.config.heartbeat = Heartbeat {
+            interval: interval.max(MIN_HEARTBEAT_INTERVAL),
+            timeout,
+        };
+        self
+    }
+
+    // idle connections are looked for twice per timeout, so it is kept above zero
+    pub fn idle_timeout(mut self, idle_timeout: Duration) -> Self {
+        self.config.idle_timeout = Some(idle_timeout.max(MIN_IDLE_TIMEOUT));
+        self
+    }
+
+    pub fn reconnect_backoff(mut self, base: Duration, max: Duration) -> Self {
+        self.config.reconnect_backoff = Backoff {
+            base,
+            max: max.max(base),
+        };
+        self
+    }
+
+    // invocations beyond the limit wait for one of those in flight on the same member to complete
+    pub fn max_concurrent_invocations(mut self, limit: usize) -> Self {
+        self.config.max_concurrent_invocations = Some(limit.max(1));
+        self
+    }
+
+    // larger messages are split into fragments of at most this many bytes
+    pub fn max_frame_size(mut self, max_frame_size: usize) -> Self {
+        self.config.max_frame_size = max_frame_size.clamp(MIN_FRAME_SIZE, MAX_FRAME_SIZE);
+        self
+    }
+
+    pub fn map(mut self, map: MapConfig) -> Self {
+        self.config.map = map;
+        self
+    }
+
+    // every connection is wrapped in a TLS session before the protocol sequence is sent
+    #[cfg(feature = "tls")]
+    pub fn tls(mut self, tls: TlsConfig) -> Self {
+        self.config.tls = Some(tls);
+        self
+    }
+
+    pub fn build(self) -> ClientConfig {
+        self.config
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct MapConfig {
+    pub(crate) put_all_batch_size: usize,
+}
+
+impl MapConfig {
+    pub fn builder() -> MapConfigBuilder {
+        MapConfigBuilder {
+            config: MapConfig::default(),
+        }
+    }
+}
+
+impl Default for MapConfig {
+    fn default() -> Self {
+        MapConfig {
+            put_all_batch_size: 1000,
+        }
+    }
+}
+
+pub struct MapConfigBuilder {
+    config: MapConfig,
+}
+
+impl MapConfigBuilder {
+    pub fn put_all_batch_size(mut self, batch_size: usize) -> Self {
+        self.config.put_all_batch_size = batch_size.max(1);
+        self
+    }
+
+    pub fn build(self) -> MapConfig {
+        self.config
+    }
+}
+
+#[cfg(feature = "tls")]
+#[derive(Clone, Debug)]
+pub struct TlsConfig {
+    pub(crate) ca_certificate: PathBuf,
+    pub(crate) server_name: String,
+    pub(crate) client_certificate: Option<(PathBuf, PathBuf)>,
+}
+
+#[cfg(feature = "tls")]
+impl TlsConfig {
+    // members are verified against the CA certificate (PEM) and must present a certificate issued for the server name
+    pub fn builder<P: AsRef<Path>>(ca_certificate: P, server_name: &str) -> TlsConfigBuilder {
+        TlsConfigBuilder {
+            config: TlsConfig {
+                ca_certificate: ca_certificate.as_ref().to_path_buf(),
+                server_name: server_name.to_string(),
+                client_certificate: None,
+            },
+        }
+    }
+}
+
+#[cfg(feature = "tls")]
+pub struct TlsConfigBuilder {
+    config: TlsConfig,
+}
+
+#[cfg(feature = "tls")]
+impl TlsConfigBuilder {
+    // members requiring mutual TLS are presented the certificate chain and its PKCS#8 private key, both PEM encoded
+    pub fn client_certificate<C: AsRef<Path>, K: AsRef<Path>>(mut self, certificate: C, key: K) -> Self {
+        self.config.client_certificate = Some((certificate.as_ref().to_path_buf(), key.as_ref().to_path_buf()));
+        self
+    }
+
+    pub fn build(self) -> TlsConfig {
+        self.config
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_build_default_config() {
+        let config = ClientConfig::builder().build();
+
+        assert_eq!(config.addresses, vec!["127.0.0.1:5701".parse().unwrap()]);
+        assert_eq!(config.username, "dev");
+        assert_eq!(config.password, "dev-pass");
+        assert_eq!(config.local_zone, None);
+        assert_eq!(config.routing_mode, RoutingMode::Smart);
+        assert_eq!(config.connect_timeout, Duration::from_secs(5));
+        assert_eq!(config.timeouts.dispatch, Duration::from_secs(120));
+        assert_eq!(config.timeouts.forward, Duration::from_secs(60));
+        assert_eq!(config.retries, Retries::default());
+        assert_eq!(config.heartbeat, Heartbeat::default());
+        assert_eq!(config.idle_timeout, None);
+        assert_eq!(config.reconnect_backoff, Backoff::default());
+        assert_eq!(config.max_frame_size, DEFAULT_MAX_FRAME_SIZE);
+        assert_eq!(config.map.put_all_batch_size, 1000);
+    }
+
+    #[test]
+    fn should_build_config_with_bounded_max_frame_size() {
+        assert_eq!(
+            ClientConfig::builder().max_frame_size(8192).build().max_frame_size,
+            8192
+        );
+        assert_eq!(
+            ClientConfig::builder().max_frame_size(1).build().max_frame_size,
+            MIN_FRAME_SIZE
+        );
+        assert_eq!(
+            ClientConfig::builder()
+                .max_frame_size(usize::MAX)
+                .build()
+                .max_frame_size,
+            MAX_FRAME_SIZE
+        );
+    }
+
+    #[test]
+    fn should_build_config_with_non_zero_idle_timeout() {
+        assert_eq!(
+            ClientConfig::builder()
+                .idle_timeout(Duration::ZERO)
+                .build()
+                .idle_timeout,
+            Some(MIN_IDLE_TIMEOUT)
+        );
+    }
+
+    #[test]
+    fn should_build_config_with_non_zero_heartbeat_interval() {
+        let config = ClientConfig::builder()
+            .heartbeat(Duration::ZERO, Duration::from_secs(10))
+            .build();
+
+        assert_eq!(config.heartbeat.interval, MIN_HEARTBEAT_INTERVAL);
+        assert_eq!(config.heartbeat.timeout, Duration::from_secs(10));
+    }
+
+    #[test]
+    fn should_keep_cluster_name_set_before_credentials() {
+        let config = ClientConfig::builder()
+            .cluster_name("production")
+            .credentials("user", "pass")
+            .build();
+
+        assert_eq!(config.username, "user");
+        assert_eq!(config.group_name(), "production");
+        assert_eq!(
+            ClientConfig::builder().credentials("user", "pass").build().group_name(),
+            "user"
+        );
+    }
+
+    #[test]
+    fn should_build_config_with_local_zone() {
+        let address = "10.0.0.1:5702".parse().unwrap();
+        let config = ClientConfig::builder()
+            .addresses(&[address])
+            .credentials("user", "pass")
+            .local_zone("eu-west-1a")
+            .routing_mode(RoutingMode::Unisocket)
+            .build();
+
+        assert_eq!(config.addresses, vec![address]);
+        assert_eq!(config.username, "user");
+        assert_eq!(config.password, "pass");
+        assert_eq!(config.local_zone.as_deref(), Some("eu-west-1a"));
+        assert_eq!(config.routing_mode, RoutingMode::Unisocket);
+    }
+
+    #[test]
+    fn should_build_config_with_overrides() {
+        let addresses = ["10.0.0.1:5701".parse().unwrap(), "10.0.0.2:5701".parse().unwrap()];
+        let config = ClientConfig::builder()
+            .addresses(&addresses)
+            .credentials("user", "pass")
+            .cluster_name("production")
+            .connect_timeout(Duration::from_secs(3))
+            .invocation_timeouts(Duration::from_secs(5), Duration::from_secs(2))
+            .invocation_retries(3, Duration::from_secs(1), Duration::from_millis(1))
+            .heartbeat(Duration::from_secs(1), Duration::from_secs(10))
+            .idle_timeout(Duration::from_secs(30))
+            .max_concurrent_invocations(0)
+            .build();
+
+        assert_eq!(config.addresses, addresses.to_vec());
+        assert_eq!(config.username, "user");
+        assert_eq!(config.password, "pass");
+        assert_eq!(config.group_name(), "production");
+        assert_eq!(config.connect_timeout, Duration::from_secs(3));
+        assert_eq!(config.timeouts.dispatch, Duration::from_secs(5));
+        assert_eq!(config.timeouts.forward, Duration::from_secs(2));
+        assert_eq!(config.retries.count, 3);
+        assert_eq!(config.retries.backoff.max, Duration::from_secs(1));
+        assert_eq!(config.heartbeat.interval, Duration::from_secs(1));
+        assert_eq!(config.heartbeat.timeout, Duration::from_secs(10));
+        assert_eq!(config.idle_timeout, Some(Duration::from_secs(30)));
+        assert_eq!(config.max_concurrent_invocations, Some(1));
+    }
+
+    #[test]
+    fn should_build_map_config_with_positive_batch_size() {
+        let config = ClientConfig::builder()
+            .map(MapConfig::builder().put_all_batch_size(0).build())
+            .build();
+
+        assert_eq!(config.map.put_all_batch_size, 1);
+    }
+}
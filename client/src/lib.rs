@@ -1,20 +1,72 @@
 #[macro_use]
 extern crate hazelcast_rust_client_macros;
 
-use std::{error, net::SocketAddr, sync::Arc};
+use std::{error, net::SocketAddr, sync::Arc, time::Duration};
 
-use log::info;
+use log::{error, info};
 use thiserror::Error;
 
-pub use protocol::pn_counter::PnCounter;
+pub use config::{ClientConfig, ClientConfigBuilder, MapConfig, MapConfigBuilder};
+#[cfg(feature = "tls")]
+pub use config::{TlsConfig, TlsConfigBuilder};
+pub use messaging::{
+    map::EntryEvent,
+    membership::{MembershipEvent, MembershipEventKind},
+    partition::PartitionLostEvent,
+    topic::TopicMessage,
+    ListenerInfo, ListenerKind, MemberInfo,
+};
+pub use protocol::{
+    atomic_long::AtomicLong,
+    list::List,
+    lock::Lock,
+    map::Map,
+    multi_map::MultiMap,
+    pn_counter::PnCounter,
+    queue::Queue,
+    ringbuffer::Ringbuffer,
+    semaphore::Semaphore,
+    set::Set,
+    topic::Topic,
+    transaction::{Transaction, TransactionOptions, TransactionOptionsBuilder, TransactionType, TransactionalMap},
+};
+// internals driven by the benchmarks, which are not meant to be used otherwise
+#[cfg(feature = "bench")]
+#[doc(hidden)]
+pub use remote::bench;
+pub use remote::RoutingMode;
+pub use serialization::{
+    ByteArray, Data, DataInput, DataOutput, DataSerializer, FromData, HazelcastJsonValue, IdentifiedDataSerializable,
+    IntEnum, SerializationService, ToData,
+};
 
-use crate::remote::cluster::Cluster;
+use crate::{
+    remote::cluster::Cluster,
+    runtime::{DefaultRuntime, Runtime},
+};
 
 mod codec;
+mod config;
 mod messaging;
 mod protocol;
 mod remote;
+mod runtime;
+mod serialization;
 
+/// Failures returned by every client operation, which callers can match on to tell them apart.
+///
+/// ```no_run
+/// use hazelcast_rust_client::{HazelcastClient, HazelcastClientError};
+///
+/// # async fn connect() {
+/// let endpoints = vec!["127.0.0.1:5701".parse().unwrap()];
+/// match HazelcastClient::new(endpoints, "dev", "wrong-pass").await {
+///     Ok(_) => println!("connected"),
+///     Err(HazelcastClientError::AuthenticationFailure(status)) => eprintln!("rejected: {}", status),
+///     Err(e) => eprintln!("unable to connect: {}", e),
+/// }
+/// # }
+/// ```
 #[derive(Error, Debug)]
 pub enum HazelcastClientError {
     #[error("unable to authenticate ({0})")]
@@ -25,12 +77,23 @@ pub enum HazelcastClientError {
     ClusterNonOperational,
     #[error("unable to communicate with the server ({0})")]
     CommunicationFailure(Box<dyn error::Error + Send + Sync>),
+    #[error("operation timed out after {0:?}")]
+    OperationTimeout(Duration),
+    #[error("replica consistency guarantees were lost ({0})")]
+    ConsistencyLost(Box<dyn error::Error + Send + Sync>),
+    #[error("received malformed message ({0})")]
+    MalformedMessage(Box<dyn error::Error + Send + Sync>),
+    #[error("unable to serialize value ({0})")]
+    SerializationFailure(String),
+    #[error("unable to deserialize value ({0})")]
+    DeserializationFailure(String),
     #[error("server was unable to process messaging ({0})")]
     ServerFailure(Box<dyn error::Error + Send + Sync>),
 }
 
 pub struct HazelcastClient {
     cluster: Arc<Cluster>,
+    map_config: MapConfig,
 }
 
 impl HazelcastClient {
@@ -38,20 +101,137 @@ impl HazelcastClient {
     where
         E: IntoIterator<Item = SocketAddr>,
     {
+        let addresses: Vec<SocketAddr> = endpoints.into_iter().collect();
+        let config = ClientConfig::builder()
+            .addresses(&addresses)
+            .credentials(username, password)
+            .build();
+        HazelcastClient::with_config(config).await
+    }
+
+    pub async fn with_config(config: ClientConfig) -> Result<Self> {
         info!("HazelcastClient {} is STARTING", env!("CARGO_PKG_VERSION"));
-        let cluster = Cluster::init(endpoints, username, password).await?;
+        let cluster = Cluster::init(&config).await?;
         info!("{}", cluster.to_string().await);
         info!("HazelcastClient is CONNECTED");
         info!("HazelcastClient is STARTED");
 
         Ok(HazelcastClient {
             cluster: Arc::new(cluster),
+            map_config: config.map,
         })
     }
 
+    pub async fn shutdown(&self) {
+        info!("HazelcastClient is SHUTTING_DOWN");
+        self.cluster.shutdown().await;
+        info!("HazelcastClient is SHUTDOWN");
+    }
+
+    pub fn server_version(&self) -> Option<&str> {
+        self.cluster.server_version()
+    }
+
+    pub async fn partition_count(&self) -> Result<i32> {
+        self.cluster.partition_count().await
+    }
+
+    pub fn active_listeners(&self) -> Vec<ListenerInfo> {
+        self.cluster.listeners()
+    }
+
+    pub async fn add_partition_lost_listener<F>(&self, listener: F) -> Result<String>
+    where
+        F: Fn(PartitionLostEvent) + Send + 'static,
+    {
+        use crate::messaging::partition::{
+            AddPartitionLostListenerRequest, AddPartitionLostListenerResponse, RemovePartitionLostListenerRequest,
+            RemovePartitionLostListenerResponse,
+        };
+
+        let (registration_id, mut events) = self
+            .cluster
+            .listen::<_, AddPartitionLostListenerResponse, _, RemovePartitionLostListenerResponse, _>(
+                ListenerKind::PartitionLost,
+                AddPartitionLostListenerRequest::new(false),
+                RemovePartitionLostListenerRequest::new,
+            )
+            .await?;
+        DefaultRuntime::spawn(async move {
+            while let Some(event) = events.recv().await {
+                match TryFrom::<PartitionLostEvent>::try_from(event) {
+                    Ok(event) => listener(event),
+                    Err(e) => error!("Failed to read partition lost event - {}", e),
+                }
+            }
+        });
+        Ok(registration_id)
+    }
+
+    // members joining or leaving are reported for as long as the client runs
+    pub fn add_membership_listener<F>(&self, listener: F)
+    where
+        F: Fn(MembershipEvent) + Send + 'static,
+    {
+        let mut events = self.cluster.membership_events();
+        DefaultRuntime::spawn(async move {
+            while let Some(event) = events.recv().await {
+                listener(event);
+            }
+        });
+    }
+
+    pub async fn remove_listener(&self, registration_id: &str) -> Result<bool> {
+        self.cluster.remove_listener(registration_id).await
+    }
+
+    pub fn map(&self, name: &str) -> Map {
+        Map::new(name, self.map_config, self.cluster.clone())
+    }
+
     pub fn pn_counter(&self, name: &str) -> PnCounter {
         PnCounter::new(name, self.cluster.clone())
     }
+
+    pub fn atomic_long(&self, name: &str) -> AtomicLong {
+        AtomicLong::new(name, self.cluster.clone())
+    }
+
+    pub fn lock(&self, name: &str) -> Lock {
+        Lock::new(name, self.cluster.clone())
+    }
+
+    pub fn semaphore(&self, name: &str) -> Semaphore {
+        Semaphore::new(name, self.cluster.clone())
+    }
+
+    pub fn queue(&self, name: &str) -> Queue {
+        Queue::new(name, self.cluster.clone())
+    }
+
+    pub fn list(&self, name: &str) -> List {
+        List::new(name, self.cluster.clone())
+    }
+
+    pub fn set(&self, name: &str) -> Set {
+        Set::new(name, self.cluster.clone())
+    }
+
+    pub fn multi_map(&self, name: &str) -> MultiMap {
+        MultiMap::new(name, self.cluster.clone())
+    }
+
+    pub fn ringbuffer(&self, name: &str) -> Ringbuffer {
+        Ringbuffer::new(name, self.cluster.clone())
+    }
+
+    pub fn topic(&self, name: &str) -> Topic {
+        Topic::new(name, self.cluster.clone())
+    }
+
+    pub async fn begin_transaction(&self, options: TransactionOptions) -> Result<Transaction> {
+        Transaction::begin(options, self.cluster.clone()).await
+    }
 }
 
 type Result<T> = std::result::Result<T, HazelcastClientError>;
@@ -61,3 +241,86 @@ trait TryFrom<T> {
 
     fn try_from(self) -> std::result::Result<T, Self::Error>;
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use bytes::{Buf, BytesMut};
+
+    use crate::{
+        codec::Writer,
+        messaging::Address,
+        remote::mock::{event, frame, registration_payload, MockMember},
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn should_notify_partition_lost_listener() {
+        let member =
+            MockMember::start_with_frames("member", Duration::from_secs(0), |message| match message.r#type() {
+                0xA => {
+                    let writeable = &mut BytesMut::new();
+                    7i32.write_to(writeable);
+                    1i32.write_to(writeable);
+                    Some(Address::from(&"127.0.0.1:5701".parse().unwrap())).write_to(writeable);
+                    Some(vec![
+                        frame(message.id(), 0x68, &registration_payload()),
+                        event(message.id(), 0xCE, &writeable.to_bytes()),
+                    ])
+                }
+                _ => None,
+            })
+            .await;
+        let config = ClientConfig::builder().addresses(&[member.address()]).build();
+        let client = HazelcastClient::with_config(config).await.unwrap();
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let observed = events.clone();
+        let registration_id = client
+            .add_partition_lost_listener(move |event| {
+                observed
+                    .lock()
+                    .unwrap()
+                    .push((event.partition_id(), event.lost_backup_count()))
+            })
+            .await
+            .unwrap();
+        while events.lock().unwrap().is_empty() {
+            DefaultRuntime::delay(Duration::from_millis(10)).await;
+        }
+
+        assert_eq!(registration_id, "registration-id");
+        assert_eq!(*events.lock().unwrap(), vec![(7, 1)]);
+        assert_eq!(client.active_listeners().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn should_authenticate_with_given_credentials() {
+        use crate::codec::Reader;
+
+        let credentials = Arc::new(Mutex::new(Vec::new()));
+        let received = credentials.clone();
+        let member = MockMember::start_with("member", move |message| {
+            if message.r#type() == 0x2 {
+                let readable = &mut message.payload();
+                let username = String::read_from(readable).unwrap();
+                let password = String::read_from(readable).unwrap();
+                received.lock().unwrap().push((username, password));
+            }
+            None
+        })
+        .await;
+
+        let client = HazelcastClient::new(vec![member.address()], "user", "secret")
+            .await
+            .unwrap();
+
+        assert_eq!(
+            *credentials.lock().unwrap(),
+            vec![("user".to_string(), "secret".to_string())]
+        );
+        client.shutdown().await;
+    }
+}
@@ -0,0 +1,253 @@
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x0A05]
+pub(crate) struct AtomicLongAddAndGetRequest<'a> {
+    name: &'a str,
+    delta: i64,
+    #[partition_id]
+    partition_id: i32,
+}
+
+impl<'a> AtomicLongAddAndGetRequest<'a> {
+    pub(crate) fn new(name: &'a str, delta: i64, partition_id: i32) -> Self {
+        AtomicLongAddAndGetRequest {
+            name,
+            delta,
+            partition_id,
+        }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x67]
+pub(crate) struct AtomicLongAddAndGetResponse {
+    value: i64,
+}
+
+impl AtomicLongAddAndGetResponse {
+    pub(crate) fn value(&self) -> i64 {
+        self.value
+    }
+}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x0A06]
+pub(crate) struct AtomicLongCompareAndSetRequest<'a> {
+    name: &'a str,
+    expected: i64,
+    updated: i64,
+    #[partition_id]
+    partition_id: i32,
+}
+
+impl<'a> AtomicLongCompareAndSetRequest<'a> {
+    pub(crate) fn new(name: &'a str, expected: i64, updated: i64, partition_id: i32) -> Self {
+        AtomicLongCompareAndSetRequest {
+            name,
+            expected,
+            updated,
+            partition_id,
+        }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x65]
+pub(crate) struct AtomicLongCompareAndSetResponse {
+    updated: bool,
+}
+
+impl AtomicLongCompareAndSetResponse {
+    pub(crate) fn updated(&self) -> bool {
+        self.updated
+    }
+}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x0A08]
+pub(crate) struct AtomicLongGetRequest<'a> {
+    name: &'a str,
+    #[partition_id]
+    partition_id: i32,
+}
+
+impl<'a> AtomicLongGetRequest<'a> {
+    pub(crate) fn new(name: &'a str, partition_id: i32) -> Self {
+        AtomicLongGetRequest { name, partition_id }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x67]
+pub(crate) struct AtomicLongGetResponse {
+    value: i64,
+}
+
+impl AtomicLongGetResponse {
+    pub(crate) fn value(&self) -> i64 {
+        self.value
+    }
+}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x0A09]
+pub(crate) struct AtomicLongGetAndAddRequest<'a> {
+    name: &'a str,
+    delta: i64,
+    #[partition_id]
+    partition_id: i32,
+}
+
+impl<'a> AtomicLongGetAndAddRequest<'a> {
+    pub(crate) fn new(name: &'a str, delta: i64, partition_id: i32) -> Self {
+        AtomicLongGetAndAddRequest {
+            name,
+            delta,
+            partition_id,
+        }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x67]
+pub(crate) struct AtomicLongGetAndAddResponse {
+    value: i64,
+}
+
+impl AtomicLongGetAndAddResponse {
+    pub(crate) fn value(&self) -> i64 {
+        self.value
+    }
+}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x0A0B]
+pub(crate) struct AtomicLongIncrementAndGetRequest<'a> {
+    name: &'a str,
+    #[partition_id]
+    partition_id: i32,
+}
+
+impl<'a> AtomicLongIncrementAndGetRequest<'a> {
+    pub(crate) fn new(name: &'a str, partition_id: i32) -> Self {
+        AtomicLongIncrementAndGetRequest { name, partition_id }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x67]
+pub(crate) struct AtomicLongIncrementAndGetResponse {
+    value: i64,
+}
+
+impl AtomicLongIncrementAndGetResponse {
+    pub(crate) fn value(&self) -> i64 {
+        self.value
+    }
+}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x0A0D]
+pub(crate) struct AtomicLongSetRequest<'a> {
+    name: &'a str,
+    value: i64,
+    #[partition_id]
+    partition_id: i32,
+}
+
+impl<'a> AtomicLongSetRequest<'a> {
+    pub(crate) fn new(name: &'a str, value: i64, partition_id: i32) -> Self {
+        AtomicLongSetRequest {
+            name,
+            value,
+            partition_id,
+        }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x64]
+pub(crate) struct AtomicLongSetResponse {}
+
+#[cfg(test)]
+mod tests {
+    use bytes::{Buf, BytesMut};
+
+    use crate::{
+        codec::{Reader, Writer},
+        messaging::Request,
+    };
+
+    use super::*;
+
+    #[test]
+    fn should_write_add_and_get_request() {
+        let request = AtomicLongAddAndGetRequest::new("counter", 5, 13);
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(String::read_from(readable).unwrap(), request.name);
+        assert_eq!(i64::read_from(readable).unwrap(), request.delta);
+        assert_eq!(readable.remaining(), 0);
+        assert_eq!(request.partition_id(), 13);
+    }
+
+    #[test]
+    fn should_write_compare_and_set_request() {
+        let request = AtomicLongCompareAndSetRequest::new("counter", 1, 2, 13);
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(String::read_from(readable).unwrap(), request.name);
+        assert_eq!(i64::read_from(readable).unwrap(), request.expected);
+        assert_eq!(i64::read_from(readable).unwrap(), request.updated);
+        assert_eq!(readable.remaining(), 0);
+    }
+
+    #[test]
+    fn should_read_compare_and_set_response() {
+        let writeable = &mut BytesMut::new();
+        true.write_to(writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert!(AtomicLongCompareAndSetResponse::read_from(readable).unwrap().updated());
+    }
+
+    #[test]
+    fn should_write_get_request() {
+        let request = AtomicLongGetRequest::new("counter", 13);
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(String::read_from(readable).unwrap(), request.name);
+        assert_eq!(readable.remaining(), 0);
+        assert_eq!(request.partition_id(), 13);
+    }
+
+    #[test]
+    fn should_read_get_response() {
+        let writeable = &mut BytesMut::new();
+        42i64.write_to(writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(AtomicLongGetResponse::read_from(readable).unwrap().value(), 42);
+    }
+
+    #[test]
+    fn should_write_set_request() {
+        let request = AtomicLongSetRequest::new("counter", 42, 13);
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(String::read_from(readable).unwrap(), request.name);
+        assert_eq!(i64::read_from(readable).unwrap(), request.value);
+        assert_eq!(readable.remaining(), 0);
+    }
+}
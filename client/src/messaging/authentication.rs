@@ -19,6 +19,8 @@ impl<'a> AuthenticationRequest<'a> {
     pub(crate) fn new(
         username: &'a str,
         password: &'a str,
+        id: Option<&'a str>,
+        owner_id: Option<&'a str>,
         client_type: &'a str,
         serialization_version: u8,
         client_version: &'a str,
@@ -26,8 +28,8 @@ impl<'a> AuthenticationRequest<'a> {
         AuthenticationRequest {
             username,
             password,
-            id: None,
-            owner_id: None,
+            id,
+            owner_id,
             owner_connection: true,
             client_type,
             serialization_version,
@@ -42,6 +44,8 @@ pub(crate) enum AuthenticationStatus {
     CredentialsFailed,
     SerializationVersionMismatch,
     NotAllowedInCluster,
+    #[display(fmt = "unknown status {}", _0)]
+    Unknown(u8),
 }
 
 #[derive(Response, Eq, PartialEq, Debug)]
@@ -52,7 +56,10 @@ pub(crate) struct AuthenticationResponse {
     id: Option<String>,
     owner_id: Option<String>,
     _serialization_version: u8,
-    _unregistered_cluster_members: Option<Vec<ClusterMember>>,
+    #[trailing]
+    server_version: Option<String>,
+    #[trailing]
+    _unregistered_cluster_members: Option<Option<Vec<ClusterMember>>>,
 }
 
 impl AuthenticationResponse {
@@ -62,7 +69,7 @@ impl AuthenticationResponse {
             1 => AuthenticationStatus::CredentialsFailed,
             2 => AuthenticationStatus::SerializationVersionMismatch,
             3 => AuthenticationStatus::NotAllowedInCluster,
-            _ => panic!("unknown status - {}", &self.status),
+            status => AuthenticationStatus::Unknown(*status),
         }
     }
 
@@ -77,6 +84,10 @@ impl AuthenticationResponse {
     pub(crate) fn owner_id(&self) -> &Option<String> {
         &self.owner_id
     }
+
+    pub(crate) fn server_version(&self) -> &Option<String> {
+        &self.server_version
+    }
 }
 
 #[cfg(test)]
@@ -89,20 +100,20 @@ mod tests {
 
     #[test]
     fn should_write_authentication_request() {
-        let request = AuthenticationRequest::new("username", "password", "Rust", 1, "1.0.0");
+        let request = AuthenticationRequest::new("username", "password", None, None, "Rust", 1, "1.0.0");
 
         let mut writeable = BytesMut::new();
         request.write_to(&mut writeable);
 
         let readable = &mut writeable.to_bytes();
-        assert_eq!(String::read_from(readable), request.username);
-        assert_eq!(String::read_from(readable), request.password);
-        assert_eq!(bool::read_from(readable), true);
-        assert_eq!(bool::read_from(readable), true);
-        assert_eq!(bool::read_from(readable), true);
-        assert_eq!(String::read_from(readable), request.client_type);
-        assert_eq!(u8::read_from(readable), request.serialization_version);
-        assert_eq!(String::read_from(readable), request.client_version);
+        assert_eq!(String::read_from(readable).unwrap(), request.username);
+        assert_eq!(String::read_from(readable).unwrap(), request.password);
+        assert!(bool::read_from(readable).unwrap());
+        assert!(bool::read_from(readable).unwrap());
+        assert!(bool::read_from(readable).unwrap());
+        assert_eq!(String::read_from(readable).unwrap(), request.client_type);
+        assert_eq!(u8::read_from(readable).unwrap(), request.serialization_version);
+        assert_eq!(String::read_from(readable).unwrap(), request.client_version);
     }
 
     #[test]
@@ -122,19 +133,94 @@ mod tests {
         id.write_to(writeable);
         owner_id.write_to(writeable);
         protocol_version.write_to(writeable);
+        "3.12.12".write_to(writeable);
         true.write_to(writeable);
 
         let readable = &mut writeable.to_bytes();
         assert_eq!(
-            AuthenticationResponse::read_from(readable),
+            AuthenticationResponse::read_from(readable).unwrap(),
             AuthenticationResponse {
                 status,
                 address,
                 id: id.map(str::to_string),
                 owner_id: owner_id.map(str::to_string),
                 _serialization_version: protocol_version,
-                _unregistered_cluster_members: None,
+                server_version: Some("3.12.12".to_string()),
+                _unregistered_cluster_members: Some(None),
             }
         );
     }
+
+    #[test]
+    fn should_report_unknown_authentication_status() {
+        let writeable = &mut BytesMut::new();
+        7u8.write_to(writeable);
+        Option::<Address>::None.write_to(writeable);
+        Option::<&str>::None.write_to(writeable);
+        Option::<&str>::None.write_to(writeable);
+        1u8.write_to(writeable);
+
+        let readable = &mut writeable.to_bytes();
+        let response = AuthenticationResponse::read_from(readable).unwrap();
+        assert_eq!(response.status().to_string(), "unknown status 7");
+    }
+
+    #[test]
+    fn should_read_authentication_response_without_address() {
+        let writeable = &mut BytesMut::new();
+        1u8.write_to(writeable);
+        Option::<Address>::None.write_to(writeable);
+        Option::<&str>::None.write_to(writeable);
+        Option::<&str>::None.write_to(writeable);
+        1u8.write_to(writeable);
+        "3.12.12".write_to(writeable);
+        true.write_to(writeable);
+
+        let readable = &mut writeable.to_bytes();
+        let response = AuthenticationResponse::read_from(readable).unwrap();
+        assert_eq!(response.address(), &None);
+        assert_eq!(response.server_version.as_deref(), Some("3.12.12"));
+        assert_eq!(response._unregistered_cluster_members, Some(None));
+        assert_eq!(readable.remaining(), 0);
+    }
+
+    #[test]
+    fn should_read_authentication_response_of_older_protocol() {
+        let writeable = &mut BytesMut::new();
+        0u8.write_to(writeable);
+        Some(Address {
+            host: "localhost".to_string(),
+            port: 5701,
+        })
+        .write_to(writeable);
+        Some("id").write_to(writeable);
+        Some("owner-id").write_to(writeable);
+        1u8.write_to(writeable);
+
+        let readable = &mut writeable.to_bytes();
+        let response = AuthenticationResponse::read_from(readable).unwrap();
+        assert_eq!(response.id().as_deref(), Some("id"));
+        assert_eq!(response.server_version, None);
+        assert_eq!(response._unregistered_cluster_members, None);
+    }
+
+    #[test]
+    fn should_read_authentication_response_of_newer_protocol() {
+        use crate::{messaging::Response, remote::Message, TryFrom};
+
+        let writeable = &mut BytesMut::new();
+        0u8.write_to(writeable);
+        Option::<Address>::None.write_to(writeable);
+        Some("id").write_to(writeable);
+        Some("owner-id").write_to(writeable);
+        1u8.write_to(writeable);
+        "3.12.12".write_to(writeable);
+        true.write_to(writeable);
+        "field-added-by-newer-server".write_to(writeable);
+        let frame = crate::remote::mock::frame(1, AuthenticationResponse::r#type(), &writeable.to_bytes());
+
+        let response: AuthenticationResponse = TryFrom::try_from(Message::decode(frame).unwrap()).unwrap();
+        assert_eq!(response.owner_id().as_deref(), Some("owner-id"));
+        assert_eq!(response.server_version.as_deref(), Some("3.12.12"));
+    }
 }
@@ -1,5 +1,28 @@
 use std::{error::Error, fmt};
 
+pub(crate) const CALLER_NOT_MEMBER: i32 = 8;
+pub(crate) const HAZELCAST_INSTANCE_NOT_ACTIVE: i32 = 21;
+pub(crate) const MEMBER_LEFT: i32 = 35;
+pub(crate) const PARTITION_MIGRATING: i32 = 41;
+pub(crate) const RETRYABLE_HAZELCAST: i32 = 49;
+pub(crate) const RETRYABLE_IO: i32 = 50;
+pub(crate) const TARGET_NOT_MEMBER: i32 = 56;
+pub(crate) const WRONG_TARGET: i32 = 66;
+pub(crate) const CONSISTENCY_LOST: i32 = 88;
+
+// raised while members shut down or partitions move between them, so repeating the invocation may succeed; a
+// disconnected target (55) is left out as it may have applied the invocation already, which must not happen twice
+const RETRYABLE: [i32; 8] = [
+    CALLER_NOT_MEMBER,
+    HAZELCAST_INSTANCE_NOT_ACTIVE,
+    MEMBER_LEFT,
+    PARTITION_MIGRATING,
+    RETRYABLE_HAZELCAST,
+    RETRYABLE_IO,
+    TARGET_NOT_MEMBER,
+    WRONG_TARGET,
+];
+
 #[derive(Response, Eq, PartialEq)]
 #[r#type = 0x6D]
 pub(crate) struct Exception {
@@ -11,6 +34,16 @@ pub(crate) struct Exception {
     cause_class_name: Option<String>,
 }
 
+impl Exception {
+    pub(crate) fn code(&self) -> i32 {
+        self.code
+    }
+
+    pub(crate) fn is_retryable(&self) -> bool {
+        RETRYABLE.contains(&self.code)
+    }
+}
+
 impl Error for Exception {}
 
 impl fmt::Display for Exception {
@@ -21,19 +54,19 @@ impl fmt::Display for Exception {
 
 impl fmt::Debug for Exception {
     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
+        writeln!(
             formatter,
-            "Error (code: {}, cause code: {}, cause class name: {:?}) {{\n",
+            "Error (code: {}, cause code: {}, cause class name: {:?}) {{",
             self.code, self.cause_error_code, self.cause_class_name
         )?;
-        write!(
+        writeln!(
             formatter,
-            "\t{}: {}\n",
+            "\t{}: {}",
             self.class_name,
             self.message.as_deref().unwrap_or("")
         )?;
         for stack_trace_entry in &self.stack_trace {
-            write!(formatter, "\t\t{}\n", stack_trace_entry)?;
+            writeln!(formatter, "\t\t{}", stack_trace_entry)?;
         }
         write!(formatter, "}}")
     }
@@ -92,7 +125,7 @@ mod tests {
 
         let readable = &mut writeable.to_bytes();
         assert_eq!(
-            Exception::read_from(readable),
+            Exception::read_from(readable).unwrap(),
             Exception {
                 code,
                 class_name: class_name.to_string(),
@@ -104,6 +137,76 @@ mod tests {
         );
     }
 
+    #[test]
+    fn should_decode_recorded_exception_message() {
+        use bytes::Bytes;
+
+        use crate::{messaging::pn_counter::PnCounterGetResponse, remote::Message, HazelcastClientError, TryFrom};
+
+        let frame = [
+            &[
+                1,   // version
+                192, // flags
+                0x6D, 0, // type
+                7, 0, 0, 0, 0, 0, 0, 0, // correlation id
+                255, 255, 255, 255, // partition id
+                22, 0, // data offset
+                21, 0, 0, 0, // code
+                54, 0, 0, 0, // class name length
+            ][..],
+            b"com.hazelcast.core.HazelcastInstanceNotActiveException",
+            &[0, 33, 0, 0, 0], // message presence and length
+            b"Hazelcast instance is not active!",
+            &[1, 0, 0, 0, 27, 0, 0, 0], // stack trace length and declaring class length
+            b"com.hazelcast.instance.Node",
+            &[8, 0, 0, 0], // method name length
+            b"getState",
+            &[0, 9, 0, 0, 0], // file name presence and length
+            b"Node.java",
+            &[88, 2, 0, 0],        // line number
+            &[255, 255, 255, 255], // cause error code
+            &[1],                  // cause class name presence
+        ]
+        .concat();
+
+        let message = Message::decode(Bytes::from(frame)).unwrap();
+        let exception = match TryFrom::<PnCounterGetResponse>::try_from(message) {
+            Err(HazelcastClientError::ServerFailure(e)) => e.downcast::<Exception>().unwrap(),
+            _ => panic!("expected server failure!"),
+        };
+        assert_eq!(
+            *exception,
+            Exception {
+                code: 21,
+                class_name: "com.hazelcast.core.HazelcastInstanceNotActiveException".to_string(),
+                message: Some("Hazelcast instance is not active!".to_string()),
+                stack_trace: vec![StackTraceEntry {
+                    declaring_class: "com.hazelcast.instance.Node".to_string(),
+                    method_name: "getState".to_string(),
+                    file_name: Some("Node.java".to_string()),
+                    line_number: 600,
+                }],
+                cause_error_code: u32::MAX,
+                cause_class_name: None,
+            }
+        );
+    }
+
+    #[test]
+    fn should_not_retry_disconnected_target() {
+        let exception = |code| Exception {
+            code,
+            class_name: "com.hazelcast.spi.exception.TargetDisconnectedException".to_string(),
+            message: None,
+            stack_trace: vec![],
+            cause_error_code: u32::MAX,
+            cause_class_name: None,
+        };
+
+        assert!(!exception(55).is_retryable());
+        assert!(exception(TARGET_NOT_MEMBER).is_retryable());
+    }
+
     #[test]
     fn should_read_stack_trace_entry() {
         let declaring_class = "NullPointerException";
@@ -119,7 +222,7 @@ mod tests {
 
         let readable = &mut writeable.to_bytes();
         assert_eq!(
-            StackTraceEntry::read_from(readable),
+            StackTraceEntry::read_from(readable).unwrap(),
             StackTraceEntry {
                 declaring_class: declaring_class.to_string(),
                 method_name: method_name.to_string(),
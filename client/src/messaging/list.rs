@@ -0,0 +1,176 @@
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x0501]
+pub(crate) struct ListSizeRequest<'a> {
+    name: &'a str,
+    #[partition_id]
+    partition_id: i32,
+}
+
+impl<'a> ListSizeRequest<'a> {
+    pub(crate) fn new(name: &'a str, partition_id: i32) -> Self {
+        ListSizeRequest { name, partition_id }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x66]
+pub(crate) struct ListSizeResponse {
+    size: i32,
+}
+
+impl ListSizeResponse {
+    pub(crate) fn size(&self) -> i32 {
+        self.size
+    }
+}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x0504]
+pub(crate) struct ListAddRequest<'a> {
+    name: &'a str,
+    value: &'a [u8],
+    #[partition_id]
+    partition_id: i32,
+}
+
+impl<'a> ListAddRequest<'a> {
+    pub(crate) fn new(name: &'a str, value: &'a [u8], partition_id: i32) -> Self {
+        ListAddRequest {
+            name,
+            value,
+            partition_id,
+        }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x65]
+pub(crate) struct ListAddResponse {
+    added: bool,
+}
+
+impl ListAddResponse {
+    pub(crate) fn added(&self) -> bool {
+        self.added
+    }
+}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x050F]
+pub(crate) struct ListGetRequest<'a> {
+    name: &'a str,
+    index: i32,
+    #[partition_id]
+    partition_id: i32,
+}
+
+impl<'a> ListGetRequest<'a> {
+    pub(crate) fn new(name: &'a str, index: i32, partition_id: i32) -> Self {
+        ListGetRequest {
+            name,
+            index,
+            partition_id,
+        }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x69]
+pub(crate) struct ListGetResponse {
+    value: Option<Vec<u8>>,
+}
+
+impl ListGetResponse {
+    pub(crate) fn value(self) -> Option<Vec<u8>> {
+        self.value
+    }
+}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x0512]
+pub(crate) struct ListRemoveWithIndexRequest<'a> {
+    name: &'a str,
+    index: i32,
+    #[partition_id]
+    partition_id: i32,
+}
+
+impl<'a> ListRemoveWithIndexRequest<'a> {
+    pub(crate) fn new(name: &'a str, index: i32, partition_id: i32) -> Self {
+        ListRemoveWithIndexRequest {
+            name,
+            index,
+            partition_id,
+        }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x69]
+pub(crate) struct ListRemoveWithIndexResponse {
+    value: Option<Vec<u8>>,
+}
+
+impl ListRemoveWithIndexResponse {
+    pub(crate) fn value(self) -> Option<Vec<u8>> {
+        self.value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::{Buf, BytesMut};
+
+    use crate::{
+        codec::{Reader, Writer},
+        messaging::Request,
+    };
+
+    use super::*;
+
+    #[test]
+    fn should_write_add_request() {
+        let value: &[u8] = &[1, 2, 3];
+        let request = ListAddRequest::new("list", value, 13);
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(String::read_from(readable).unwrap(), request.name);
+        assert_eq!(Vec::<u8>::read_from(readable).unwrap(), value);
+        assert_eq!(readable.remaining(), 0);
+        assert_eq!(request.partition_id(), 13);
+    }
+
+    #[test]
+    fn should_read_add_response() {
+        let writeable = &mut BytesMut::new();
+        true.write_to(writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert!(ListAddResponse::read_from(readable).unwrap().added());
+    }
+
+    #[test]
+    fn should_write_get_request() {
+        let request = ListGetRequest::new("list", 2, 13);
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(String::read_from(readable).unwrap(), request.name);
+        assert_eq!(i32::read_from(readable).unwrap(), request.index);
+        assert_eq!(readable.remaining(), 0);
+    }
+
+    #[test]
+    fn should_read_get_response() {
+        let writeable = &mut BytesMut::new();
+        Some(&[4u8, 2][..]).write_to(writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(ListGetResponse::read_from(readable).unwrap().value(), Some(vec![4, 2]));
+    }
+}
@@ -0,0 +1,163 @@
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x0705]
+pub(crate) struct LockLockRequest<'a> {
+    name: &'a str,
+    lease_time: i64,
+    thread_id: i64,
+    reference_id: i64,
+    #[partition_id]
+    partition_id: i32,
+}
+
+impl<'a> LockLockRequest<'a> {
+    pub(crate) fn new(name: &'a str, lease_time: i64, thread_id: i64, reference_id: i64, partition_id: i32) -> Self {
+        LockLockRequest {
+            name,
+            lease_time,
+            thread_id,
+            reference_id,
+            partition_id,
+        }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x64]
+pub(crate) struct LockLockResponse {}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x0706]
+pub(crate) struct LockUnlockRequest<'a> {
+    name: &'a str,
+    thread_id: i64,
+    reference_id: i64,
+    #[partition_id]
+    partition_id: i32,
+}
+
+impl<'a> LockUnlockRequest<'a> {
+    pub(crate) fn new(name: &'a str, thread_id: i64, reference_id: i64, partition_id: i32) -> Self {
+        LockUnlockRequest {
+            name,
+            thread_id,
+            reference_id,
+            partition_id,
+        }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x64]
+pub(crate) struct LockUnlockResponse {}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x0708]
+pub(crate) struct LockTryLockRequest<'a> {
+    name: &'a str,
+    thread_id: i64,
+    lease_time: i64,
+    timeout: i64,
+    reference_id: i64,
+    #[partition_id]
+    partition_id: i32,
+}
+
+impl<'a> LockTryLockRequest<'a> {
+    pub(crate) fn new(
+        name: &'a str,
+        thread_id: i64,
+        lease_time: i64,
+        timeout: i64,
+        reference_id: i64,
+        partition_id: i32,
+    ) -> Self {
+        LockTryLockRequest {
+            name,
+            thread_id,
+            lease_time,
+            timeout,
+            reference_id,
+            partition_id,
+        }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x65]
+pub(crate) struct LockTryLockResponse {
+    acquired: bool,
+}
+
+impl LockTryLockResponse {
+    pub(crate) fn acquired(&self) -> bool {
+        self.acquired
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::{Buf, BytesMut};
+
+    use crate::{
+        codec::{Reader, Writer},
+        messaging::Request,
+    };
+
+    use super::*;
+
+    #[test]
+    fn should_write_lock_request() {
+        let request = LockLockRequest::new("lock", -1, 1, 7, 13);
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(String::read_from(readable).unwrap(), request.name);
+        assert_eq!(i64::read_from(readable).unwrap(), request.lease_time);
+        assert_eq!(i64::read_from(readable).unwrap(), request.thread_id);
+        assert_eq!(i64::read_from(readable).unwrap(), request.reference_id);
+        assert_eq!(readable.remaining(), 0);
+        assert_eq!(request.partition_id(), 13);
+    }
+
+    #[test]
+    fn should_write_unlock_request() {
+        let request = LockUnlockRequest::new("lock", 1, 7, 13);
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(String::read_from(readable).unwrap(), request.name);
+        assert_eq!(i64::read_from(readable).unwrap(), request.thread_id);
+        assert_eq!(i64::read_from(readable).unwrap(), request.reference_id);
+        assert_eq!(readable.remaining(), 0);
+        assert_eq!(request.partition_id(), 13);
+    }
+
+    #[test]
+    fn should_write_try_lock_request() {
+        let request = LockTryLockRequest::new("lock", 1, -1, 0, 7, 13);
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(String::read_from(readable).unwrap(), request.name);
+        assert_eq!(i64::read_from(readable).unwrap(), request.thread_id);
+        assert_eq!(i64::read_from(readable).unwrap(), request.lease_time);
+        assert_eq!(i64::read_from(readable).unwrap(), request.timeout);
+        assert_eq!(i64::read_from(readable).unwrap(), request.reference_id);
+        assert_eq!(readable.remaining(), 0);
+    }
+
+    #[test]
+    fn should_read_try_lock_response() {
+        let writeable = &mut BytesMut::new();
+        false.write_to(writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert!(!LockTryLockResponse::read_from(readable).unwrap().acquired());
+    }
+}
@@ -0,0 +1,922 @@
+use crate::{
+    codec::{DecodeError, DecodeResult},
+    messaging::Registration,
+};
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x101]
+pub(crate) struct MapPutRequest<'a> {
+    name: &'a str,
+    key: &'a [u8],
+    value: &'a [u8],
+    thread_id: i64,
+    ttl: i64,
+    #[partition_id]
+    partition_id: i32,
+}
+
+impl<'a> MapPutRequest<'a> {
+    pub(crate) fn new(
+        name: &'a str,
+        key: &'a [u8],
+        value: &'a [u8],
+        thread_id: i64,
+        ttl: i64,
+        partition_id: i32,
+    ) -> Self {
+        MapPutRequest {
+            name,
+            key,
+            value,
+            thread_id,
+            ttl,
+            partition_id,
+        }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x69]
+pub(crate) struct MapPutResponse {
+    value: Option<Vec<u8>>,
+}
+
+impl MapPutResponse {
+    pub(crate) fn value(self) -> Option<Vec<u8>> {
+        self.value
+    }
+}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x102]
+pub(crate) struct MapGetRequest<'a> {
+    name: &'a str,
+    key: &'a [u8],
+    thread_id: i64,
+    #[partition_id]
+    partition_id: i32,
+}
+
+impl<'a> MapGetRequest<'a> {
+    pub(crate) fn new(name: &'a str, key: &'a [u8], thread_id: i64, partition_id: i32) -> Self {
+        MapGetRequest {
+            name,
+            key,
+            thread_id,
+            partition_id,
+        }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x69]
+pub(crate) struct MapGetResponse {
+    value: Option<Vec<u8>>,
+}
+
+impl MapGetResponse {
+    pub(crate) fn value(self) -> Option<Vec<u8>> {
+        self.value
+    }
+}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x103]
+pub(crate) struct MapRemoveRequest<'a> {
+    name: &'a str,
+    key: &'a [u8],
+    thread_id: i64,
+    #[partition_id]
+    partition_id: i32,
+}
+
+impl<'a> MapRemoveRequest<'a> {
+    pub(crate) fn new(name: &'a str, key: &'a [u8], thread_id: i64, partition_id: i32) -> Self {
+        MapRemoveRequest {
+            name,
+            key,
+            thread_id,
+            partition_id,
+        }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x69]
+pub(crate) struct MapRemoveResponse {
+    value: Option<Vec<u8>>,
+}
+
+impl MapRemoveResponse {
+    pub(crate) fn value(self) -> Option<Vec<u8>> {
+        self.value
+    }
+}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x124]
+pub(crate) struct MapLoadAllRequest<'a> {
+    name: &'a str,
+    replace_existing: bool,
+}
+
+impl<'a> MapLoadAllRequest<'a> {
+    pub(crate) fn new(name: &'a str, replace_existing: bool) -> Self {
+        MapLoadAllRequest { name, replace_existing }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x64]
+pub(crate) struct MapLoadAllResponse {}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x125]
+pub(crate) struct MapLoadGivenKeysRequest<'a> {
+    name: &'a str,
+    keys: &'a [&'a [u8]],
+    replace_existing: bool,
+}
+
+impl<'a> MapLoadGivenKeysRequest<'a> {
+    pub(crate) fn new(name: &'a str, keys: &'a [&'a [u8]], replace_existing: bool) -> Self {
+        MapLoadGivenKeysRequest {
+            name,
+            keys,
+            replace_existing,
+        }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x64]
+pub(crate) struct MapLoadGivenKeysResponse {}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x10C]
+pub(crate) struct MapDeleteRequest<'a> {
+    name: &'a str,
+    key: &'a [u8],
+    thread_id: i64,
+    #[partition_id]
+    partition_id: i32,
+}
+
+impl<'a> MapDeleteRequest<'a> {
+    pub(crate) fn new(name: &'a str, key: &'a [u8], thread_id: i64, partition_id: i32) -> Self {
+        MapDeleteRequest {
+            name,
+            key,
+            thread_id,
+            partition_id,
+        }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x64]
+pub(crate) struct MapDeleteResponse {}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x10F]
+pub(crate) struct MapTryPutRequest<'a> {
+    name: &'a str,
+    key: &'a [u8],
+    value: &'a [u8],
+    thread_id: i64,
+    timeout: i64,
+    #[partition_id]
+    partition_id: i32,
+}
+
+impl<'a> MapTryPutRequest<'a> {
+    pub(crate) fn new(
+        name: &'a str,
+        key: &'a [u8],
+        value: &'a [u8],
+        thread_id: i64,
+        timeout: i64,
+        partition_id: i32,
+    ) -> Self {
+        MapTryPutRequest {
+            name,
+            key,
+            value,
+            thread_id,
+            timeout,
+            partition_id,
+        }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x65]
+pub(crate) struct MapTryPutResponse {
+    value: bool,
+}
+
+impl MapTryPutResponse {
+    pub(crate) fn value(&self) -> bool {
+        self.value
+    }
+}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x14D]
+pub(crate) struct MapSetWithMaxIdleRequest<'a> {
+    name: &'a str,
+    key: &'a [u8],
+    value: &'a [u8],
+    thread_id: i64,
+    ttl: i64,
+    max_idle: i64,
+    #[partition_id]
+    partition_id: i32,
+}
+
+impl<'a> MapSetWithMaxIdleRequest<'a> {
+    pub(crate) fn new(
+        name: &'a str,
+        key: &'a [u8],
+        value: &'a [u8],
+        thread_id: i64,
+        ttl: i64,
+        max_idle: i64,
+        partition_id: i32,
+    ) -> Self {
+        MapSetWithMaxIdleRequest {
+            name,
+            key,
+            value,
+            thread_id,
+            ttl,
+            max_idle,
+            partition_id,
+        }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x69]
+pub(crate) struct MapSetWithMaxIdleResponse {
+    _value: Option<Vec<u8>>,
+}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x10A]
+pub(crate) struct MapContainsValueRequest<'a> {
+    name: &'a str,
+    value: &'a [u8],
+}
+
+impl<'a> MapContainsValueRequest<'a> {
+    pub(crate) fn new(name: &'a str, value: &'a [u8]) -> Self {
+        MapContainsValueRequest { name, value }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x65]
+pub(crate) struct MapContainsValueResponse {
+    value: bool,
+}
+
+impl MapContainsValueResponse {
+    pub(crate) fn value(&self) -> bool {
+        self.value
+    }
+}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x10B]
+pub(crate) struct MapRemoveIfSameRequest<'a> {
+    name: &'a str,
+    key: &'a [u8],
+    value: &'a [u8],
+    thread_id: i64,
+    #[partition_id]
+    partition_id: i32,
+}
+
+impl<'a> MapRemoveIfSameRequest<'a> {
+    pub(crate) fn new(name: &'a str, key: &'a [u8], value: &'a [u8], thread_id: i64, partition_id: i32) -> Self {
+        MapRemoveIfSameRequest {
+            name,
+            key,
+            value,
+            thread_id,
+            partition_id,
+        }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x65]
+pub(crate) struct MapRemoveIfSameResponse {
+    value: bool,
+}
+
+impl MapRemoveIfSameResponse {
+    pub(crate) fn value(&self) -> bool {
+        self.value
+    }
+}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x130]
+pub(crate) struct MapPutAllRequest<'a> {
+    name: &'a str,
+    entries: &'a [(&'a [u8], &'a [u8])],
+    #[partition_id]
+    partition_id: i32,
+}
+
+impl<'a> MapPutAllRequest<'a> {
+    pub(crate) fn new(name: &'a str, entries: &'a [(&'a [u8], &'a [u8])], partition_id: i32) -> Self {
+        MapPutAllRequest {
+            name,
+            entries,
+            partition_id,
+        }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x64]
+pub(crate) struct MapPutAllResponse {}
+
+pub(crate) const ADDED: i32 = 1;
+pub(crate) const REMOVED: i32 = 2;
+pub(crate) const UPDATED: i32 = 4;
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x11C]
+pub(crate) struct MapAddEntryListenerRequest<'a> {
+    name: &'a str,
+    include_value: bool,
+    listener_flags: i32,
+    local_only: bool,
+}
+
+impl<'a> MapAddEntryListenerRequest<'a> {
+    pub(crate) fn new(name: &'a str, include_value: bool, listener_flags: i32) -> Self {
+        MapAddEntryListenerRequest {
+            name,
+            include_value,
+            listener_flags,
+            local_only: false,
+        }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x68]
+pub(crate) struct MapAddEntryListenerResponse {
+    registration_id: String,
+}
+
+impl Registration for MapAddEntryListenerResponse {
+    fn registration_id(&self) -> &str {
+        &self.registration_id
+    }
+}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x11A]
+pub(crate) struct MapAddEntryListenerWithPredicateRequest<'a> {
+    name: &'a str,
+    predicate: &'a [u8],
+    include_value: bool,
+    listener_flags: i32,
+    local_only: bool,
+}
+
+impl<'a> MapAddEntryListenerWithPredicateRequest<'a> {
+    pub(crate) fn new(name: &'a str, predicate: &'a [u8], include_value: bool, listener_flags: i32) -> Self {
+        MapAddEntryListenerWithPredicateRequest {
+            name,
+            predicate,
+            include_value,
+            listener_flags,
+            local_only: false,
+        }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x68]
+pub(crate) struct MapAddEntryListenerWithPredicateResponse {
+    registration_id: String,
+}
+
+impl Registration for MapAddEntryListenerWithPredicateResponse {
+    fn registration_id(&self) -> &str {
+        &self.registration_id
+    }
+}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x11E]
+pub(crate) struct MapRemoveEntryListenerRequest {
+    name: String,
+    registration_id: String,
+}
+
+impl MapRemoveEntryListenerRequest {
+    pub(crate) fn new(name: String, registration_id: String) -> Self {
+        MapRemoveEntryListenerRequest { name, registration_id }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x65]
+pub(crate) struct MapRemoveEntryListenerResponse {
+    _removed: bool,
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0xCB]
+pub(crate) struct MapEntryEvent {
+    key: Option<Vec<u8>>,
+    value: Option<Vec<u8>>,
+    old_value: Option<Vec<u8>>,
+    merging_value: Option<Vec<u8>>,
+    event_type: i32,
+    member: String,
+    affected_entries: i32,
+}
+
+impl MapEntryEvent {
+    pub(crate) fn entry_event(self) -> DecodeResult<EntryEvent> {
+        let key = self.key.ok_or_else(|| DecodeError::new("entry event without key"))?;
+        match self.event_type {
+            ADDED => Ok(EntryEvent::Added { key, value: self.value }),
+            UPDATED => Ok(EntryEvent::Updated {
+                key,
+                old_value: self.old_value,
+                value: self.value,
+            }),
+            REMOVED => Ok(EntryEvent::Removed {
+                key,
+                old_value: self.old_value,
+            }),
+            other => Err(DecodeError::new(&format!("unknown entry event type: {}", other))),
+        }
+    }
+}
+
+// values are only present when the listener was added to include them
+#[derive(Eq, PartialEq, Debug, Clone)]
+pub enum EntryEvent {
+    Added {
+        key: Vec<u8>,
+        value: Option<Vec<u8>>,
+    },
+    Updated {
+        key: Vec<u8>,
+        old_value: Option<Vec<u8>>,
+        value: Option<Vec<u8>>,
+    },
+    Removed {
+        key: Vec<u8>,
+        old_value: Option<Vec<u8>>,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::{Buf, Bytes, BytesMut};
+
+    use crate::{
+        codec::{Reader, Writer},
+        messaging::Request,
+    };
+
+    use super::*;
+
+    #[test]
+    fn should_write_put_request() {
+        let (key, value): (&[u8], &[u8]) = (&[1, 2], &[3]);
+        let request = MapPutRequest::new("map-name", key, value, 1, -1, 5);
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(String::read_from(readable).unwrap(), request.name);
+        assert_eq!(Vec::<u8>::read_from(readable).unwrap(), key);
+        assert_eq!(Vec::<u8>::read_from(readable).unwrap(), value);
+        assert_eq!(i64::read_from(readable).unwrap(), request.thread_id);
+        assert_eq!(i64::read_from(readable).unwrap(), -1);
+        assert_eq!(readable.remaining(), 0);
+        assert_eq!(request.partition_id(), 5);
+    }
+
+    #[test]
+    fn should_read_put_response() {
+        let writeable = &mut BytesMut::new();
+        Some(&[4u8, 2][..]).write_to(writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(MapPutResponse::read_from(readable).unwrap().value(), Some(vec![4, 2]));
+    }
+
+    #[test]
+    fn should_write_get_request() {
+        let key: &[u8] = &[1, 2];
+        let request = MapGetRequest::new("map-name", key, 1, 5);
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(String::read_from(readable).unwrap(), request.name);
+        assert_eq!(Vec::<u8>::read_from(readable).unwrap(), key);
+        assert_eq!(i64::read_from(readable).unwrap(), request.thread_id);
+        assert_eq!(readable.remaining(), 0);
+        assert_eq!(request.partition_id(), 5);
+    }
+
+    #[test]
+    fn should_read_get_response() {
+        let writeable = &mut BytesMut::new();
+        Option::<&[u8]>::None.write_to(writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(MapGetResponse::read_from(readable).unwrap().value(), None);
+    }
+
+    #[test]
+    fn should_write_remove_request() {
+        let key: &[u8] = &[1, 2];
+        let request = MapRemoveRequest::new("map-name", key, 1, 5);
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(String::read_from(readable).unwrap(), request.name);
+        assert_eq!(Vec::<u8>::read_from(readable).unwrap(), key);
+        assert_eq!(i64::read_from(readable).unwrap(), request.thread_id);
+        assert_eq!(readable.remaining(), 0);
+        assert_eq!(request.partition_id(), 5);
+    }
+
+    #[test]
+    fn should_read_remove_response() {
+        let writeable = &mut BytesMut::new();
+        Some(&[7u8][..]).write_to(writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(MapRemoveResponse::read_from(readable).unwrap().value(), Some(vec![7]));
+    }
+
+    #[test]
+    fn should_write_load_all_request() {
+        let request = MapLoadAllRequest::new("map-name", true);
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(String::read_from(readable).unwrap(), request.name);
+        assert_eq!(bool::read_from(readable).unwrap(), request.replace_existing);
+    }
+
+    #[test]
+    fn should_read_load_all_response() {
+        let readable = &mut BytesMut::new().to_bytes();
+        assert_eq!(MapLoadAllResponse::read_from(readable).unwrap(), MapLoadAllResponse {});
+    }
+
+    #[test]
+    fn should_write_load_given_keys_request() {
+        let keys: &[&[u8]] = &[&[1, 2, 3], &[4]];
+        let request = MapLoadGivenKeysRequest::new("map-name", keys, false);
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(String::read_from(readable).unwrap(), request.name);
+        assert_eq!(
+            Vec::<Vec<u8>>::read_from(readable).unwrap(),
+            vec![vec![1, 2, 3], vec![4]]
+        );
+        assert_eq!(bool::read_from(readable).unwrap(), request.replace_existing);
+    }
+
+    #[test]
+    fn should_read_load_given_keys_response() {
+        let readable = &mut BytesMut::new().to_bytes();
+        assert_eq!(
+            MapLoadGivenKeysResponse::read_from(readable).unwrap(),
+            MapLoadGivenKeysResponse {}
+        );
+    }
+
+    #[test]
+    fn should_write_delete_request() {
+        let key: &[u8] = &[1, 0, 2];
+        let request = MapDeleteRequest::new("map-name", key, 1, 13);
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(String::read_from(readable).unwrap(), request.name);
+        assert_eq!(Vec::<u8>::read_from(readable).unwrap(), key);
+        assert_eq!(i64::read_from(readable).unwrap(), request.thread_id);
+        assert_eq!(readable.remaining(), 0);
+        assert_eq!(request.partition_id(), 13);
+    }
+
+    #[test]
+    fn should_read_delete_response() {
+        let readable = &mut BytesMut::new().to_bytes();
+        assert_eq!(MapDeleteResponse::read_from(readable).unwrap(), MapDeleteResponse {});
+    }
+
+    #[test]
+    fn should_write_put_all_request() {
+        let entries: &[(&[u8], &[u8])] = &[(&[1], &[2, 3]), (&[4], &[5])];
+        let request = MapPutAllRequest::new("map-name", entries, 7);
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(String::read_from(readable).unwrap(), request.name);
+        assert_eq!(
+            Vec::<(Vec<u8>, Vec<u8>)>::read_from(readable).unwrap(),
+            vec![(vec![1], vec![2, 3]), (vec![4], vec![5])]
+        );
+        assert_eq!(readable.remaining(), 0);
+        assert_eq!(request.partition_id(), 7);
+    }
+
+    #[test]
+    fn should_read_put_all_response() {
+        let readable = &mut BytesMut::new().to_bytes();
+        assert_eq!(MapPutAllResponse::read_from(readable).unwrap(), MapPutAllResponse {});
+    }
+
+    #[test]
+    fn should_write_try_put_request() {
+        let (key, value): (&[u8], &[u8]) = (&[1, 2], &[3]);
+        let request = MapTryPutRequest::new("map-name", key, value, 1, 500, 7);
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(String::read_from(readable).unwrap(), request.name);
+        assert_eq!(Vec::<u8>::read_from(readable).unwrap(), key);
+        assert_eq!(Vec::<u8>::read_from(readable).unwrap(), value);
+        assert_eq!(i64::read_from(readable).unwrap(), request.thread_id);
+        assert_eq!(i64::read_from(readable).unwrap(), 500);
+        assert_eq!(readable.remaining(), 0);
+        assert_eq!(request.partition_id(), 7);
+    }
+
+    #[test]
+    fn should_write_set_with_max_idle_request() {
+        let (key, value): (&[u8], &[u8]) = (&[1, 2], &[3]);
+        let request = MapSetWithMaxIdleRequest::new("map-name", key, value, 1, -1, 30_000, 7);
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(String::read_from(readable).unwrap(), request.name);
+        assert_eq!(Vec::<u8>::read_from(readable).unwrap(), key);
+        assert_eq!(Vec::<u8>::read_from(readable).unwrap(), value);
+        assert_eq!(i64::read_from(readable).unwrap(), request.thread_id);
+        assert_eq!(i64::read_from(readable).unwrap(), -1);
+        assert_eq!(i64::read_from(readable).unwrap(), 30_000);
+        assert_eq!(readable.remaining(), 0);
+        assert_eq!(request.partition_id(), 7);
+        assert_eq!(MapSetWithMaxIdleRequest::r#type(), 0x14D);
+    }
+
+    #[test]
+    fn should_read_try_put_response() {
+        let writeable = &mut BytesMut::new();
+        false.write_to(writeable);
+
+        let readable = &mut writeable.to_bytes();
+        let response = MapTryPutResponse::read_from(readable).unwrap();
+        assert!(!response.value());
+    }
+
+    #[test]
+    fn should_write_contains_value_request() {
+        let value: &[u8] = &[4, 2];
+        let request = MapContainsValueRequest::new("map-name", value);
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(String::read_from(readable).unwrap(), request.name);
+        assert_eq!(Vec::<u8>::read_from(readable).unwrap(), value);
+        assert_eq!(readable.remaining(), 0);
+        assert_eq!(request.partition_id(), -1);
+    }
+
+    #[test]
+    fn should_read_contains_value_response() {
+        for value in &[true, false] {
+            let writeable = &mut BytesMut::new();
+            value.write_to(writeable);
+
+            let readable = &mut writeable.to_bytes();
+            assert_eq!(MapContainsValueResponse::read_from(readable).unwrap().value(), *value);
+        }
+    }
+
+    #[test]
+    fn should_write_remove_if_same_request() {
+        let (key, value): (&[u8], &[u8]) = (&[1, 2], &[3]);
+        let request = MapRemoveIfSameRequest::new("map-name", key, value, 1, 11);
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(String::read_from(readable).unwrap(), request.name);
+        assert_eq!(Vec::<u8>::read_from(readable).unwrap(), key);
+        assert_eq!(Vec::<u8>::read_from(readable).unwrap(), value);
+        assert_eq!(i64::read_from(readable).unwrap(), request.thread_id);
+        assert_eq!(readable.remaining(), 0);
+        assert_eq!(request.partition_id(), 11);
+    }
+
+    #[test]
+    fn should_read_remove_if_same_response() {
+        for value in &[true, false] {
+            let writeable = &mut BytesMut::new();
+            value.write_to(writeable);
+
+            let readable = &mut writeable.to_bytes();
+            assert_eq!(MapRemoveIfSameResponse::read_from(readable).unwrap().value(), *value);
+        }
+    }
+
+    #[test]
+    fn should_write_add_entry_listener_request() {
+        let request = MapAddEntryListenerRequest::new("map-name", true, ADDED | REMOVED | UPDATED);
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(String::read_from(readable).unwrap(), request.name);
+        assert_eq!(bool::read_from(readable).unwrap(), request.include_value);
+        assert_eq!(i32::read_from(readable).unwrap(), 7);
+        assert!(!bool::read_from(readable).unwrap());
+        assert_eq!(readable.remaining(), 0);
+    }
+
+    #[test]
+    fn should_encode_add_entry_listener_request_as_recorded() {
+        use crate::remote::Message;
+
+        let frame = [
+            &[
+                1,   // version
+                192, // flags
+                0x1C, 0x01, // type
+                3, 0, 0, 0, 0, 0, 0, 0, // correlation id
+                255, 255, 255, 255, // partition id
+                22, 0, // data offset
+                8, 0, 0, 0, // name length
+            ][..],
+            b"map-name",
+            &[1],          // include value
+            &[7, 0, 0, 0], // listener flags
+            &[0],          // local only
+        ]
+        .concat();
+
+        let request = MapAddEntryListenerRequest::new("map-name", true, ADDED | REMOVED | UPDATED);
+        assert_eq!(Message::encode(3, &request).payload(), frame);
+    }
+
+    #[test]
+    fn should_read_added_entry_event() {
+        let frame: &[u8] = &[
+            0, 1, 0, 0, 0, 7, // key
+            0, 2, 0, 0, 0, 8, 9, // value
+            1, // old value
+            1, // merging value
+            1, 0, 0, 0, // event type
+            6, 0, 0, 0, b'm', b'e', b'm', b'b', b'e', b'r', // member
+            1, 0, 0, 0, // affected entries
+        ];
+
+        let event = MapEntryEvent::read_from(&mut Bytes::copy_from_slice(frame)).unwrap();
+        assert_eq!(
+            event.entry_event().unwrap(),
+            EntryEvent::Added {
+                key: vec![7],
+                value: Some(vec![8, 9]),
+            }
+        );
+    }
+
+    #[test]
+    fn should_read_updated_entry_event() {
+        let writeable = &mut BytesMut::new();
+        let (key, value, old_value): (&[u8], &[u8], &[u8]) = (&[1], &[3], &[2]);
+        Some(key).write_to(writeable);
+        Some(value).write_to(writeable);
+        Some(old_value).write_to(writeable);
+        Option::<&[u8]>::None.write_to(writeable);
+        UPDATED.write_to(writeable);
+        "member".write_to(writeable);
+        1i32.write_to(writeable);
+
+        let event = MapEntryEvent::read_from(&mut writeable.to_bytes()).unwrap();
+        assert_eq!(
+            event.entry_event().unwrap(),
+            EntryEvent::Updated {
+                key: vec![1],
+                old_value: Some(vec![2]),
+                value: Some(vec![3]),
+            }
+        );
+    }
+
+    #[test]
+    fn should_fail_to_read_entry_event_of_unknown_type() {
+        let writeable = &mut BytesMut::new();
+        let key: &[u8] = &[1];
+        Some(key).write_to(writeable);
+        for _ in 0..3 {
+            Option::<&[u8]>::None.write_to(writeable);
+        }
+        64i32.write_to(writeable);
+        "member".write_to(writeable);
+        1i32.write_to(writeable);
+
+        let event = MapEntryEvent::read_from(&mut writeable.to_bytes()).unwrap();
+        assert!(event.entry_event().is_err());
+    }
+
+    #[test]
+    fn should_encode_remove_entry_listener_request_as_recorded() {
+        use crate::remote::Message;
+
+        let frame = [
+            &[
+                1,   // version
+                192, // flags
+                0x1E, 0x01, // type
+                4, 0, 0, 0, 0, 0, 0, 0, // correlation id
+                255, 255, 255, 255, // partition id
+                22, 0, // data offset
+                8, 0, 0, 0, // name length
+            ][..],
+            b"map-name",
+            &[15, 0, 0, 0], // registration id length
+            b"registration-id",
+        ]
+        .concat();
+
+        let request = MapRemoveEntryListenerRequest::new("map-name".to_string(), "registration-id".to_string());
+        assert_eq!(Message::encode(4, &request).payload(), frame);
+    }
+
+    #[test]
+    fn should_write_add_entry_listener_with_predicate_request() {
+        let predicate: &[u8] = &[0, 0, 0, 0, 255, 255, 255, 254, 7];
+        let request = MapAddEntryListenerWithPredicateRequest::new("map-name", predicate, true, 1 | 4);
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(String::read_from(readable).unwrap(), request.name);
+        assert_eq!(Vec::<u8>::read_from(readable).unwrap(), predicate);
+        assert_eq!(bool::read_from(readable).unwrap(), request.include_value);
+        assert_eq!(i32::read_from(readable).unwrap(), request.listener_flags);
+        assert!(!bool::read_from(readable).unwrap());
+    }
+
+    #[test]
+    fn should_read_add_entry_listener_with_predicate_response() {
+        let registration_id = "registration-id";
+
+        let writeable = &mut BytesMut::new();
+        registration_id.write_to(writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(
+            MapAddEntryListenerWithPredicateResponse::read_from(readable).unwrap(),
+            MapAddEntryListenerWithPredicateResponse {
+                registration_id: registration_id.to_string()
+            }
+        );
+    }
+}
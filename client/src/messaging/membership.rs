@@ -0,0 +1,148 @@
+use crate::messaging::{ClusterMember, MemberInfo};
+
+pub(crate) const MEMBER_ADDED: i32 = 1;
+pub(crate) const MEMBER_REMOVED: i32 = 2;
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x4]
+pub(crate) struct AddMembershipListenerRequest {
+    local_only: bool,
+}
+
+impl AddMembershipListenerRequest {
+    pub(crate) fn new(local_only: bool) -> Self {
+        AddMembershipListenerRequest { local_only }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x68]
+pub(crate) struct AddMembershipListenerResponse {
+    _registration_id: String,
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0xC8]
+pub(crate) struct MemberEvent {
+    member: ClusterMember,
+    event_type: i32,
+}
+
+impl MemberEvent {
+    pub(crate) fn member(self) -> ClusterMember {
+        self.member
+    }
+
+    pub(crate) fn event_type(&self) -> i32 {
+        self.event_type
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0xC9]
+pub(crate) struct MemberListEvent {
+    members: Vec<ClusterMember>,
+}
+
+impl MemberListEvent {
+    pub(crate) fn members(self) -> Vec<ClusterMember> {
+        self.members
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum MembershipEventKind {
+    Added,
+    Removed,
+}
+
+#[derive(Eq, PartialEq, Debug, Clone)]
+pub struct MembershipEvent {
+    member: MemberInfo,
+    kind: MembershipEventKind,
+}
+
+impl MembershipEvent {
+    pub(crate) fn new(member: MemberInfo, kind: MembershipEventKind) -> Self {
+        MembershipEvent { member, kind }
+    }
+
+    pub fn member(&self) -> &MemberInfo {
+        &self.member
+    }
+
+    pub fn kind(&self) -> MembershipEventKind {
+        self.kind
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::{Buf, BytesMut};
+
+    use crate::{
+        codec::{Reader, Writer},
+        messaging::Address,
+    };
+
+    use super::*;
+
+    #[test]
+    fn should_write_add_membership_listener_request() {
+        let request = AddMembershipListenerRequest::new(false);
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert!(!bool::read_from(readable).unwrap());
+        assert_eq!(readable.remaining(), 0);
+    }
+
+    #[test]
+    fn should_read_add_membership_listener_response() {
+        let writeable = &mut BytesMut::new();
+        "registration-id".write_to(writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(
+            AddMembershipListenerResponse::read_from(readable).unwrap(),
+            AddMembershipListenerResponse {
+                _registration_id: "registration-id".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn should_read_member_event() {
+        let writeable = &mut BytesMut::new();
+        write_member(writeable, "member-id");
+        MEMBER_REMOVED.write_to(writeable);
+
+        let readable = &mut writeable.to_bytes();
+        let event = MemberEvent::read_from(readable).unwrap();
+        assert_eq!(event.event_type(), MEMBER_REMOVED);
+        assert_eq!(event.member().id, "member-id");
+    }
+
+    #[test]
+    fn should_read_member_list_event() {
+        let writeable = &mut BytesMut::new();
+        2u32.write_to(writeable);
+        write_member(writeable, "first");
+        write_member(writeable, "second");
+
+        let readable = &mut writeable.to_bytes();
+        let members = MemberListEvent::read_from(readable).unwrap().members();
+        assert_eq!(members.len(), 2);
+        assert_eq!(members[0].id, "first");
+        assert_eq!(members[1].id, "second");
+    }
+
+    fn write_member(writeable: &mut BytesMut, id: &str) {
+        Address::from(&"127.0.0.1:5701".parse().unwrap()).write_to(writeable);
+        id.write_to(writeable);
+        false.write_to(writeable);
+        0u32.write_to(writeable);
+    }
+}
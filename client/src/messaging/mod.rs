@@ -1,13 +1,34 @@
-use std::net::SocketAddr;
+use std::{
+    collections::HashMap,
+    convert::TryInto,
+    io::{self, ErrorKind},
+    net::SocketAddr,
+};
 
 use derive_more::Display;
 
-use crate::codec::{Reader, Writer};
+use crate::{
+    codec::{Reader, Writer},
+    runtime::{DefaultRuntime, Runtime},
+};
 
+pub(crate) mod atomic_long;
 pub(crate) mod authentication;
 pub(crate) mod error;
+pub(crate) mod list;
+pub(crate) mod lock;
+pub(crate) mod map;
+pub(crate) mod membership;
+pub(crate) mod multi_map;
+pub(crate) mod partition;
 pub(crate) mod ping;
 pub(crate) mod pn_counter;
+pub(crate) mod queue;
+pub(crate) mod ringbuffer;
+pub(crate) mod semaphore;
+pub(crate) mod set;
+pub(crate) mod topic;
+pub(crate) mod transaction;
 
 pub(crate) trait Request: Writer {
     fn r#type() -> u16;
@@ -28,6 +49,20 @@ pub(crate) struct Address {
     port: u32,
 }
 
+impl Address {
+    pub(crate) async fn resolve(&self) -> io::Result<SocketAddr> {
+        let port = self
+            .port
+            .try_into()
+            .map_err(|_| io::Error::new(ErrorKind::InvalidInput, format!("invalid port of {}", self)))?;
+        DefaultRuntime::resolve(&self.host, port)
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| io::Error::new(ErrorKind::NotFound, format!("unable to resolve {}", self)))
+    }
+}
+
 impl From<&std::net::SocketAddr> for Address {
     fn from(address: &SocketAddr) -> Self {
         Address {
@@ -42,13 +77,85 @@ pub(crate) struct ClusterMember {
     address: Address,
     id: String,
     lite: bool,
-    attributes: Vec<AttributeEntry>,
+    attributes: Vec<(String, String)>,
+}
+
+impl ClusterMember {
+    pub(crate) fn address(&self) -> &Address {
+        &self.address
+    }
 }
 
-#[derive(Reader, Eq, PartialEq, Debug, Clone)]
-pub(crate) struct AttributeEntry {
-    _key: String,
-    _value: String,
+#[derive(Eq, PartialEq, Debug, Clone)]
+pub struct MemberInfo {
+    id: String,
+    address: String,
+    lite: bool,
+    attributes: HashMap<String, String>,
+}
+
+impl MemberInfo {
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn address(&self) -> &str {
+        &self.address
+    }
+
+    pub fn is_lite(&self) -> bool {
+        self.lite
+    }
+
+    pub fn attributes(&self) -> &HashMap<String, String> {
+        &self.attributes
+    }
+}
+
+impl From<ClusterMember> for MemberInfo {
+    fn from(member: ClusterMember) -> Self {
+        MemberInfo {
+            id: member.id,
+            address: member.address.to_string(),
+            lite: member.lite,
+            attributes: member.attributes.into_iter().collect(),
+        }
+    }
+}
+
+pub(crate) trait Registration {
+    fn registration_id(&self) -> &str;
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum ListenerKind {
+    PartitionLost,
+    Topic,
+    MapEntry,
+}
+
+#[derive(Eq, PartialEq, Debug, Clone)]
+pub struct ListenerInfo {
+    registration_id: String,
+    kind: ListenerKind,
+}
+
+impl ListenerInfo {
+    pub(crate) fn new(registration_id: &str, kind: ListenerKind) -> Self {
+        ListenerInfo {
+            registration_id: registration_id.to_string(),
+            kind,
+        }
+    }
+
+    pub fn registration_id(&self) -> &str {
+        &self.registration_id
+    }
+
+    pub fn kind(&self) -> ListenerKind {
+        self.kind
+    }
 }
 
 #[derive(Writer, Reader, Eq, PartialEq, Debug, Clone)]
@@ -76,7 +183,42 @@ mod tests {
         address.write_to(&mut writeable);
 
         let readable = &mut writeable.to_bytes();
-        assert_eq!(Address::read_from(readable), address);
+        assert_eq!(Address::read_from(readable).unwrap(), address);
+    }
+
+    #[test]
+    fn should_read_address_with_hostname() {
+        let writeable = &mut BytesMut::new();
+        "member-1.hazelcast.svc".write_to(writeable);
+        5701u32.write_to(writeable);
+
+        let readable = &mut writeable.to_bytes();
+        let address = Address::read_from(readable).unwrap();
+        assert_eq!(address.host, "member-1.hazelcast.svc");
+        assert_eq!(address.port, 5701);
+        assert_eq!(address.to_string(), "member-1.hazelcast.svc:5701");
+    }
+
+    #[tokio::test]
+    async fn should_resolve_address_with_hostname() {
+        let address = Address {
+            host: "localhost".to_string(),
+            port: 5701,
+        };
+
+        let resolved = address.resolve().await.unwrap();
+        assert!(resolved.ip().is_loopback());
+        assert_eq!(resolved.port(), 5701);
+    }
+
+    #[tokio::test]
+    async fn should_fail_to_resolve_address_with_invalid_port() {
+        let address = Address {
+            host: "localhost".to_string(),
+            port: 70000,
+        };
+
+        assert_eq!(address.resolve().await.unwrap_err().kind(), ErrorKind::InvalidInput);
     }
 
     #[test]
@@ -96,7 +238,7 @@ mod tests {
 
         let readable = &mut writeable.to_bytes();
         assert_eq!(
-            ClusterMember::read_from(readable),
+            ClusterMember::read_from(readable).unwrap(),
             ClusterMember {
                 address,
                 id: id.to_string(),
@@ -107,22 +249,26 @@ mod tests {
     }
 
     #[test]
-    fn should_read_attribute() {
-        let key = "key";
-        let value = "value";
-
+    fn should_expose_attributes_of_member() {
         let writeable = &mut BytesMut::new();
-        key.write_to(writeable);
-        value.write_to(writeable);
+        "member-1.hazelcast.svc".write_to(writeable);
+        5701u32.write_to(writeable);
+        "member-id".write_to(writeable);
+        false.write_to(writeable);
+        2u32.write_to(writeable);
+        for (key, value) in &[("zone", "eu-west-1a"), ("rack", "r7")] {
+            key.write_to(writeable);
+            value.write_to(writeable);
+        }
 
         let readable = &mut writeable.to_bytes();
-        assert_eq!(
-            AttributeEntry::read_from(readable),
-            AttributeEntry {
-                _key: key.to_string(),
-                _value: value.to_string(),
-            }
-        );
+        let member: MemberInfo = ClusterMember::read_from(readable).unwrap().into();
+        assert_eq!(member.id(), "member-id");
+        assert_eq!(member.address(), "member-1.hazelcast.svc:5701");
+        assert!(!member.is_lite());
+        assert_eq!(member.attributes().len(), 2);
+        assert_eq!(member.attributes()["zone"], "eu-west-1a");
+        assert_eq!(member.attributes()["rack"], "r7");
     }
 
     #[test]
@@ -136,8 +282,8 @@ mod tests {
         replica_timestamp.write_to(writeable);
 
         let readable = &mut writeable.to_bytes();
-        assert_eq!(String::read_from(readable), replica_timestamp.key);
-        assert_eq!(i64::read_from(readable), replica_timestamp.value);
+        assert_eq!(String::read_from(readable).unwrap(), replica_timestamp.key);
+        assert_eq!(i64::read_from(readable).unwrap(), replica_timestamp.value);
     }
 
     #[test]
@@ -151,11 +297,36 @@ mod tests {
 
         let readable = &mut writeable.to_bytes();
         assert_eq!(
-            ReplicaTimestampEntry::read_from(readable),
+            ReplicaTimestampEntry::read_from(readable).unwrap(),
             ReplicaTimestampEntry {
                 key: key.to_string(),
                 value,
             }
         );
     }
+
+    #[test]
+    fn should_write_and_read_replica_timestamp_list() {
+        let replica_timestamps = vec![
+            ReplicaTimestampEntry {
+                key: "a".to_string(),
+                value: 1,
+            },
+            ReplicaTimestampEntry {
+                key: "longer-key".to_string(),
+                value: 2,
+            },
+        ];
+
+        let writeable = &mut BytesMut::new();
+        (&replica_timestamps[..]).write_to(writeable);
+        assert_eq!((&replica_timestamps[..]).length(), writeable.len());
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(
+            Vec::<ReplicaTimestampEntry>::read_from(readable).unwrap(),
+            replica_timestamps
+        );
+        assert_eq!(readable.remaining(), 0);
+    }
 }
@@ -0,0 +1,210 @@
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x0201]
+pub(crate) struct MultiMapPutRequest<'a> {
+    name: &'a str,
+    key: &'a [u8],
+    value: &'a [u8],
+    thread_id: i64,
+    #[partition_id]
+    partition_id: i32,
+}
+
+impl<'a> MultiMapPutRequest<'a> {
+    pub(crate) fn new(name: &'a str, key: &'a [u8], value: &'a [u8], thread_id: i64, partition_id: i32) -> Self {
+        MultiMapPutRequest {
+            name,
+            key,
+            value,
+            thread_id,
+            partition_id,
+        }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x65]
+pub(crate) struct MultiMapPutResponse {
+    added: bool,
+}
+
+impl MultiMapPutResponse {
+    pub(crate) fn added(&self) -> bool {
+        self.added
+    }
+}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x0202]
+pub(crate) struct MultiMapGetRequest<'a> {
+    name: &'a str,
+    key: &'a [u8],
+    thread_id: i64,
+    #[partition_id]
+    partition_id: i32,
+}
+
+impl<'a> MultiMapGetRequest<'a> {
+    pub(crate) fn new(name: &'a str, key: &'a [u8], thread_id: i64, partition_id: i32) -> Self {
+        MultiMapGetRequest {
+            name,
+            key,
+            thread_id,
+            partition_id,
+        }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x6A]
+pub(crate) struct MultiMapGetResponse {
+    values: Vec<Vec<u8>>,
+}
+
+impl MultiMapGetResponse {
+    pub(crate) fn values(self) -> Vec<Vec<u8>> {
+        self.values
+    }
+}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x0203]
+pub(crate) struct MultiMapRemoveRequest<'a> {
+    name: &'a str,
+    key: &'a [u8],
+    thread_id: i64,
+    #[partition_id]
+    partition_id: i32,
+}
+
+impl<'a> MultiMapRemoveRequest<'a> {
+    pub(crate) fn new(name: &'a str, key: &'a [u8], thread_id: i64, partition_id: i32) -> Self {
+        MultiMapRemoveRequest {
+            name,
+            key,
+            thread_id,
+            partition_id,
+        }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x6A]
+pub(crate) struct MultiMapRemoveResponse {
+    values: Vec<Vec<u8>>,
+}
+
+impl MultiMapRemoveResponse {
+    pub(crate) fn values(self) -> Vec<Vec<u8>> {
+        self.values
+    }
+}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x020C]
+pub(crate) struct MultiMapValueCountRequest<'a> {
+    name: &'a str,
+    key: &'a [u8],
+    thread_id: i64,
+    #[partition_id]
+    partition_id: i32,
+}
+
+impl<'a> MultiMapValueCountRequest<'a> {
+    pub(crate) fn new(name: &'a str, key: &'a [u8], thread_id: i64, partition_id: i32) -> Self {
+        MultiMapValueCountRequest {
+            name,
+            key,
+            thread_id,
+            partition_id,
+        }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x66]
+pub(crate) struct MultiMapValueCountResponse {
+    count: i32,
+}
+
+impl MultiMapValueCountResponse {
+    pub(crate) fn count(&self) -> i32 {
+        self.count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::{Buf, BytesMut};
+
+    use crate::{
+        codec::{Reader, Writer},
+        messaging::Request,
+    };
+
+    use super::*;
+
+    #[test]
+    fn should_write_put_request() {
+        let (key, value): (&[u8], &[u8]) = (&[1, 2], &[3]);
+        let request = MultiMapPutRequest::new("multi-map", key, value, 1, 13);
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(String::read_from(readable).unwrap(), request.name);
+        assert_eq!(Vec::<u8>::read_from(readable).unwrap(), key);
+        assert_eq!(Vec::<u8>::read_from(readable).unwrap(), value);
+        assert_eq!(i64::read_from(readable).unwrap(), request.thread_id);
+        assert_eq!(readable.remaining(), 0);
+        assert_eq!(request.partition_id(), 13);
+    }
+
+    #[test]
+    fn should_write_get_request() {
+        let key: &[u8] = &[1, 2];
+        let request = MultiMapGetRequest::new("multi-map", key, 1, 13);
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(String::read_from(readable).unwrap(), request.name);
+        assert_eq!(Vec::<u8>::read_from(readable).unwrap(), key);
+        assert_eq!(i64::read_from(readable).unwrap(), request.thread_id);
+        assert_eq!(readable.remaining(), 0);
+    }
+
+    #[test]
+    fn should_read_get_response_with_multiple_values() {
+        let values: &[&[u8]] = &[&[1], &[2, 3], &[]];
+
+        let writeable = &mut BytesMut::new();
+        values.write_to(writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(
+            MultiMapGetResponse::read_from(readable).unwrap().values(),
+            vec![vec![1], vec![2, 3], vec![]]
+        );
+        assert_eq!(readable.remaining(), 0);
+    }
+
+    #[test]
+    fn should_read_remove_response_without_values() {
+        let writeable = &mut BytesMut::new();
+        0u32.write_to(writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert!(MultiMapRemoveResponse::read_from(readable).unwrap().values().is_empty());
+    }
+
+    #[test]
+    fn should_read_value_count_response() {
+        let writeable = &mut BytesMut::new();
+        2i32.write_to(writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(MultiMapValueCountResponse::read_from(readable).unwrap().count(), 2);
+    }
+}
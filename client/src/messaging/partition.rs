@@ -0,0 +1,181 @@
+use crate::messaging::{Address, Registration};
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x8]
+pub(crate) struct GetPartitionsRequest {}
+
+impl GetPartitionsRequest {
+    pub(crate) fn new() -> Self {
+        GetPartitionsRequest {}
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x6C]
+pub(crate) struct GetPartitionsResponse {
+    partitions: Vec<(Address, Vec<i32>)>,
+    _state_version: i32,
+}
+
+impl GetPartitionsResponse {
+    pub(crate) fn partitions(&self) -> &[(Address, Vec<i32>)] {
+        &self.partitions
+    }
+}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0xA]
+pub(crate) struct AddPartitionLostListenerRequest {
+    local_only: bool,
+}
+
+impl AddPartitionLostListenerRequest {
+    pub(crate) fn new(local_only: bool) -> Self {
+        AddPartitionLostListenerRequest { local_only }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x68]
+pub(crate) struct AddPartitionLostListenerResponse {
+    registration_id: String,
+}
+
+impl Registration for AddPartitionLostListenerResponse {
+    fn registration_id(&self) -> &str {
+        &self.registration_id
+    }
+}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0xB]
+pub(crate) struct RemovePartitionLostListenerRequest {
+    registration_id: String,
+}
+
+impl RemovePartitionLostListenerRequest {
+    pub(crate) fn new(registration_id: String) -> Self {
+        RemovePartitionLostListenerRequest { registration_id }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x65]
+pub(crate) struct RemovePartitionLostListenerResponse {
+    _removed: bool,
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0xCE]
+pub struct PartitionLostEvent {
+    partition_id: i32,
+    lost_backup_count: i32,
+    source: Option<Address>,
+}
+
+impl PartitionLostEvent {
+    pub fn partition_id(&self) -> i32 {
+        self.partition_id
+    }
+
+    pub fn lost_backup_count(&self) -> i32 {
+        self.lost_backup_count
+    }
+
+    pub fn source(&self) -> Option<String> {
+        self.source.as_ref().map(Address::to_string)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::{Buf, BytesMut};
+
+    use crate::codec::{Reader, Writer};
+
+    use super::*;
+
+    #[test]
+    fn should_write_get_partitions_request() {
+        let request = GetPartitionsRequest::new();
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(readable.bytes(), []);
+    }
+
+    #[test]
+    fn should_read_get_partitions_response() {
+        let address = Address {
+            host: "localhost".to_string(),
+            port: 5701,
+        };
+        let partition_ids = [0i32, 1, 2];
+        let state_version = 7;
+
+        let writeable = &mut BytesMut::new();
+        1u32.write_to(writeable);
+        address.write_to(writeable);
+        (&partition_ids[..]).write_to(writeable);
+        state_version.write_to(writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(
+            GetPartitionsResponse::read_from(readable).unwrap(),
+            GetPartitionsResponse {
+                partitions: vec![(address, partition_ids.to_vec())],
+                _state_version: state_version,
+            }
+        );
+    }
+
+    #[test]
+    fn should_write_add_partition_lost_listener_request() {
+        let request = AddPartitionLostListenerRequest::new(true);
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert!(bool::read_from(readable).unwrap());
+        assert_eq!(readable.remaining(), 0);
+    }
+
+    #[test]
+    fn should_read_add_partition_lost_listener_response() {
+        let writeable = &mut BytesMut::new();
+        "registration-id".write_to(writeable);
+
+        let readable = &mut writeable.to_bytes();
+        let response = AddPartitionLostListenerResponse::read_from(readable).unwrap();
+        assert_eq!(response.registration_id(), "registration-id");
+    }
+
+    #[test]
+    fn should_write_remove_partition_lost_listener_request() {
+        let request = RemovePartitionLostListenerRequest::new("registration-id".to_string());
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(String::read_from(readable).unwrap(), "registration-id");
+        assert_eq!(readable.remaining(), 0);
+    }
+
+    #[test]
+    fn should_read_partition_lost_event() {
+        let writeable = &mut BytesMut::new();
+        13i32.write_to(writeable);
+        2i32.write_to(writeable);
+        Some(Address::from(&"127.0.0.1:5701".parse().unwrap())).write_to(writeable);
+
+        let readable = &mut writeable.to_bytes();
+        let event = PartitionLostEvent::read_from(readable).unwrap();
+        assert_eq!(event.partition_id(), 13);
+        assert_eq!(event.lost_backup_count(), 2);
+        assert_eq!(event.source().as_deref(), Some("127.0.0.1:5701"));
+    }
+}
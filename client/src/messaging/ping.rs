@@ -34,6 +34,6 @@ mod tests {
     #[test]
     fn should_read_ping_response() {
         let readable = &mut BytesMut::new().to_bytes();
-        assert_eq!(PingResponse::read_from(readable), PingResponse {});
+        assert_eq!(PingResponse::read_from(readable).unwrap(), PingResponse {});
     }
 }
@@ -131,12 +131,12 @@ mod tests {
         request.write_to(&mut writeable);
 
         let readable = &mut writeable.to_bytes();
-        assert_eq!(String::read_from(readable), request.name);
+        assert_eq!(String::read_from(readable).unwrap(), request.name);
         assert_eq!(
-            Vec::<ReplicaTimestampEntry>::read_from(readable).deref(),
+            Vec::<ReplicaTimestampEntry>::read_from(readable).unwrap().deref(),
             replica_timestamps
         );
-        assert_eq!(&Address::read_from(readable), request.address);
+        assert_eq!(&Address::read_from(readable).unwrap(), request.address);
     }
 
     #[test]
@@ -153,7 +153,7 @@ mod tests {
 
         let readable = &mut writeable.to_bytes();
         assert_eq!(
-            PnCounterGetResponse::read_from(readable),
+            PnCounterGetResponse::read_from(readable).unwrap(),
             PnCounterGetResponse {
                 value,
                 replica_timestamps,
@@ -177,14 +177,14 @@ mod tests {
         request.write_to(&mut writeable);
 
         let readable = &mut writeable.to_bytes();
-        assert_eq!(String::read_from(readable), request.name);
-        assert_eq!(i64::read_from(readable), request.delta);
-        assert_eq!(bool::read_from(readable), request.get_before_update);
+        assert_eq!(String::read_from(readable).unwrap(), request.name);
+        assert_eq!(i64::read_from(readable).unwrap(), request.delta);
+        assert_eq!(bool::read_from(readable).unwrap(), request.get_before_update);
         assert_eq!(
-            Vec::<ReplicaTimestampEntry>::read_from(readable).deref(),
+            Vec::<ReplicaTimestampEntry>::read_from(readable).unwrap().deref(),
             replica_timestamps
         );
-        assert_eq!(&Address::read_from(readable), request.address);
+        assert_eq!(&Address::read_from(readable).unwrap(), request.address);
     }
 
     #[test]
@@ -203,7 +203,7 @@ mod tests {
 
         let readable = &mut writeable.to_bytes();
         assert_eq!(
-            PnCounterAddResponse::read_from(readable),
+            PnCounterAddResponse::read_from(readable).unwrap(),
             PnCounterAddResponse {
                 value,
                 replica_timestamps,
@@ -220,7 +220,7 @@ mod tests {
         request.write_to(&mut writeable);
 
         let readable = &mut writeable.to_bytes();
-        assert_eq!(String::read_from(readable), request.name);
+        assert_eq!(String::read_from(readable).unwrap(), request.name);
     }
 
     #[test]
@@ -232,7 +232,7 @@ mod tests {
 
         let readable = &mut writeable.to_bytes();
         assert_eq!(
-            PnCounterGetReplicaCountResponse::read_from(readable),
+            PnCounterGetReplicaCountResponse::read_from(readable).unwrap(),
             PnCounterGetReplicaCountResponse { count }
         );
     }
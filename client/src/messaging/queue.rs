@@ -0,0 +1,186 @@
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x0301]
+pub(crate) struct QueueOfferRequest<'a> {
+    name: &'a str,
+    value: &'a [u8],
+    timeout: i64,
+    #[partition_id]
+    partition_id: i32,
+}
+
+impl<'a> QueueOfferRequest<'a> {
+    pub(crate) fn new(name: &'a str, value: &'a [u8], timeout: i64, partition_id: i32) -> Self {
+        QueueOfferRequest {
+            name,
+            value,
+            timeout,
+            partition_id,
+        }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x65]
+pub(crate) struct QueueOfferResponse {
+    offered: bool,
+}
+
+impl QueueOfferResponse {
+    pub(crate) fn offered(&self) -> bool {
+        self.offered
+    }
+}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x0303]
+pub(crate) struct QueueSizeRequest<'a> {
+    name: &'a str,
+    #[partition_id]
+    partition_id: i32,
+}
+
+impl<'a> QueueSizeRequest<'a> {
+    pub(crate) fn new(name: &'a str, partition_id: i32) -> Self {
+        QueueSizeRequest { name, partition_id }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x66]
+pub(crate) struct QueueSizeResponse {
+    size: i32,
+}
+
+impl QueueSizeResponse {
+    pub(crate) fn size(&self) -> i32 {
+        self.size
+    }
+}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x0305]
+pub(crate) struct QueuePollRequest<'a> {
+    name: &'a str,
+    timeout: i64,
+    #[partition_id]
+    partition_id: i32,
+}
+
+impl<'a> QueuePollRequest<'a> {
+    pub(crate) fn new(name: &'a str, timeout: i64, partition_id: i32) -> Self {
+        QueuePollRequest {
+            name,
+            timeout,
+            partition_id,
+        }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x69]
+pub(crate) struct QueuePollResponse {
+    value: Option<Vec<u8>>,
+}
+
+impl QueuePollResponse {
+    pub(crate) fn value(self) -> Option<Vec<u8>> {
+        self.value
+    }
+}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x0307]
+pub(crate) struct QueuePeekRequest<'a> {
+    name: &'a str,
+    #[partition_id]
+    partition_id: i32,
+}
+
+impl<'a> QueuePeekRequest<'a> {
+    pub(crate) fn new(name: &'a str, partition_id: i32) -> Self {
+        QueuePeekRequest { name, partition_id }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x69]
+pub(crate) struct QueuePeekResponse {
+    value: Option<Vec<u8>>,
+}
+
+impl QueuePeekResponse {
+    pub(crate) fn value(self) -> Option<Vec<u8>> {
+        self.value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::{Buf, BytesMut};
+
+    use crate::{
+        codec::{Reader, Writer},
+        messaging::Request,
+    };
+
+    use super::*;
+
+    #[test]
+    fn should_write_offer_request() {
+        let value: &[u8] = &[1, 2, 3];
+        let request = QueueOfferRequest::new("queue", value, 0, 13);
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(String::read_from(readable).unwrap(), request.name);
+        assert_eq!(Vec::<u8>::read_from(readable).unwrap(), value);
+        assert_eq!(i64::read_from(readable).unwrap(), request.timeout);
+        assert_eq!(readable.remaining(), 0);
+        assert_eq!(request.partition_id(), 13);
+    }
+
+    #[test]
+    fn should_write_poll_request() {
+        let request = QueuePollRequest::new("queue", 0, 13);
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(String::read_from(readable).unwrap(), request.name);
+        assert_eq!(i64::read_from(readable).unwrap(), request.timeout);
+        assert_eq!(readable.remaining(), 0);
+    }
+
+    #[test]
+    fn should_read_poll_response() {
+        let writeable = &mut BytesMut::new();
+        Some(&[4u8, 2][..]).write_to(writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(
+            QueuePollResponse::read_from(readable).unwrap().value(),
+            Some(vec![4, 2])
+        );
+    }
+
+    #[test]
+    fn should_read_poll_response_of_empty_queue() {
+        let writeable = &mut BytesMut::new();
+        Option::<&[u8]>::None.write_to(writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(QueuePollResponse::read_from(readable).unwrap().value(), None);
+    }
+
+    #[test]
+    fn should_read_size_response() {
+        let writeable = &mut BytesMut::new();
+        3i32.write_to(writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(QueueSizeResponse::read_from(readable).unwrap().size(), 3);
+    }
+}
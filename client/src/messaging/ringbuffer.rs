@@ -0,0 +1,178 @@
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x1902]
+pub(crate) struct RingbufferTailSequenceRequest<'a> {
+    name: &'a str,
+    #[partition_id]
+    partition_id: i32,
+}
+
+impl<'a> RingbufferTailSequenceRequest<'a> {
+    pub(crate) fn new(name: &'a str, partition_id: i32) -> Self {
+        RingbufferTailSequenceRequest { name, partition_id }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x67]
+pub(crate) struct RingbufferTailSequenceResponse {
+    sequence: i64,
+}
+
+impl RingbufferTailSequenceResponse {
+    pub(crate) fn sequence(&self) -> i64 {
+        self.sequence
+    }
+}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x1904]
+pub(crate) struct RingbufferCapacityRequest<'a> {
+    name: &'a str,
+    #[partition_id]
+    partition_id: i32,
+}
+
+impl<'a> RingbufferCapacityRequest<'a> {
+    pub(crate) fn new(name: &'a str, partition_id: i32) -> Self {
+        RingbufferCapacityRequest { name, partition_id }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x67]
+pub(crate) struct RingbufferCapacityResponse {
+    capacity: i64,
+}
+
+impl RingbufferCapacityResponse {
+    pub(crate) fn capacity(&self) -> i64 {
+        self.capacity
+    }
+}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x1906]
+pub(crate) struct RingbufferAddRequest<'a> {
+    name: &'a str,
+    overflow_policy: i32,
+    value: &'a [u8],
+    #[partition_id]
+    partition_id: i32,
+}
+
+impl<'a> RingbufferAddRequest<'a> {
+    pub(crate) fn new(name: &'a str, overflow_policy: i32, value: &'a [u8], partition_id: i32) -> Self {
+        RingbufferAddRequest {
+            name,
+            overflow_policy,
+            value,
+            partition_id,
+        }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x67]
+pub(crate) struct RingbufferAddResponse {
+    sequence: i64,
+}
+
+impl RingbufferAddResponse {
+    pub(crate) fn sequence(&self) -> i64 {
+        self.sequence
+    }
+}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x1907]
+pub(crate) struct RingbufferReadOneRequest<'a> {
+    name: &'a str,
+    sequence: i64,
+    #[partition_id]
+    partition_id: i32,
+}
+
+impl<'a> RingbufferReadOneRequest<'a> {
+    pub(crate) fn new(name: &'a str, sequence: i64, partition_id: i32) -> Self {
+        RingbufferReadOneRequest {
+            name,
+            sequence,
+            partition_id,
+        }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x69]
+pub(crate) struct RingbufferReadOneResponse {
+    value: Option<Vec<u8>>,
+}
+
+impl RingbufferReadOneResponse {
+    pub(crate) fn value(self) -> Option<Vec<u8>> {
+        self.value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::{Buf, BytesMut};
+
+    use crate::{
+        codec::{Reader, Writer},
+        messaging::Request,
+    };
+
+    use super::*;
+
+    #[test]
+    fn should_write_add_request() {
+        let value: &[u8] = &[1, 2, 3];
+        let request = RingbufferAddRequest::new("ringbuffer", 0, value, 13);
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(String::read_from(readable).unwrap(), request.name);
+        assert_eq!(i32::read_from(readable).unwrap(), request.overflow_policy);
+        assert_eq!(Vec::<u8>::read_from(readable).unwrap(), value);
+        assert_eq!(readable.remaining(), 0);
+        assert_eq!(request.partition_id(), 13);
+    }
+
+    #[test]
+    fn should_read_add_response() {
+        let writeable = &mut BytesMut::new();
+        42i64.write_to(writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(RingbufferAddResponse::read_from(readable).unwrap().sequence(), 42);
+    }
+
+    #[test]
+    fn should_write_read_one_request() {
+        let request = RingbufferReadOneRequest::new("ringbuffer", 42, 13);
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(String::read_from(readable).unwrap(), request.name);
+        assert_eq!(i64::read_from(readable).unwrap(), request.sequence);
+        assert_eq!(readable.remaining(), 0);
+        assert_eq!(request.partition_id(), 13);
+    }
+
+    #[test]
+    fn should_read_read_one_response() {
+        let writeable = &mut BytesMut::new();
+        Some(&[4u8, 2][..]).write_to(writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(
+            RingbufferReadOneResponse::read_from(readable).unwrap().value(),
+            Some(vec![4, 2])
+        );
+    }
+}
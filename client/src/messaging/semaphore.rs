@@ -0,0 +1,168 @@
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x0D01]
+pub(crate) struct SemaphoreInitRequest<'a> {
+    name: &'a str,
+    permits: i32,
+    #[partition_id]
+    partition_id: i32,
+}
+
+impl<'a> SemaphoreInitRequest<'a> {
+    pub(crate) fn new(name: &'a str, permits: i32, partition_id: i32) -> Self {
+        SemaphoreInitRequest {
+            name,
+            permits,
+            partition_id,
+        }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x65]
+pub(crate) struct SemaphoreInitResponse {
+    initialized: bool,
+}
+
+impl SemaphoreInitResponse {
+    pub(crate) fn initialized(&self) -> bool {
+        self.initialized
+    }
+}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x0D02]
+pub(crate) struct SemaphoreAcquireRequest<'a> {
+    name: &'a str,
+    permits: i32,
+    #[partition_id]
+    partition_id: i32,
+}
+
+impl<'a> SemaphoreAcquireRequest<'a> {
+    pub(crate) fn new(name: &'a str, permits: i32, partition_id: i32) -> Self {
+        SemaphoreAcquireRequest {
+            name,
+            permits,
+            partition_id,
+        }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x64]
+pub(crate) struct SemaphoreAcquireResponse {}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x0D03]
+pub(crate) struct SemaphoreAvailablePermitsRequest<'a> {
+    name: &'a str,
+    #[partition_id]
+    partition_id: i32,
+}
+
+impl<'a> SemaphoreAvailablePermitsRequest<'a> {
+    pub(crate) fn new(name: &'a str, partition_id: i32) -> Self {
+        SemaphoreAvailablePermitsRequest { name, partition_id }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x66]
+pub(crate) struct SemaphoreAvailablePermitsResponse {
+    permits: i32,
+}
+
+impl SemaphoreAvailablePermitsResponse {
+    pub(crate) fn permits(&self) -> i32 {
+        self.permits
+    }
+}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x0D06]
+pub(crate) struct SemaphoreReleaseRequest<'a> {
+    name: &'a str,
+    permits: i32,
+    #[partition_id]
+    partition_id: i32,
+}
+
+impl<'a> SemaphoreReleaseRequest<'a> {
+    pub(crate) fn new(name: &'a str, permits: i32, partition_id: i32) -> Self {
+        SemaphoreReleaseRequest {
+            name,
+            permits,
+            partition_id,
+        }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x64]
+pub(crate) struct SemaphoreReleaseResponse {}
+
+#[cfg(test)]
+mod tests {
+    use bytes::{Buf, BytesMut};
+
+    use crate::{
+        codec::{Reader, Writer},
+        messaging::Request,
+    };
+
+    use super::*;
+
+    #[test]
+    fn should_write_init_request() {
+        let request = SemaphoreInitRequest::new("semaphore", 3, 13);
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(String::read_from(readable).unwrap(), request.name);
+        assert_eq!(i32::read_from(readable).unwrap(), request.permits);
+        assert_eq!(readable.remaining(), 0);
+        assert_eq!(request.partition_id(), 13);
+    }
+
+    #[test]
+    fn should_write_acquire_request() {
+        let request = SemaphoreAcquireRequest::new("semaphore", 2, 13);
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(String::read_from(readable).unwrap(), request.name);
+        assert_eq!(i32::read_from(readable).unwrap(), request.permits);
+        assert_eq!(readable.remaining(), 0);
+    }
+
+    #[test]
+    fn should_write_release_request() {
+        let request = SemaphoreReleaseRequest::new("semaphore", 2, 13);
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(String::read_from(readable).unwrap(), request.name);
+        assert_eq!(i32::read_from(readable).unwrap(), request.permits);
+        assert_eq!(readable.remaining(), 0);
+    }
+
+    #[test]
+    fn should_read_available_permits_response() {
+        let writeable = &mut BytesMut::new();
+        5i32.write_to(writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(
+            SemaphoreAvailablePermitsResponse::read_from(readable)
+                .unwrap()
+                .permits(),
+            5
+        );
+    }
+}
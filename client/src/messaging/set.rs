@@ -0,0 +1,178 @@
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x0601]
+pub(crate) struct SetSizeRequest<'a> {
+    name: &'a str,
+    #[partition_id]
+    partition_id: i32,
+}
+
+impl<'a> SetSizeRequest<'a> {
+    pub(crate) fn new(name: &'a str, partition_id: i32) -> Self {
+        SetSizeRequest { name, partition_id }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x66]
+pub(crate) struct SetSizeResponse {
+    size: i32,
+}
+
+impl SetSizeResponse {
+    pub(crate) fn size(&self) -> i32 {
+        self.size
+    }
+}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x0602]
+pub(crate) struct SetContainsRequest<'a> {
+    name: &'a str,
+    value: &'a [u8],
+    #[partition_id]
+    partition_id: i32,
+}
+
+impl<'a> SetContainsRequest<'a> {
+    pub(crate) fn new(name: &'a str, value: &'a [u8], partition_id: i32) -> Self {
+        SetContainsRequest {
+            name,
+            value,
+            partition_id,
+        }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x65]
+pub(crate) struct SetContainsResponse {
+    contains: bool,
+}
+
+impl SetContainsResponse {
+    pub(crate) fn contains(&self) -> bool {
+        self.contains
+    }
+}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x0604]
+pub(crate) struct SetAddRequest<'a> {
+    name: &'a str,
+    value: &'a [u8],
+    #[partition_id]
+    partition_id: i32,
+}
+
+impl<'a> SetAddRequest<'a> {
+    pub(crate) fn new(name: &'a str, value: &'a [u8], partition_id: i32) -> Self {
+        SetAddRequest {
+            name,
+            value,
+            partition_id,
+        }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x65]
+pub(crate) struct SetAddResponse {
+    added: bool,
+}
+
+impl SetAddResponse {
+    pub(crate) fn added(&self) -> bool {
+        self.added
+    }
+}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x0605]
+pub(crate) struct SetRemoveRequest<'a> {
+    name: &'a str,
+    value: &'a [u8],
+    #[partition_id]
+    partition_id: i32,
+}
+
+impl<'a> SetRemoveRequest<'a> {
+    pub(crate) fn new(name: &'a str, value: &'a [u8], partition_id: i32) -> Self {
+        SetRemoveRequest {
+            name,
+            value,
+            partition_id,
+        }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x65]
+pub(crate) struct SetRemoveResponse {
+    removed: bool,
+}
+
+impl SetRemoveResponse {
+    pub(crate) fn removed(&self) -> bool {
+        self.removed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::{Buf, BytesMut};
+
+    use crate::{
+        codec::{Reader, Writer},
+        messaging::Request,
+    };
+
+    use super::*;
+
+    #[test]
+    fn should_write_add_request() {
+        let value: &[u8] = &[1, 2, 3];
+        let request = SetAddRequest::new("set", value, 13);
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(String::read_from(readable).unwrap(), request.name);
+        assert_eq!(Vec::<u8>::read_from(readable).unwrap(), value);
+        assert_eq!(readable.remaining(), 0);
+        assert_eq!(request.partition_id(), 13);
+    }
+
+    #[test]
+    fn should_write_contains_request() {
+        let value: &[u8] = &[1, 2, 3];
+        let request = SetContainsRequest::new("set", value, 13);
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(String::read_from(readable).unwrap(), request.name);
+        assert_eq!(Vec::<u8>::read_from(readable).unwrap(), value);
+        assert_eq!(readable.remaining(), 0);
+    }
+
+    #[test]
+    fn should_read_bool_responses() {
+        for value in &[true, false] {
+            let writeable = &mut BytesMut::new();
+            value.write_to(writeable);
+            let bytes = writeable.to_bytes();
+
+            assert_eq!(SetAddResponse::read_from(&mut bytes.clone()).unwrap().added(), *value);
+            assert_eq!(
+                SetContainsResponse::read_from(&mut bytes.clone()).unwrap().contains(),
+                *value
+            );
+            assert_eq!(
+                SetRemoveResponse::read_from(&mut bytes.clone()).unwrap().removed(),
+                *value
+            );
+        }
+    }
+}
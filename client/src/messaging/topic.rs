@@ -0,0 +1,158 @@
+use crate::{messaging::Registration, serialization::FromData, Result};
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x0401]
+pub(crate) struct TopicPublishRequest<'a> {
+    name: &'a str,
+    message: &'a [u8],
+    #[partition_id]
+    partition_id: i32,
+}
+
+impl<'a> TopicPublishRequest<'a> {
+    pub(crate) fn new(name: &'a str, message: &'a [u8], partition_id: i32) -> Self {
+        TopicPublishRequest {
+            name,
+            message,
+            partition_id,
+        }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x64]
+pub(crate) struct TopicPublishResponse {}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x0402]
+pub(crate) struct TopicAddMessageListenerRequest<'a> {
+    name: &'a str,
+    local_only: bool,
+}
+
+impl<'a> TopicAddMessageListenerRequest<'a> {
+    pub(crate) fn new(name: &'a str, local_only: bool) -> Self {
+        TopicAddMessageListenerRequest { name, local_only }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x68]
+pub(crate) struct TopicAddMessageListenerResponse {
+    registration_id: String,
+}
+
+impl Registration for TopicAddMessageListenerResponse {
+    fn registration_id(&self) -> &str {
+        &self.registration_id
+    }
+}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x0403]
+pub(crate) struct TopicRemoveMessageListenerRequest {
+    name: String,
+    registration_id: String,
+}
+
+impl TopicRemoveMessageListenerRequest {
+    pub(crate) fn new(name: String, registration_id: String) -> Self {
+        TopicRemoveMessageListenerRequest { name, registration_id }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x65]
+pub(crate) struct TopicRemoveMessageListenerResponse {
+    _removed: bool,
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0xCD]
+pub struct TopicMessage {
+    message: Vec<u8>,
+    publish_time: i64,
+    publisher: String,
+}
+
+impl TopicMessage {
+    pub fn message<T: FromData>(&self) -> Result<T> {
+        T::from_data(&self.message)
+    }
+
+    pub fn publish_time(&self) -> i64 {
+        self.publish_time
+    }
+
+    pub fn publisher(&self) -> &str {
+        &self.publisher
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::{Buf, BytesMut};
+
+    use crate::{
+        codec::{Reader, Writer},
+        messaging::Request,
+    };
+
+    use super::*;
+
+    #[test]
+    fn should_write_publish_request() {
+        let message: &[u8] = &[1, 2, 3];
+        let request = TopicPublishRequest::new("topic", message, 13);
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(String::read_from(readable).unwrap(), request.name);
+        assert_eq!(Vec::<u8>::read_from(readable).unwrap(), message);
+        assert_eq!(readable.remaining(), 0);
+        assert_eq!(request.partition_id(), 13);
+    }
+
+    #[test]
+    fn should_write_add_message_listener_request() {
+        let request = TopicAddMessageListenerRequest::new("topic", false);
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(String::read_from(readable).unwrap(), request.name);
+        assert_eq!(bool::read_from(readable).unwrap(), request.local_only);
+        assert_eq!(readable.remaining(), 0);
+    }
+
+    #[test]
+    fn should_write_remove_message_listener_request() {
+        let request = TopicRemoveMessageListenerRequest::new("topic".to_string(), "registration-id".to_string());
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(String::read_from(readable).unwrap(), request.name);
+        assert_eq!(String::read_from(readable).unwrap(), request.registration_id);
+        assert_eq!(readable.remaining(), 0);
+    }
+
+    #[test]
+    fn should_read_topic_message() {
+        let writeable = &mut BytesMut::new();
+        let message: &[u8] = &[1, 2];
+        Writer::write_to(&message, writeable);
+        7i64.write_to(writeable);
+        "publisher".write_to(writeable);
+
+        let readable = &mut writeable.to_bytes();
+        let message = TopicMessage::read_from(readable).unwrap();
+        assert_eq!(message.message::<Vec<u8>>().unwrap(), vec![1, 2]);
+        assert_eq!(message.publish_time(), 7);
+        assert_eq!(message.publisher(), "publisher");
+    }
+}
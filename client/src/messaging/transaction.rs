@@ -0,0 +1,306 @@
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x1702]
+pub(crate) struct TransactionCreateRequest {
+    timeout: i64,
+    durability: i32,
+    transaction_type: i32,
+    thread_id: i64,
+}
+
+impl TransactionCreateRequest {
+    pub(crate) fn new(timeout: i64, durability: i32, transaction_type: i32, thread_id: i64) -> Self {
+        TransactionCreateRequest {
+            timeout,
+            durability,
+            transaction_type,
+            thread_id,
+        }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x68]
+pub(crate) struct TransactionCreateResponse {
+    transaction_id: String,
+}
+
+impl TransactionCreateResponse {
+    pub(crate) fn transaction_id(self) -> String {
+        self.transaction_id
+    }
+}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x1701]
+pub(crate) struct TransactionCommitRequest<'a> {
+    transaction_id: &'a str,
+    thread_id: i64,
+}
+
+impl<'a> TransactionCommitRequest<'a> {
+    pub(crate) fn new(transaction_id: &'a str, thread_id: i64) -> Self {
+        TransactionCommitRequest {
+            transaction_id,
+            thread_id,
+        }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x64]
+pub(crate) struct TransactionCommitResponse {}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x1703]
+pub(crate) struct TransactionRollbackRequest<'a> {
+    transaction_id: &'a str,
+    thread_id: i64,
+}
+
+impl<'a> TransactionRollbackRequest<'a> {
+    pub(crate) fn new(transaction_id: &'a str, thread_id: i64) -> Self {
+        TransactionRollbackRequest {
+            transaction_id,
+            thread_id,
+        }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x64]
+pub(crate) struct TransactionRollbackResponse {}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x1002]
+pub(crate) struct TransactionalMapGetRequest<'a> {
+    name: &'a str,
+    transaction_id: &'a str,
+    thread_id: i64,
+    key: &'a [u8],
+}
+
+impl<'a> TransactionalMapGetRequest<'a> {
+    pub(crate) fn new(name: &'a str, transaction_id: &'a str, thread_id: i64, key: &'a [u8]) -> Self {
+        TransactionalMapGetRequest {
+            name,
+            transaction_id,
+            thread_id,
+            key,
+        }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x69]
+pub(crate) struct TransactionalMapGetResponse {
+    value: Option<Vec<u8>>,
+}
+
+impl TransactionalMapGetResponse {
+    pub(crate) fn value(self) -> Option<Vec<u8>> {
+        self.value
+    }
+}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x1006]
+pub(crate) struct TransactionalMapPutRequest<'a> {
+    name: &'a str,
+    transaction_id: &'a str,
+    thread_id: i64,
+    key: &'a [u8],
+    value: &'a [u8],
+    ttl: i64,
+}
+
+impl<'a> TransactionalMapPutRequest<'a> {
+    pub(crate) fn new(
+        name: &'a str,
+        transaction_id: &'a str,
+        thread_id: i64,
+        key: &'a [u8],
+        value: &'a [u8],
+        ttl: i64,
+    ) -> Self {
+        TransactionalMapPutRequest {
+            name,
+            transaction_id,
+            thread_id,
+            key,
+            value,
+            ttl,
+        }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x69]
+pub(crate) struct TransactionalMapPutResponse {
+    value: Option<Vec<u8>>,
+}
+
+impl TransactionalMapPutResponse {
+    pub(crate) fn value(self) -> Option<Vec<u8>> {
+        self.value
+    }
+}
+
+#[derive(Request, Eq, PartialEq, Debug)]
+#[r#type = 0x100B]
+pub(crate) struct TransactionalMapRemoveRequest<'a> {
+    name: &'a str,
+    transaction_id: &'a str,
+    thread_id: i64,
+    key: &'a [u8],
+}
+
+impl<'a> TransactionalMapRemoveRequest<'a> {
+    pub(crate) fn new(name: &'a str, transaction_id: &'a str, thread_id: i64, key: &'a [u8]) -> Self {
+        TransactionalMapRemoveRequest {
+            name,
+            transaction_id,
+            thread_id,
+            key,
+        }
+    }
+}
+
+#[derive(Response, Eq, PartialEq, Debug)]
+#[r#type = 0x69]
+pub(crate) struct TransactionalMapRemoveResponse {
+    value: Option<Vec<u8>>,
+}
+
+impl TransactionalMapRemoveResponse {
+    pub(crate) fn value(self) -> Option<Vec<u8>> {
+        self.value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::{Buf, BytesMut};
+
+    use crate::{
+        codec::{Reader, Writer},
+        messaging::Request,
+    };
+
+    use super::*;
+
+    #[test]
+    fn should_write_create_request() {
+        let request = TransactionCreateRequest::new(120_000, 1, 1, 7);
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(i64::read_from(readable).unwrap(), request.timeout);
+        assert_eq!(i32::read_from(readable).unwrap(), request.durability);
+        assert_eq!(i32::read_from(readable).unwrap(), request.transaction_type);
+        assert_eq!(i64::read_from(readable).unwrap(), request.thread_id);
+        assert_eq!(readable.remaining(), 0);
+        assert_eq!(request.partition_id(), -1);
+    }
+
+    #[test]
+    fn should_read_create_response() {
+        let writeable = &mut BytesMut::new();
+        "transaction-id".write_to(writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(
+            TransactionCreateResponse::read_from(readable).unwrap().transaction_id(),
+            "transaction-id"
+        );
+    }
+
+    #[test]
+    fn should_write_commit_request() {
+        let request = TransactionCommitRequest::new("transaction-id", 7);
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(String::read_from(readable).unwrap(), request.transaction_id);
+        assert_eq!(i64::read_from(readable).unwrap(), request.thread_id);
+        assert_eq!(readable.remaining(), 0);
+    }
+
+    #[test]
+    fn should_write_rollback_request() {
+        let request = TransactionRollbackRequest::new("transaction-id", 7);
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(String::read_from(readable).unwrap(), request.transaction_id);
+        assert_eq!(i64::read_from(readable).unwrap(), request.thread_id);
+        assert_eq!(readable.remaining(), 0);
+    }
+
+    #[test]
+    fn should_write_transactional_map_get_request() {
+        let key: &[u8] = &[1, 2];
+        let request = TransactionalMapGetRequest::new("map-name", "transaction-id", 7, key);
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(String::read_from(readable).unwrap(), request.name);
+        assert_eq!(String::read_from(readable).unwrap(), request.transaction_id);
+        assert_eq!(i64::read_from(readable).unwrap(), request.thread_id);
+        assert_eq!(Vec::<u8>::read_from(readable).unwrap(), key);
+        assert_eq!(readable.remaining(), 0);
+    }
+
+    #[test]
+    fn should_write_transactional_map_put_request() {
+        let (key, value): (&[u8], &[u8]) = (&[1, 2], &[3]);
+        let request = TransactionalMapPutRequest::new("map-name", "transaction-id", 7, key, value, -1);
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(String::read_from(readable).unwrap(), request.name);
+        assert_eq!(String::read_from(readable).unwrap(), request.transaction_id);
+        assert_eq!(i64::read_from(readable).unwrap(), request.thread_id);
+        assert_eq!(Vec::<u8>::read_from(readable).unwrap(), key);
+        assert_eq!(Vec::<u8>::read_from(readable).unwrap(), value);
+        assert_eq!(i64::read_from(readable).unwrap(), -1);
+        assert_eq!(readable.remaining(), 0);
+    }
+
+    #[test]
+    fn should_write_transactional_map_remove_request() {
+        let key: &[u8] = &[1, 2];
+        let request = TransactionalMapRemoveRequest::new("map-name", "transaction-id", 7, key);
+
+        let mut writeable = BytesMut::new();
+        request.write_to(&mut writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(String::read_from(readable).unwrap(), request.name);
+        assert_eq!(String::read_from(readable).unwrap(), request.transaction_id);
+        assert_eq!(i64::read_from(readable).unwrap(), request.thread_id);
+        assert_eq!(Vec::<u8>::read_from(readable).unwrap(), key);
+        assert_eq!(readable.remaining(), 0);
+    }
+
+    #[test]
+    fn should_read_transactional_map_put_response() {
+        let writeable = &mut BytesMut::new();
+        Some(&[4u8, 2][..]).write_to(writeable);
+
+        let readable = &mut writeable.to_bytes();
+        assert_eq!(
+            TransactionalMapPutResponse::read_from(readable).unwrap().value(),
+            Some(vec![4, 2])
+        );
+    }
+}
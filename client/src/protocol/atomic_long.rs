@@ -0,0 +1,143 @@
+use std::sync::Arc;
+
+use crate::{
+    messaging::atomic_long::{
+        AtomicLongAddAndGetRequest, AtomicLongAddAndGetResponse, AtomicLongCompareAndSetRequest,
+        AtomicLongCompareAndSetResponse, AtomicLongGetAndAddRequest, AtomicLongGetAndAddResponse, AtomicLongGetRequest,
+        AtomicLongGetResponse, AtomicLongIncrementAndGetRequest, AtomicLongIncrementAndGetResponse,
+        AtomicLongSetRequest, AtomicLongSetResponse,
+    },
+    protocol::partition_key,
+    remote::cluster::Cluster,
+    Result,
+};
+
+pub struct AtomicLong {
+    name: String,
+    cluster: Arc<Cluster>,
+}
+
+impl AtomicLong {
+    pub(crate) fn new(name: &str, cluster: Arc<Cluster>) -> Self {
+        AtomicLong {
+            name: name.to_string(),
+            cluster,
+        }
+    }
+
+    pub async fn get(&self) -> Result<i64> {
+        let request = AtomicLongGetRequest::new(&self.name, self.partition_id().await?);
+        let response: AtomicLongGetResponse = self.cluster.dispatch(request).await?;
+        Ok(response.value())
+    }
+
+    pub async fn set(&self, value: i64) -> Result<()> {
+        let request = AtomicLongSetRequest::new(&self.name, value, self.partition_id().await?);
+        let _: AtomicLongSetResponse = self.cluster.dispatch(request).await?;
+        Ok(())
+    }
+
+    pub async fn add_and_get(&self, delta: i64) -> Result<i64> {
+        let request = AtomicLongAddAndGetRequest::new(&self.name, delta, self.partition_id().await?);
+        let response: AtomicLongAddAndGetResponse = self.cluster.dispatch(request).await?;
+        Ok(response.value())
+    }
+
+    pub async fn get_and_add(&self, delta: i64) -> Result<i64> {
+        let request = AtomicLongGetAndAddRequest::new(&self.name, delta, self.partition_id().await?);
+        let response: AtomicLongGetAndAddResponse = self.cluster.dispatch(request).await?;
+        Ok(response.value())
+    }
+
+    pub async fn compare_and_set(&self, expected: i64, updated: i64) -> Result<bool> {
+        let request = AtomicLongCompareAndSetRequest::new(&self.name, expected, updated, self.partition_id().await?);
+        let response: AtomicLongCompareAndSetResponse = self.cluster.dispatch(request).await?;
+        Ok(response.updated())
+    }
+
+    pub async fn increment_and_get(&self) -> Result<i64> {
+        let request = AtomicLongIncrementAndGetRequest::new(&self.name, self.partition_id().await?);
+        let response: AtomicLongIncrementAndGetResponse = self.cluster.dispatch(request).await?;
+        Ok(response.value())
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn partition_id(&self) -> Result<i32> {
+        self.cluster
+            .partition_id(partition_key(&self.name).partition_hash())
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicI64, Ordering};
+
+    use bytes::{Buf, BytesMut};
+
+    use crate::{
+        codec::{Reader, Writer},
+        remote::mock::start_cluster,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn should_update_value() {
+        let value = AtomicI64::new(0);
+        let (_member, cluster) = start_cluster(move |message| {
+            let readable = &mut message.payload();
+            let writeable = &mut BytesMut::new();
+            if (0x0A01..=0x0A0D).contains(&message.r#type()) {
+                assert_eq!(String::read_from(readable).unwrap(), "counter");
+            }
+            match message.r#type() {
+                0x0A05 => {
+                    let delta = i64::read_from(readable).unwrap();
+                    (value.fetch_add(delta, Ordering::SeqCst) + delta).write_to(writeable);
+                    Some((0x67, writeable.to_bytes()))
+                }
+                0x0A06 => {
+                    let (expected, updated) = (i64::read_from(readable).unwrap(), i64::read_from(readable).unwrap());
+                    let swapped = value.compare_exchange(expected, updated, Ordering::SeqCst, Ordering::SeqCst);
+                    swapped.is_ok().write_to(writeable);
+                    Some((0x65, writeable.to_bytes()))
+                }
+                0x0A08 => {
+                    value.load(Ordering::SeqCst).write_to(writeable);
+                    Some((0x67, writeable.to_bytes()))
+                }
+                0x0A09 => {
+                    value
+                        .fetch_add(i64::read_from(readable).unwrap(), Ordering::SeqCst)
+                        .write_to(writeable);
+                    Some((0x67, writeable.to_bytes()))
+                }
+                0x0A0B => {
+                    (value.fetch_add(1, Ordering::SeqCst) + 1).write_to(writeable);
+                    Some((0x67, writeable.to_bytes()))
+                }
+                0x0A0D => {
+                    value.store(i64::read_from(readable).unwrap(), Ordering::SeqCst);
+                    assert_eq!(readable.remaining(), 0);
+                    Some((0x64, writeable.to_bytes()))
+                }
+                _ => None,
+            }
+        })
+        .await;
+        let counter = AtomicLong::new("counter", cluster);
+
+        counter.set(5).await.unwrap();
+        assert_eq!(counter.get().await.unwrap(), 5);
+        assert_eq!(counter.add_and_get(3).await.unwrap(), 8);
+        assert_eq!(counter.get_and_add(2).await.unwrap(), 8);
+        assert_eq!(counter.increment_and_get().await.unwrap(), 11);
+        assert!(!counter.compare_and_set(10, 0).await.unwrap());
+        assert!(counter.compare_and_set(11, 0).await.unwrap());
+        assert_eq!(counter.get().await.unwrap(), 0);
+    }
+}
@@ -0,0 +1,124 @@
+use std::{convert::TryInto, sync::Arc};
+
+use crate::{
+    messaging::list::{
+        ListAddRequest, ListAddResponse, ListGetRequest, ListGetResponse, ListRemoveWithIndexRequest,
+        ListRemoveWithIndexResponse, ListSizeRequest, ListSizeResponse,
+    },
+    protocol::partition_key,
+    remote::cluster::Cluster,
+    serialization::{FromData, ToData},
+    Result,
+};
+
+pub struct List {
+    name: String,
+    cluster: Arc<Cluster>,
+}
+
+impl List {
+    pub(crate) fn new(name: &str, cluster: Arc<Cluster>) -> Self {
+        List {
+            name: name.to_string(),
+            cluster,
+        }
+    }
+
+    pub async fn add<T: ToData + ?Sized>(&self, value: &T) -> Result<bool> {
+        let value = value.to_data()?;
+        let request = ListAddRequest::new(&self.name, &value, self.partition_id().await?);
+        let response: ListAddResponse = self.cluster.dispatch(request).await?;
+        Ok(response.added())
+    }
+
+    pub async fn get<T: FromData>(&self, index: usize) -> Result<Option<T>> {
+        let index = index.try_into().unwrap_or(i32::MAX);
+        let request = ListGetRequest::new(&self.name, index, self.partition_id().await?);
+        let response: ListGetResponse = self.cluster.dispatch(request).await?;
+        response.value().as_deref().map(T::from_data).transpose()
+    }
+
+    pub async fn remove<T: FromData>(&self, index: usize) -> Result<Option<T>> {
+        let index = index.try_into().unwrap_or(i32::MAX);
+        let request = ListRemoveWithIndexRequest::new(&self.name, index, self.partition_id().await?);
+        let response: ListRemoveWithIndexResponse = self.cluster.dispatch(request).await?;
+        response.value().as_deref().map(T::from_data).transpose()
+    }
+
+    pub async fn size(&self) -> Result<usize> {
+        let request = ListSizeRequest::new(&self.name, self.partition_id().await?);
+        let response: ListSizeResponse = self.cluster.dispatch(request).await?;
+        Ok(response.size().try_into().unwrap_or(0))
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn partition_id(&self) -> Result<i32> {
+        self.cluster
+            .partition_id(partition_key(&self.name).partition_hash())
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use bytes::{Buf, BytesMut};
+
+    use crate::{
+        codec::{Reader, Writer},
+        remote::mock::start_cluster,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn should_add_get_and_remove_by_index() {
+        let stored: Arc<Mutex<Vec<Vec<u8>>>> = Arc::new(Mutex::new(Vec::new()));
+        let list = stored.clone();
+        let (_member, cluster) = start_cluster(move |message| {
+            let readable = &mut message.payload();
+            let writeable = &mut BytesMut::new();
+            let mut list = list.lock().unwrap();
+            if (0x0501..=0x0517).contains(&message.r#type()) {
+                assert_eq!(String::read_from(readable).unwrap(), "list");
+            }
+            match message.r#type() {
+                0x0501 => {
+                    (list.len() as i32).write_to(writeable);
+                    Some((0x66, writeable.to_bytes()))
+                }
+                0x0504 => {
+                    list.push(Vec::<u8>::read_from(readable).unwrap());
+                    true.write_to(writeable);
+                    Some((0x65, writeable.to_bytes()))
+                }
+                0x050F => {
+                    let index = i32::read_from(readable).unwrap() as usize;
+                    list.get(index).map(Vec::as_slice).write_to(writeable);
+                    Some((0x69, writeable.to_bytes()))
+                }
+                0x0512 => {
+                    let index = i32::read_from(readable).unwrap() as usize;
+                    Some(list.remove(index).as_slice()).write_to(writeable);
+                    Some((0x69, writeable.to_bytes()))
+                }
+                _ => None,
+            }
+        })
+        .await;
+        let list = List::new("list", cluster);
+
+        assert!(list.add("first").await.unwrap());
+        assert!(list.add("second").await.unwrap());
+        assert_eq!(stored.lock().unwrap()[0], "first".to_data().unwrap());
+        assert_eq!(list.get::<String>(1).await.unwrap(), Some("second".to_string()));
+        assert_eq!(list.remove::<String>(0).await.unwrap(), Some("first".to_string()));
+        assert_eq!(list.size().await.unwrap(), 1);
+        assert_eq!(list.get::<String>(0).await.unwrap(), Some("second".to_string()));
+        assert_eq!(list.get::<String>(1).await.unwrap(), None);
+    }
+}
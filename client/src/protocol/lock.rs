@@ -0,0 +1,199 @@
+use std::sync::Arc;
+
+use crate::{
+    messaging::lock::{
+        LockLockRequest, LockLockResponse, LockTryLockRequest, LockTryLockResponse, LockUnlockRequest,
+        LockUnlockResponse,
+    },
+    protocol::partition_key,
+    remote::cluster::Cluster,
+    Result,
+};
+
+const NO_LEASE: i64 = -1;
+
+// the lock is held on behalf of this handle, so other handles to the same lock have to wait for it to be released
+pub struct Lock {
+    name: String,
+    owner_id: i64,
+    cluster: Arc<Cluster>,
+}
+
+impl Lock {
+    pub(crate) fn new(name: &str, cluster: Arc<Cluster>) -> Self {
+        Lock {
+            name: name.to_string(),
+            owner_id: cluster.next_lock_owner_id(),
+            cluster,
+        }
+    }
+
+    pub async fn lock(&self) -> Result<()> {
+        let request = LockLockRequest::new(
+            &self.name,
+            NO_LEASE,
+            self.owner_id,
+            self.cluster.next_lock_reference_id(),
+            self.partition_id().await?,
+        );
+        let _: LockLockResponse = self.cluster.dispatch(request).await?;
+        Ok(())
+    }
+
+    pub async fn try_lock(&self) -> Result<bool> {
+        let request = LockTryLockRequest::new(
+            &self.name,
+            self.owner_id,
+            NO_LEASE,
+            0,
+            self.cluster.next_lock_reference_id(),
+            self.partition_id().await?,
+        );
+        let response: LockTryLockResponse = self.cluster.dispatch(request).await?;
+        Ok(response.acquired())
+    }
+
+    pub async fn unlock(&self) -> Result<()> {
+        let request = LockUnlockRequest::new(
+            &self.name,
+            self.owner_id,
+            self.cluster.next_lock_reference_id(),
+            self.partition_id().await?,
+        );
+        let _: LockUnlockResponse = self.cluster.dispatch(request).await?;
+        Ok(())
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn partition_id(&self) -> Result<i32> {
+        self.cluster
+            .partition_id(partition_key(&self.name).partition_hash())
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::Mutex, time::Duration};
+
+    use bytes::{Buf, BytesMut};
+
+    use crate::{
+        codec::{Reader, Writer},
+        config::ClientConfig,
+        remote::mock::{frame, partitions_payload, start_cluster, MockMember},
+        runtime::{DefaultRuntime, Runtime},
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn should_lock_and_unlock_with_reference_ids_distinct_across_instances() {
+        let references = Arc::new(Mutex::new(Vec::new()));
+        let received = references.clone();
+        let locked = Mutex::new(false);
+        let (_member, cluster) = start_cluster(move |message| {
+            let readable = &mut message.payload();
+            let writeable = &mut BytesMut::new();
+            match message.r#type() {
+                0x0705 => {
+                    assert_eq!(String::read_from(readable).unwrap(), "lock");
+                    assert_eq!(i64::read_from(readable).unwrap(), NO_LEASE);
+                    let _thread_id = i64::read_from(readable).unwrap();
+                    received.lock().unwrap().push(i64::read_from(readable).unwrap());
+                    *locked.lock().unwrap() = true;
+                    Some((0x64, writeable.to_bytes()))
+                }
+                0x0706 => {
+                    let _name = String::read_from(readable).unwrap();
+                    let _thread_id = i64::read_from(readable).unwrap();
+                    received.lock().unwrap().push(i64::read_from(readable).unwrap());
+                    *locked.lock().unwrap() = false;
+                    Some((0x64, writeable.to_bytes()))
+                }
+                0x0708 => {
+                    let _name = String::read_from(readable).unwrap();
+                    let _thread_id = i64::read_from(readable).unwrap();
+                    let _lease_time = i64::read_from(readable).unwrap();
+                    assert_eq!(i64::read_from(readable).unwrap(), 0);
+                    received.lock().unwrap().push(i64::read_from(readable).unwrap());
+                    let mut locked = locked.lock().unwrap();
+                    (!*locked).write_to(writeable);
+                    *locked = true;
+                    Some((0x65, writeable.to_bytes()))
+                }
+                _ => None,
+            }
+        })
+        .await;
+        let (lock, same) = (Lock::new("lock", cluster.clone()), Lock::new("lock", cluster));
+
+        lock.lock().await.unwrap();
+        assert!(!same.try_lock().await.unwrap());
+        lock.unlock().await.unwrap();
+        assert!(same.try_lock().await.unwrap());
+
+        assert_eq!(*references.lock().unwrap(), vec![0, 1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn should_make_other_handle_wait_until_lock_is_released() {
+        // the holder's thread id and the request of a contending thread, answered once the lock is released
+        let state: Mutex<(Option<i64>, Option<u64>)> = Mutex::new((None, None));
+        let member = MockMember::start_with_frames("member", Duration::from_secs(0), move |message| {
+            let readable = &mut message.payload();
+            let mut state = state.lock().unwrap();
+            match message.r#type() {
+                0x8 => Some(vec![frame(message.id(), 0x6C, &partitions_payload(271))]),
+                0x0705 => {
+                    let _name = String::read_from(readable).unwrap();
+                    let _lease_time = i64::read_from(readable).unwrap();
+                    let thread_id = i64::read_from(readable).unwrap();
+                    match state.0 {
+                        Some(holder) if holder != thread_id => {
+                            state.1 = Some(message.id());
+                            Some(vec![])
+                        }
+                        _ => {
+                            state.0 = Some(thread_id);
+                            Some(vec![frame(message.id(), 0x64, &[])])
+                        }
+                    }
+                }
+                0x0706 => {
+                    let _name = String::read_from(readable).unwrap();
+                    assert_eq!(Some(i64::read_from(readable).unwrap()), state.0);
+                    let mut frames = vec![frame(message.id(), 0x64, &[])];
+                    state.0 = None;
+                    if let Some(waiting) = state.1.take() {
+                        frames.push(frame(waiting, 0x64, &[]));
+                    }
+                    Some(frames)
+                }
+                _ => None,
+            }
+        })
+        .await;
+        let config = ClientConfig::builder().addresses(&[member.address()]).build();
+        let cluster = Arc::new(Cluster::init(&config).await.unwrap());
+        let (first, second) = (Lock::new("lock", cluster.clone()), Lock::new("lock", cluster));
+        let events = Mutex::new(Vec::new());
+
+        first.lock().await.unwrap();
+        let acquire = async {
+            second.lock().await.unwrap();
+            events.lock().unwrap().push("second locked");
+        };
+        let release = async {
+            DefaultRuntime::delay(Duration::from_millis(100)).await;
+            events.lock().unwrap().push("first unlocking");
+            first.unlock().await.unwrap();
+        };
+        futures::join!(acquire, release);
+
+        assert_eq!(*events.lock().unwrap(), vec!["first unlocking", "second locked"]);
+    }
+}
@@ -0,0 +1,426 @@
+use std::{collections::BTreeMap, convert::TryInto, sync::Arc, time::Duration};
+
+use tokio::sync::mpsc;
+
+use crate::{
+    config::MapConfig,
+    messaging::{
+        map::{
+            EntryEvent, MapAddEntryListenerRequest, MapAddEntryListenerResponse,
+            MapAddEntryListenerWithPredicateRequest, MapAddEntryListenerWithPredicateResponse, MapContainsValueRequest,
+            MapContainsValueResponse, MapDeleteRequest, MapDeleteResponse, MapEntryEvent, MapGetRequest,
+            MapGetResponse, MapLoadAllRequest, MapLoadAllResponse, MapLoadGivenKeysRequest, MapLoadGivenKeysResponse,
+            MapPutAllRequest, MapPutAllResponse, MapPutRequest, MapPutResponse, MapRemoveEntryListenerRequest,
+            MapRemoveEntryListenerResponse, MapRemoveIfSameRequest, MapRemoveIfSameResponse, MapRemoveRequest,
+            MapRemoveResponse, MapSetWithMaxIdleRequest, MapSetWithMaxIdleResponse, MapTryPutRequest,
+            MapTryPutResponse, ADDED, REMOVED, UPDATED,
+        },
+        ListenerKind, Registration, Request, Response,
+    },
+    remote::cluster::Cluster,
+    runtime::{DefaultRuntime, Runtime},
+    serialization::{Data, FromData, ToData},
+    Result, TryFrom,
+};
+
+const THREAD_ID: i64 = 1;
+const DEFAULT_TTL: i64 = -1;
+
+pub struct Map {
+    name: String,
+    config: MapConfig,
+    cluster: Arc<Cluster>,
+}
+
+impl Map {
+    pub(crate) fn new(name: &str, config: MapConfig, cluster: Arc<Cluster>) -> Self {
+        Map {
+            name: name.to_string(),
+            config,
+            cluster,
+        }
+    }
+
+    pub async fn get<K: ToData + ?Sized, V: FromData>(&mut self, key: &K) -> Result<Option<V>> {
+        let key = Data::from(key.to_data()?);
+        let partition_id = self.cluster.partition_id(key.partition_hash()).await?;
+        let request = MapGetRequest::new(&self.name, key.bytes(), THREAD_ID, partition_id);
+        let response: MapGetResponse = self.cluster.dispatch(request).await?;
+        response.value().as_deref().map(V::from_data).transpose()
+    }
+
+    pub async fn put<K: ToData + ?Sized, V: ToData + FromData>(&mut self, key: &K, value: &V) -> Result<Option<V>> {
+        let (key, value) = (Data::from(key.to_data()?), value.to_data()?);
+        let partition_id = self.cluster.partition_id(key.partition_hash()).await?;
+        let request = MapPutRequest::new(&self.name, key.bytes(), &value, THREAD_ID, DEFAULT_TTL, partition_id);
+        let response: MapPutResponse = self.cluster.dispatch(request).await?;
+        response.value().as_deref().map(V::from_data).transpose()
+    }
+
+    pub async fn remove<K: ToData + ?Sized, V: FromData>(&mut self, key: &K) -> Result<Option<V>> {
+        let key = Data::from(key.to_data()?);
+        let partition_id = self.cluster.partition_id(key.partition_hash()).await?;
+        let request = MapRemoveRequest::new(&self.name, key.bytes(), THREAD_ID, partition_id);
+        let response: MapRemoveResponse = self.cluster.dispatch(request).await?;
+        response.value().as_deref().map(V::from_data).transpose()
+    }
+
+    /// Scans all entries of the map on every member, which is expensive for large maps.
+    pub async fn contains_value<V: ToData + ?Sized>(&mut self, value: &V) -> Result<bool> {
+        let value = value.to_data()?;
+        let request = MapContainsValueRequest::new(&self.name, &value);
+        let response: MapContainsValueResponse = self.cluster.dispatch(request).await?;
+        Ok(response.value())
+    }
+
+    pub async fn delete<K: ToData + ?Sized>(&mut self, key: &K) -> Result<()> {
+        let key = Data::from(key.to_data()?);
+        let partition_id = self.cluster.partition_id(key.partition_hash()).await?;
+        let request = MapDeleteRequest::new(&self.name, key.bytes(), THREAD_ID, partition_id);
+        let _: MapDeleteResponse = self.cluster.dispatch(request).await?;
+        Ok(())
+    }
+
+    // entries are sent to the owners of their partitions, in batches bounded by the configured size
+    pub async fn put_all<K: ToData + ?Sized, V: ToData + ?Sized>(&mut self, entries: &[(&K, &V)]) -> Result<()> {
+        let mut partitions = BTreeMap::new();
+        for (key, value) in entries {
+            let (key, value) = (Data::from(key.to_data()?), value.to_data()?);
+            let partition_id = self.cluster.partition_id(key.partition_hash()).await?;
+            partitions
+                .entry(partition_id)
+                .or_insert_with(Vec::new)
+                .push((key, value));
+        }
+
+        for (partition_id, entries) in &partitions {
+            let entries: Vec<(&[u8], &[u8])> = entries.iter().map(|(key, value)| (key.bytes(), &value[..])).collect();
+            for batch in entries.chunks(self.config.put_all_batch_size) {
+                let request = MapPutAllRequest::new(&self.name, batch, *partition_id);
+                let _: MapPutAllResponse = self.cluster.dispatch(request).await?;
+            }
+        }
+        Ok(())
+    }
+
+    pub async fn remove_if_same<K: ToData + ?Sized, V: ToData + ?Sized>(&mut self, key: &K, value: &V) -> Result<bool> {
+        let (key, value) = (Data::from(key.to_data()?), value.to_data()?);
+        let partition_id = self.cluster.partition_id(key.partition_hash()).await?;
+        let request = MapRemoveIfSameRequest::new(&self.name, key.bytes(), &value, THREAD_ID, partition_id);
+        let response: MapRemoveIfSameResponse = self.cluster.dispatch(request).await?;
+        Ok(response.value())
+    }
+
+    pub async fn try_put<K: ToData + ?Sized, V: ToData + ?Sized>(
+        &mut self,
+        key: &K,
+        value: &V,
+        timeout: Duration,
+    ) -> Result<bool> {
+        let (key, value) = (Data::from(key.to_data()?), value.to_data()?);
+        let partition_id = self.cluster.partition_id(key.partition_hash()).await?;
+        let timeout = timeout.as_millis().try_into().unwrap_or(i64::MAX);
+        let request = MapTryPutRequest::new(&self.name, key.bytes(), &value, THREAD_ID, timeout, partition_id);
+        let response: MapTryPutResponse = self.cluster.dispatch(request).await?;
+        Ok(response.value())
+    }
+
+    // the entry expires once it has not been accessed for longer than the given duration
+    pub async fn set_with_max_idle<K: ToData + ?Sized, V: ToData + ?Sized>(
+        &mut self,
+        key: &K,
+        value: &V,
+        max_idle: Duration,
+    ) -> Result<()> {
+        let (key, value) = (Data::from(key.to_data()?), value.to_data()?);
+        let partition_id = self.cluster.partition_id(key.partition_hash()).await?;
+        let max_idle = max_idle.as_millis().try_into().unwrap_or(i64::MAX);
+        let request = MapSetWithMaxIdleRequest::new(
+            &self.name,
+            key.bytes(),
+            &value,
+            THREAD_ID,
+            DEFAULT_TTL,
+            max_idle,
+            partition_id,
+        );
+        let _: MapSetWithMaxIdleResponse = self.cluster.dispatch(request).await?;
+        Ok(())
+    }
+
+    pub async fn load_all(&mut self, replace_existing: bool) -> Result<()> {
+        let request = MapLoadAllRequest::new(&self.name, replace_existing);
+        let _: MapLoadAllResponse = self.cluster.dispatch(request).await?;
+        Ok(())
+    }
+
+    pub async fn load_all_keys<K: ToData + ?Sized>(&mut self, keys: &[&K], replace_existing: bool) -> Result<()> {
+        let keys = keys.iter().map(|key| key.to_data()).collect::<Result<Vec<_>>>()?;
+        let keys: Vec<&[u8]> = keys.iter().map(Vec::as_slice).collect();
+        let request = MapLoadGivenKeysRequest::new(&self.name, &keys, replace_existing);
+        let _: MapLoadGivenKeysResponse = self.cluster.dispatch(request).await?;
+        Ok(())
+    }
+
+    pub async fn add_entry_listener(
+        &mut self,
+        include_value: bool,
+    ) -> Result<(String, mpsc::UnboundedReceiver<Result<EntryEvent>>)> {
+        let request = MapAddEntryListenerRequest::new(&self.name, include_value, ADDED | REMOVED | UPDATED);
+        self.listen::<_, MapAddEntryListenerResponse>(request).await
+    }
+
+    // only entries matching the serialized predicate are evaluated on the members and reported
+    pub async fn add_entry_listener_with_predicate(
+        &mut self,
+        predicate: &[u8],
+        include_value: bool,
+    ) -> Result<(String, mpsc::UnboundedReceiver<Result<EntryEvent>>)> {
+        let request = MapAddEntryListenerWithPredicateRequest::new(
+            &self.name,
+            predicate,
+            include_value,
+            ADDED | REMOVED | UPDATED,
+        );
+        self.listen::<_, MapAddEntryListenerWithPredicateResponse>(request)
+            .await
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn listen<RQ: Request, RS: Response + Registration>(
+        &self,
+        request: RQ,
+    ) -> Result<(String, mpsc::UnboundedReceiver<Result<EntryEvent>>)> {
+        let name = self.name.clone();
+        let (registration_id, mut events) = self
+            .cluster
+            .listen::<_, RS, _, MapRemoveEntryListenerResponse, _>(ListenerKind::MapEntry, request, move |id| {
+                MapRemoveEntryListenerRequest::new(name.clone(), id)
+            })
+            .await?;
+
+        let (sender, entry_events) = mpsc::unbounded_channel();
+        DefaultRuntime::spawn(async move {
+            while let Some(event) = events.recv().await {
+                let event =
+                    TryFrom::<MapEntryEvent>::try_from(event).and_then(|event| event.entry_event().map_err(Into::into));
+                if sender.send(event).is_err() {
+                    break;
+                }
+            }
+        });
+        Ok((registration_id, entry_events))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::HashMap,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Mutex,
+        },
+    };
+
+    use bytes::{Buf, BytesMut};
+
+    use crate::{
+        codec::{Reader, Writer},
+        config::ClientConfig,
+        remote::mock::{event, frame, partitions_payload, registration_payload, start_cluster, MockMember},
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn should_put_all_entries_of_partition_in_batches() {
+        let requests = Arc::new(AtomicUsize::new(0));
+        let stored = Arc::new(Mutex::new(HashMap::new()));
+        let (put_alls, entries) = (requests.clone(), stored.clone());
+        let member = MockMember::start_with("member", move |message| match message.r#type() {
+            0x8 => Some((0x6C, partitions_payload(1))),
+            0x130 => {
+                let readable = &mut message.payload();
+                let _name = String::read_from(readable).unwrap();
+                put_alls.fetch_add(1, Ordering::SeqCst);
+                entries
+                    .lock()
+                    .unwrap()
+                    .extend(Vec::<(Vec<u8>, Vec<u8>)>::read_from(readable).unwrap());
+                None
+            }
+            _ => None,
+        })
+        .await;
+        let cluster = Cluster::init(&ClientConfig::builder().addresses(&[member.address()]).build())
+            .await
+            .unwrap();
+        let config = MapConfig::builder().put_all_batch_size(2).build();
+        let mut map = Map::new("map-name", config, Arc::new(cluster));
+
+        let keys: Vec<i64> = (0..5).collect();
+        let entries: Vec<(&i64, &i64)> = keys.iter().map(|key| (key, key)).collect();
+        map.put_all(&entries).await.unwrap();
+
+        assert_eq!(requests.load(Ordering::SeqCst), 3);
+        let stored = stored.lock().unwrap();
+        assert_eq!(stored.len(), 5);
+        assert!(keys.iter().all(|key| {
+            let key = key.to_data().unwrap();
+            stored.get(&key) == Some(&key)
+        }));
+    }
+
+    #[tokio::test]
+    async fn should_set_entry_with_max_idle() {
+        let max_idles = Arc::new(Mutex::new(Vec::new()));
+        let received = max_idles.clone();
+        let member = MockMember::start_with("member", move |message| match message.r#type() {
+            0x8 => Some((0x6C, partitions_payload(1))),
+            0x14D => {
+                let readable = &mut message.payload();
+                let _name = String::read_from(readable).unwrap();
+                assert_eq!(Vec::<u8>::read_from(readable).unwrap(), "key".to_data().unwrap());
+                assert_eq!(Vec::<u8>::read_from(readable).unwrap(), 2i64.to_data().unwrap());
+                let _thread_id = i64::read_from(readable).unwrap();
+                assert_eq!(i64::read_from(readable).unwrap(), DEFAULT_TTL);
+                received.lock().unwrap().push(i64::read_from(readable).unwrap());
+
+                let writeable = &mut BytesMut::new();
+                Option::<&[u8]>::None.write_to(writeable);
+                Some((0x69, writeable.to_bytes()))
+            }
+            _ => None,
+        })
+        .await;
+        let cluster = Cluster::init(&ClientConfig::builder().addresses(&[member.address()]).build())
+            .await
+            .unwrap();
+        let mut map = Map::new("map-name", MapConfig::default(), Arc::new(cluster));
+
+        map.set_with_max_idle("key", &2i64, Duration::from_secs(30))
+            .await
+            .unwrap();
+
+        assert_eq!(*max_idles.lock().unwrap(), vec![30_000]);
+    }
+
+    #[tokio::test]
+    async fn should_put_and_get_serialized_values() {
+        let entries: Mutex<HashMap<Vec<u8>, Vec<u8>>> = Mutex::new(HashMap::new());
+        let (_member, cluster) = start_cluster(move |message| {
+            let readable = &mut message.payload();
+            let writeable = &mut BytesMut::new();
+            let mut entries = entries.lock().unwrap();
+            match message.r#type() {
+                0x101 | 0x102 => assert_eq!(String::read_from(readable).unwrap(), "map-name"),
+                _ => return None,
+            }
+            let key = Vec::<u8>::read_from(readable).unwrap();
+            assert_eq!(key, "key".to_data().unwrap());
+            let previous = match message.r#type() {
+                0x101 => entries.insert(key, Vec::<u8>::read_from(readable).unwrap()),
+                _ => entries.get(&key).cloned(),
+            };
+            previous.as_deref().write_to(writeable);
+            Some((0x69, writeable.to_bytes()))
+        })
+        .await;
+        let mut map = Map::new("map-name", MapConfig::default(), cluster);
+
+        assert_eq!(map.put("key", &7i64).await.unwrap(), None);
+        assert_eq!(map.put("key", &8i64).await.unwrap(), Some(7));
+        assert_eq!(map.get::<_, i64>("key").await.unwrap(), Some(8));
+        assert!(map.get::<_, String>("key").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn should_receive_entry_events() {
+        let member =
+            MockMember::start_with_frames("member", Duration::from_secs(0), |message| match message.r#type() {
+                0x11C => {
+                    let readable = &mut message.payload();
+                    assert_eq!(String::read_from(readable).unwrap(), "map-name");
+                    assert!(bool::read_from(readable).unwrap());
+                    assert_eq!(i32::read_from(readable).unwrap(), ADDED | REMOVED | UPDATED);
+                    Some(vec![
+                        frame(message.id(), 0x68, &registration_payload()),
+                        event(message.id(), 0xCB, &entry_event(ADDED, Some(&[2]), None)),
+                        event(message.id(), 0xCB, &entry_event(REMOVED, None, Some(&[2]))),
+                    ])
+                }
+                _ => None,
+            })
+            .await;
+        let cluster = Cluster::init(&ClientConfig::builder().addresses(&[member.address()]).build())
+            .await
+            .unwrap();
+        let mut map = Map::new("map-name", MapConfig::default(), Arc::new(cluster));
+
+        let (registration_id, mut events) = map.add_entry_listener(true).await.unwrap();
+
+        assert_eq!(registration_id, "registration-id");
+        assert_eq!(
+            events.recv().await.unwrap().unwrap(),
+            EntryEvent::Added {
+                key: vec![1],
+                value: Some(vec![2]),
+            }
+        );
+        assert_eq!(
+            events.recv().await.unwrap().unwrap(),
+            EntryEvent::Removed {
+                key: vec![1],
+                old_value: Some(vec![2]),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn should_send_predicate_of_entry_listener() {
+        let predicates = Arc::new(Mutex::new(Vec::new()));
+        let received = predicates.clone();
+        let member = MockMember::start_with_frames("member", Duration::from_secs(0), move |message| {
+            match message.r#type() {
+                0x11A => {
+                    let readable = &mut message.payload();
+                    let _name = String::read_from(readable).unwrap();
+                    received.lock().unwrap().push(Vec::<u8>::read_from(readable).unwrap());
+                    Some(vec![frame(message.id(), 0x68, &registration_payload())])
+                }
+                _ => None,
+            }
+        })
+        .await;
+        let cluster = Cluster::init(&ClientConfig::builder().addresses(&[member.address()]).build())
+            .await
+            .unwrap();
+        let mut map = Map::new("map-name", MapConfig::default(), Arc::new(cluster));
+
+        map.add_entry_listener_with_predicate(&[0, 0, 0, 0, 255, 255, 255, 254, 7], false)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            *predicates.lock().unwrap(),
+            vec![vec![0, 0, 0, 0, 255, 255, 255, 254, 7]]
+        );
+    }
+
+    fn entry_event(event_type: i32, value: Option<&[u8]>, old_value: Option<&[u8]>) -> Vec<u8> {
+        let writeable = &mut BytesMut::new();
+        let key: &[u8] = &[1];
+        Some(key).write_to(writeable);
+        value.write_to(writeable);
+        old_value.write_to(writeable);
+        Option::<&[u8]>::None.write_to(writeable);
+        event_type.write_to(writeable);
+        "member".write_to(writeable);
+        1i32.write_to(writeable);
+        writeable.to_vec()
+    }
+}
@@ -1 +1,33 @@
+use crate::serialization::{serialize_string, Data};
+
+pub mod atomic_long;
+pub mod list;
+pub mod lock;
+pub mod map;
+pub mod multi_map;
 pub mod pn_counter;
+pub mod queue;
+pub mod ringbuffer;
+pub mod semaphore;
+pub mod set;
+pub mod topic;
+pub mod transaction;
+
+// structures live on the partition of their name, or of the part following '@' when one is given
+pub(crate) fn partition_key(name: &str) -> Data {
+    match name.split_once('@') {
+        Some((_, key)) => Data::from(serialize_string(key)),
+        None => Data::from(serialize_string(name)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_use_part_following_at_sign_as_partition_key() {
+        assert_eq!(partition_key("counter@group"), Data::from(serialize_string("group")));
+        assert_eq!(partition_key("counter"), Data::from(serialize_string("counter")));
+    }
+}
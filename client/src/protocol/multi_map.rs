@@ -0,0 +1,130 @@
+use std::{convert::TryInto, sync::Arc};
+
+use crate::{
+    messaging::multi_map::{
+        MultiMapGetRequest, MultiMapGetResponse, MultiMapPutRequest, MultiMapPutResponse, MultiMapRemoveRequest,
+        MultiMapRemoveResponse, MultiMapValueCountRequest, MultiMapValueCountResponse,
+    },
+    remote::cluster::Cluster,
+    serialization::{Data, FromData, ToData},
+    Result,
+};
+
+const THREAD_ID: i64 = 1;
+
+pub struct MultiMap {
+    name: String,
+    cluster: Arc<Cluster>,
+}
+
+impl MultiMap {
+    pub(crate) fn new(name: &str, cluster: Arc<Cluster>) -> Self {
+        MultiMap {
+            name: name.to_string(),
+            cluster,
+        }
+    }
+
+    pub async fn put<K: ToData + ?Sized, V: ToData + ?Sized>(&self, key: &K, value: &V) -> Result<bool> {
+        let (key, value) = (Data::from(key.to_data()?), value.to_data()?);
+        let partition_id = self.cluster.partition_id(key.partition_hash()).await?;
+        let request = MultiMapPutRequest::new(&self.name, key.bytes(), &value, THREAD_ID, partition_id);
+        let response: MultiMapPutResponse = self.cluster.dispatch(request).await?;
+        Ok(response.added())
+    }
+
+    pub async fn get<K: ToData + ?Sized, V: FromData>(&self, key: &K) -> Result<Vec<V>> {
+        let key = Data::from(key.to_data()?);
+        let partition_id = self.cluster.partition_id(key.partition_hash()).await?;
+        let request = MultiMapGetRequest::new(&self.name, key.bytes(), THREAD_ID, partition_id);
+        let response: MultiMapGetResponse = self.cluster.dispatch(request).await?;
+        response.values().iter().map(|value| V::from_data(value)).collect()
+    }
+
+    pub async fn remove<K: ToData + ?Sized, V: FromData>(&self, key: &K) -> Result<Vec<V>> {
+        let key = Data::from(key.to_data()?);
+        let partition_id = self.cluster.partition_id(key.partition_hash()).await?;
+        let request = MultiMapRemoveRequest::new(&self.name, key.bytes(), THREAD_ID, partition_id);
+        let response: MultiMapRemoveResponse = self.cluster.dispatch(request).await?;
+        response.values().iter().map(|value| V::from_data(value)).collect()
+    }
+
+    pub async fn value_count<K: ToData + ?Sized>(&self, key: &K) -> Result<usize> {
+        let key = Data::from(key.to_data()?);
+        let partition_id = self.cluster.partition_id(key.partition_hash()).await?;
+        let request = MultiMapValueCountRequest::new(&self.name, key.bytes(), THREAD_ID, partition_id);
+        let response: MultiMapValueCountResponse = self.cluster.dispatch(request).await?;
+        Ok(response.count().try_into().unwrap_or(0))
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, sync::Mutex};
+
+    use bytes::{Buf, BytesMut};
+
+    use crate::{
+        codec::{Reader, Writer},
+        remote::mock::start_cluster,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn should_collect_values_per_key() {
+        let stored = Arc::new(Mutex::new(HashMap::<Vec<u8>, Vec<Vec<u8>>>::new()));
+        let entries = stored.clone();
+        let (_member, cluster) = start_cluster(move |message| {
+            let readable = &mut message.payload();
+            let writeable = &mut BytesMut::new();
+            let mut entries = entries.lock().unwrap();
+            if (0x0201..=0x020C).contains(&message.r#type()) {
+                assert_eq!(String::read_from(readable).unwrap(), "multi-map");
+            }
+            match message.r#type() {
+                0x0201 => {
+                    let key = Vec::<u8>::read_from(readable).unwrap();
+                    entries
+                        .entry(key)
+                        .or_default()
+                        .push(Vec::<u8>::read_from(readable).unwrap());
+                    true.write_to(writeable);
+                    Some((0x65, writeable.to_bytes()))
+                }
+                0x0202 => {
+                    let values = entries.get(&Vec::<u8>::read_from(readable).unwrap()).cloned();
+                    let values: Vec<&[u8]> = values.iter().flatten().map(Vec::as_slice).collect();
+                    values.as_slice().write_to(writeable);
+                    Some((0x6A, writeable.to_bytes()))
+                }
+                0x0203 => {
+                    let values = entries.remove(&Vec::<u8>::read_from(readable).unwrap());
+                    let values: Vec<&[u8]> = values.iter().flatten().map(Vec::as_slice).collect();
+                    values.as_slice().write_to(writeable);
+                    Some((0x6A, writeable.to_bytes()))
+                }
+                0x020C => {
+                    let values = entries.get(&Vec::<u8>::read_from(readable).unwrap());
+                    (values.map_or(0, Vec::len) as i32).write_to(writeable);
+                    Some((0x66, writeable.to_bytes()))
+                }
+                _ => None,
+            }
+        })
+        .await;
+        let multi_map = MultiMap::new("multi-map", cluster);
+
+        assert!(multi_map.put("key", &2i64).await.unwrap());
+        assert!(multi_map.put("key", &3i64).await.unwrap());
+        assert!(stored.lock().unwrap().contains_key(&"key".to_data().unwrap()));
+        assert_eq!(multi_map.value_count("key").await.unwrap(), 2);
+        assert_eq!(multi_map.get::<_, i64>("key").await.unwrap(), vec![2, 3]);
+        assert_eq!(multi_map.remove::<_, i64>("key").await.unwrap(), vec![2, 3]);
+        assert!(multi_map.get::<_, i64>("key").await.unwrap().is_empty());
+    }
+}
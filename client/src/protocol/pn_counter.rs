@@ -1,4 +1,7 @@
-use std::sync::Arc;
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use crate::{
     messaging::{
@@ -9,6 +12,8 @@ use crate::{
         Address, ReplicaTimestampEntry,
     },
     remote::cluster::Cluster,
+    runtime::{DefaultRuntime, Runtime},
+    HazelcastClientError::{ClusterNonOperational, CommunicationFailure, ConsistencyLost, NodeNonOperational},
     Result,
 };
 
@@ -18,6 +23,7 @@ pub struct PnCounter {
 
     address: Option<Address>,
     replica_timestamps: Vec<ReplicaTimestampEntry>,
+    replica_count: ReplicaCount,
 }
 
 impl PnCounter {
@@ -27,13 +33,28 @@ impl PnCounter {
             cluster,
             address: None,
             replica_timestamps: vec![],
+            replica_count: ReplicaCount::new(REPLICA_COUNT_TTL),
         }
     }
 
     pub async fn get(&mut self) -> Result<i64> {
+        let deadline = Instant::now() + RETRY_TIMEOUT;
+        loop {
+            match self.try_get().await {
+                Err(CommunicationFailure(_)) | Err(NodeNonOperational) | Err(ClusterNonOperational)
+                    if Instant::now() < deadline =>
+                {
+                    DefaultRuntime::delay(RETRY_DELAY).await
+                }
+                result => return result,
+            }
+        }
+    }
+
+    async fn try_get(&mut self) -> Result<i64> {
         let address = self.cluster.address(self.address.take()).await?;
         let request = PnCounterGetRequest::new(&self.name, &self.replica_timestamps, &address);
-        let response: PnCounterGetResponse = self.cluster.forward(request, &address).await?;
+        let response: PnCounterGetResponse = self.observe(self.cluster.forward(request, &address).await)?;
         self.address = Some(address);
         self.replica_timestamps = response.replica_timestamps().to_vec();
         Ok(response.value())
@@ -47,23 +68,380 @@ impl PnCounter {
         self.add(delta, false).await
     }
 
+    pub async fn increment(&mut self) -> Result<i64> {
+        self.add_and_get(1).await
+    }
+
+    pub async fn decrement(&mut self) -> Result<i64> {
+        self.add_and_get(-1).await
+    }
+
+    pub async fn get_and_subtract(&mut self, delta: i64) -> Result<i64> {
+        self.add(delta.wrapping_neg(), true).await
+    }
+
+    pub async fn subtract_and_get(&mut self, delta: i64) -> Result<i64> {
+        self.add(delta.wrapping_neg(), false).await
+    }
+
+    // a failed connection may have applied the delta already, so only adds that were never sent are retried
     async fn add(&mut self, delta: i64, get_before_update: bool) -> Result<i64> {
+        let deadline = Instant::now() + RETRY_TIMEOUT;
+        loop {
+            match self.try_add(delta, get_before_update).await {
+                Err(NodeNonOperational) | Err(ClusterNonOperational) if Instant::now() < deadline => {
+                    DefaultRuntime::delay(RETRY_DELAY).await
+                }
+                result => return result,
+            }
+        }
+    }
+
+    async fn try_add(&mut self, delta: i64, get_before_update: bool) -> Result<i64> {
         let address = self.cluster.address(self.address.take()).await?;
         let request =
             PnCounterAddRequest::new(&self.name, delta, get_before_update, &self.replica_timestamps, &address);
-        let response: PnCounterAddResponse = self.cluster.forward(request, &address).await?;
+        let response: PnCounterAddResponse = self.observe(self.cluster.forward(request, &address).await)?;
         self.address = Some(address);
         self.replica_timestamps = response.replica_timestamps().to_vec();
         Ok(response.value())
     }
 
-    pub async fn replica_count(&mut self) -> Result<u32> {
+    // the replicas lost track of the observed timestamps, so they are reset for subsequent operations to succeed
+    fn observe<R>(&mut self, result: Result<R>) -> Result<R> {
+        if let Err(ConsistencyLost(_)) = result {
+            self.replica_timestamps.clear();
+        }
+        result
+    }
+
+    pub async fn replica_count(&mut self, force: bool) -> Result<u32> {
+        let version = self.cluster.membership_version().await;
+        if !force {
+            if let Some(count) = self.replica_count.get(version, Instant::now()) {
+                return Ok(count);
+            }
+        }
+
         let request = PnCounterGetReplicaCountRequest::new(&self.name);
         let response: PnCounterGetReplicaCountResponse = self.cluster.dispatch(request).await?;
+        self.replica_count.put(response.count(), version, Instant::now());
         Ok(response.count())
     }
 
+    // forgets the observed replica timestamps and target member, e.g. to start over after a topology change
+    pub fn reset(&mut self) {
+        self.replica_timestamps.clear();
+        self.address = None;
+    }
+
     pub fn name(&self) -> &str {
         &self.name
     }
 }
+
+// operations are retried while the target member reconnects or another one takes over
+const RETRY_TIMEOUT: Duration = Duration::from_secs(10);
+const RETRY_DELAY: Duration = Duration::from_millis(100);
+
+const REPLICA_COUNT_TTL: Duration = Duration::from_secs(30);
+
+struct ReplicaCount {
+    ttl: Duration,
+    cached: Option<(u32, u64, Instant)>,
+}
+
+impl ReplicaCount {
+    fn new(ttl: Duration) -> Self {
+        ReplicaCount { ttl, cached: None }
+    }
+
+    fn get(&self, version: u64, now: Instant) -> Option<u32> {
+        match self.cached {
+            Some((count, cached_version, cached_at)) if cached_version == version && now < cached_at + self.ttl => {
+                Some(count)
+            }
+            _ => None,
+        }
+    }
+
+    fn put(&mut self, count: u32, version: u64, now: Instant) {
+        self.cached = Some((count, version, now));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use bytes::{Buf, BytesMut};
+
+    use crate::{
+        codec::Writer,
+        config::ClientConfig,
+        messaging::error::{CONSISTENCY_LOST, PARTITION_MIGRATING},
+        remote::mock::{exception, MockMember},
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn should_reset_replica_timestamps_when_consistency_is_lost() {
+        let gets = AtomicUsize::new(0);
+        let member = MockMember::start_with("member", move |message| match message.r#type() {
+            0x2001 if gets.fetch_add(1, Ordering::SeqCst) == 1 => {
+                Some((0x6D, exception(CONSISTENCY_LOST, "ConsistencyLostException")))
+            }
+            0x2001 => {
+                let writeable = &mut BytesMut::new();
+                69i64.write_to(writeable);
+                1u32.write_to(writeable);
+                "replica".write_to(writeable);
+                5i64.write_to(writeable);
+                Some((0x7F, writeable.to_bytes()))
+            }
+            _ => None,
+        })
+        .await;
+        let config = ClientConfig::builder().addresses(&[member.address()]).build();
+        let cluster = Cluster::init(&config).await.unwrap();
+        let mut counter = PnCounter::new("counter", Arc::new(cluster));
+
+        assert_eq!(counter.get().await.unwrap(), 69);
+        assert_eq!(counter.replica_timestamps.len(), 1);
+
+        match counter.get().await {
+            Err(ConsistencyLost(_)) => {}
+            _ => panic!("expected consistency lost!"),
+        }
+        assert!(counter.replica_timestamps.is_empty());
+
+        assert_eq!(counter.get().await.unwrap(), 69);
+        assert_eq!(counter.replica_timestamps.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn should_send_empty_replica_timestamps_after_reset() {
+        use crate::codec::Reader;
+        use std::sync::Mutex;
+
+        let sent = Arc::new(Mutex::new(Vec::new()));
+        let received = sent.clone();
+        let member = MockMember::start_with("member", move |message| match message.r#type() {
+            0x2001 => {
+                let readable = &mut message.payload();
+                let _name = String::read_from(readable).unwrap();
+                received.lock().unwrap().push(u32::read_from(readable).unwrap());
+
+                let writeable = &mut BytesMut::new();
+                69i64.write_to(writeable);
+                1u32.write_to(writeable);
+                "replica".write_to(writeable);
+                5i64.write_to(writeable);
+                Some((0x7F, writeable.to_bytes()))
+            }
+            _ => None,
+        })
+        .await;
+        let config = ClientConfig::builder().addresses(&[member.address()]).build();
+        let cluster = Cluster::init(&config).await.unwrap();
+        let mut counter = PnCounter::new("counter", Arc::new(cluster));
+
+        counter.get().await.unwrap();
+        counter.get().await.unwrap();
+        counter.reset();
+        assert!(counter.replica_timestamps.is_empty());
+        assert!(counter.address.is_none());
+        counter.get().await.unwrap();
+
+        assert_eq!(*sent.lock().unwrap(), vec![0, 1, 0]);
+    }
+
+    #[tokio::test]
+    async fn should_subtract_by_adding_negated_delta() {
+        use std::sync::Mutex;
+
+        let sent = Arc::new(Mutex::new(Vec::new()));
+        let received = sent.clone();
+        let member = MockMember::start_with("member", move |message| match message.r#type() {
+            0x2002 => {
+                received.lock().unwrap().push(message.payload());
+
+                let writeable = &mut BytesMut::new();
+                42i64.write_to(writeable);
+                0u32.write_to(writeable);
+                1u32.write_to(writeable);
+                Some((0x7F, writeable.to_bytes()))
+            }
+            _ => None,
+        })
+        .await;
+        let config = ClientConfig::builder().addresses(&[member.address()]).build();
+        let cluster = Cluster::init(&config).await.unwrap();
+        let mut counter = PnCounter::new("counter", Arc::new(cluster));
+
+        assert_eq!(counter.get_and_subtract(5).await.unwrap(), 42);
+        assert_eq!(counter.get_and_add(-5).await.unwrap(), 42);
+        assert_eq!(counter.subtract_and_get(5).await.unwrap(), 42);
+        assert_eq!(counter.add_and_get(-5).await.unwrap(), 42);
+
+        let sent = sent.lock().unwrap();
+        assert_eq!(sent.len(), 4);
+        assert_eq!(sent[0], sent[1]);
+        assert_eq!(sent[2], sent[3]);
+        assert_ne!(sent[0], sent[2]);
+    }
+
+    #[tokio::test]
+    async fn should_increment_and_decrement_by_one() {
+        use crate::codec::Reader;
+        use std::sync::Mutex;
+
+        let sent = Arc::new(Mutex::new(Vec::new()));
+        let received = sent.clone();
+        let member = MockMember::start_with("member", move |message| match message.r#type() {
+            0x2002 => {
+                let readable = &mut message.payload();
+                let _name = String::read_from(readable).unwrap();
+                let delta = i64::read_from(readable).unwrap();
+                let get_before_update = bool::read_from(readable).unwrap();
+                received.lock().unwrap().push((delta, get_before_update));
+
+                let writeable = &mut BytesMut::new();
+                delta.write_to(writeable);
+                0u32.write_to(writeable);
+                1u32.write_to(writeable);
+                Some((0x7F, writeable.to_bytes()))
+            }
+            _ => None,
+        })
+        .await;
+        let config = ClientConfig::builder().addresses(&[member.address()]).build();
+        let cluster = Cluster::init(&config).await.unwrap();
+        let mut counter = PnCounter::new("counter", Arc::new(cluster));
+
+        assert_eq!(counter.increment().await.unwrap(), 1);
+        assert_eq!(counter.decrement().await.unwrap(), -1);
+
+        assert_eq!(*sent.lock().unwrap(), vec![(1, false), (-1, false)]);
+    }
+
+    #[tokio::test]
+    async fn should_not_send_add_again_after_connection_failed() {
+        let adds = Arc::new(AtomicUsize::new(0));
+        let attempts = adds.clone();
+        let member = MockMember::start_with_frames("member", Duration::from_secs(0), move |message| {
+            match message.r#type() {
+                0x2002 => {
+                    attempts.fetch_add(1, Ordering::SeqCst);
+                    Some(vec![])
+                }
+                _ => None,
+            }
+        })
+        .await;
+        let config = ClientConfig::builder().addresses(&[member.address()]).build();
+        let cluster = Cluster::init(&config).await.unwrap();
+        let mut counter = PnCounter::new("counter", Arc::new(cluster));
+
+        let disconnect = async {
+            while adds.load(Ordering::SeqCst) == 0 {
+                DefaultRuntime::delay(Duration::from_millis(10)).await;
+            }
+            member.disconnect();
+        };
+        let (result, _) = futures::join!(counter.add_and_get(1), disconnect);
+
+        assert!(matches!(result, Err(CommunicationFailure(_))));
+        assert_eq!(adds.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn should_retry_get_while_partition_migrates() {
+        let gets = Arc::new(AtomicUsize::new(0));
+        let attempts = gets.clone();
+        let member = MockMember::start_with("member", move |message| match message.r#type() {
+            0x2001 if attempts.fetch_add(1, Ordering::SeqCst) == 0 => {
+                Some((0x6D, exception(PARTITION_MIGRATING, "PartitionMigratingException")))
+            }
+            0x2001 => {
+                let writeable = &mut BytesMut::new();
+                69i64.write_to(writeable);
+                0u32.write_to(writeable);
+                Some((0x7F, writeable.to_bytes()))
+            }
+            _ => None,
+        })
+        .await;
+        let config = ClientConfig::builder().addresses(&[member.address()]).build();
+        let cluster = Cluster::init(&config).await.unwrap();
+        let mut counter = PnCounter::new("counter", Arc::new(cluster));
+
+        assert_eq!(counter.get().await.unwrap(), 69);
+        assert_eq!(gets.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn should_ask_member_for_replica_count_once_while_cached() {
+        let requests = Arc::new(AtomicUsize::new(0));
+        let received = requests.clone();
+        let member = MockMember::start_with("member", move |message| match message.r#type() {
+            0x2003 => {
+                received.fetch_add(1, Ordering::SeqCst);
+                let writeable = &mut BytesMut::new();
+                3u32.write_to(writeable);
+                Some((0x66, writeable.to_bytes()))
+            }
+            _ => None,
+        })
+        .await;
+        let config = ClientConfig::builder().addresses(&[member.address()]).build();
+        let cluster = Cluster::init(&config).await.unwrap();
+        let mut counter = PnCounter::new("counter", Arc::new(cluster));
+
+        assert_eq!(counter.replica_count(false).await.unwrap(), 3);
+        assert_eq!(counter.replica_count(false).await.unwrap(), 3);
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+        assert_eq!(counter.replica_count(true).await.unwrap(), 3);
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn should_miss_empty_replica_count() {
+        let replica_count = ReplicaCount::new(REPLICA_COUNT_TTL);
+
+        assert_eq!(replica_count.get(0, Instant::now()), None);
+    }
+
+    #[test]
+    fn should_hit_replica_count_within_ttl() {
+        let mut replica_count = ReplicaCount::new(REPLICA_COUNT_TTL);
+        let now = Instant::now();
+
+        replica_count.put(3, 1, now);
+
+        assert_eq!(replica_count.get(1, now), Some(3));
+        assert_eq!(replica_count.get(1, now + Duration::from_secs(1)), Some(3));
+    }
+
+    #[test]
+    fn should_miss_replica_count_after_ttl() {
+        let mut replica_count = ReplicaCount::new(REPLICA_COUNT_TTL);
+        let now = Instant::now();
+
+        replica_count.put(3, 1, now);
+
+        assert_eq!(replica_count.get(1, now + REPLICA_COUNT_TTL), None);
+    }
+
+    #[test]
+    fn should_miss_replica_count_after_membership_change() {
+        let mut replica_count = ReplicaCount::new(REPLICA_COUNT_TTL);
+        let now = Instant::now();
+
+        replica_count.put(3, 1, now);
+
+        assert_eq!(replica_count.get(2, now), None);
+    }
+}
@@ -0,0 +1,124 @@
+use std::sync::Arc;
+
+use crate::{
+    messaging::queue::{
+        QueueOfferRequest, QueueOfferResponse, QueuePeekRequest, QueuePeekResponse, QueuePollRequest,
+        QueuePollResponse, QueueSizeRequest, QueueSizeResponse,
+    },
+    protocol::partition_key,
+    remote::cluster::Cluster,
+    serialization::{FromData, ToData},
+    Result,
+};
+
+const NO_WAIT: i64 = 0;
+
+pub struct Queue {
+    name: String,
+    cluster: Arc<Cluster>,
+}
+
+impl Queue {
+    pub(crate) fn new(name: &str, cluster: Arc<Cluster>) -> Self {
+        Queue {
+            name: name.to_string(),
+            cluster,
+        }
+    }
+
+    // a bounded queue that is full rejects the value right away
+    pub async fn offer<T: ToData + ?Sized>(&self, value: &T) -> Result<bool> {
+        let value = value.to_data()?;
+        let request = QueueOfferRequest::new(&self.name, &value, NO_WAIT, self.partition_id().await?);
+        let response: QueueOfferResponse = self.cluster.dispatch(request).await?;
+        Ok(response.offered())
+    }
+
+    pub async fn poll<T: FromData>(&self) -> Result<Option<T>> {
+        let request = QueuePollRequest::new(&self.name, NO_WAIT, self.partition_id().await?);
+        let response: QueuePollResponse = self.cluster.dispatch(request).await?;
+        response.value().as_deref().map(T::from_data).transpose()
+    }
+
+    pub async fn peek<T: FromData>(&self) -> Result<Option<T>> {
+        let request = QueuePeekRequest::new(&self.name, self.partition_id().await?);
+        let response: QueuePeekResponse = self.cluster.dispatch(request).await?;
+        response.value().as_deref().map(T::from_data).transpose()
+    }
+
+    pub async fn size(&self) -> Result<usize> {
+        use std::convert::TryInto;
+
+        let request = QueueSizeRequest::new(&self.name, self.partition_id().await?);
+        let response: QueueSizeResponse = self.cluster.dispatch(request).await?;
+        Ok(response.size().try_into().unwrap_or(0))
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn partition_id(&self) -> Result<i32> {
+        self.cluster
+            .partition_id(partition_key(&self.name).partition_hash())
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::VecDeque, sync::Mutex};
+
+    use bytes::{Buf, BytesMut};
+
+    use crate::{
+        codec::{Reader, Writer},
+        remote::mock::start_cluster,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn should_offer_peek_and_poll_in_order() {
+        let stored = Arc::new(Mutex::new(VecDeque::new()));
+        let queue = stored.clone();
+        let (_member, cluster) = start_cluster(move |message| {
+            let readable = &mut message.payload();
+            let writeable = &mut BytesMut::new();
+            let mut queue = queue.lock().unwrap();
+            match message.r#type() {
+                0x0301 => {
+                    let _name = String::read_from(readable).unwrap();
+                    queue.push_back(Vec::<u8>::read_from(readable).unwrap());
+                    true.write_to(writeable);
+                    Some((0x65, writeable.to_bytes()))
+                }
+                0x0303 => {
+                    (queue.len() as i32).write_to(writeable);
+                    Some((0x66, writeable.to_bytes()))
+                }
+                0x0305 => {
+                    queue.pop_front().as_deref().write_to(writeable);
+                    Some((0x69, writeable.to_bytes()))
+                }
+                0x0307 => {
+                    queue.front().map(Vec::as_slice).write_to(writeable);
+                    Some((0x69, writeable.to_bytes()))
+                }
+                _ => None,
+            }
+        })
+        .await;
+        let queue = Queue::new("queue", cluster);
+
+        assert!(queue.offer("first").await.unwrap());
+        assert!(queue.offer(&2i64).await.unwrap());
+        assert_eq!(queue.size().await.unwrap(), 2);
+        assert_eq!(stored.lock().unwrap().front(), Some(&"first".to_data().unwrap()));
+        assert_eq!(queue.peek::<String>().await.unwrap(), Some("first".to_string()));
+        assert_eq!(queue.poll::<String>().await.unwrap(), Some("first".to_string()));
+        assert_eq!(queue.poll::<i64>().await.unwrap(), Some(2));
+        assert_eq!(queue.poll::<String>().await.unwrap(), None);
+        assert_eq!(queue.peek::<String>().await.unwrap(), None);
+    }
+}
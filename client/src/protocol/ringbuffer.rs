@@ -0,0 +1,129 @@
+use std::sync::Arc;
+
+use crate::{
+    messaging::ringbuffer::{
+        RingbufferAddRequest, RingbufferAddResponse, RingbufferCapacityRequest, RingbufferCapacityResponse,
+        RingbufferReadOneRequest, RingbufferReadOneResponse, RingbufferTailSequenceRequest,
+        RingbufferTailSequenceResponse,
+    },
+    protocol::partition_key,
+    remote::cluster::Cluster,
+    serialization::{FromData, ToData},
+    Result,
+};
+
+// a full ringbuffer drops its oldest item rather than rejecting the new one
+const OVERWRITE: i32 = 0;
+
+pub struct Ringbuffer {
+    name: String,
+    cluster: Arc<Cluster>,
+}
+
+impl Ringbuffer {
+    pub(crate) fn new(name: &str, cluster: Arc<Cluster>) -> Self {
+        Ringbuffer {
+            name: name.to_string(),
+            cluster,
+        }
+    }
+
+    pub async fn add<T: ToData + ?Sized>(&self, value: &T) -> Result<i64> {
+        let value = value.to_data()?;
+        let request = RingbufferAddRequest::new(&self.name, OVERWRITE, &value, self.partition_id().await?);
+        let response: RingbufferAddResponse = self.cluster.dispatch(request).await?;
+        Ok(response.sequence())
+    }
+
+    pub async fn read_one<T: FromData>(&self, sequence: i64) -> Result<Option<T>> {
+        let request = RingbufferReadOneRequest::new(&self.name, sequence, self.partition_id().await?);
+        let response: RingbufferReadOneResponse = self.cluster.dispatch(request).await?;
+        response.value().as_deref().map(T::from_data).transpose()
+    }
+
+    pub async fn capacity(&self) -> Result<i64> {
+        let request = RingbufferCapacityRequest::new(&self.name, self.partition_id().await?);
+        let response: RingbufferCapacityResponse = self.cluster.dispatch(request).await?;
+        Ok(response.capacity())
+    }
+
+    pub async fn tail_sequence(&self) -> Result<i64> {
+        let request = RingbufferTailSequenceRequest::new(&self.name, self.partition_id().await?);
+        let response: RingbufferTailSequenceResponse = self.cluster.dispatch(request).await?;
+        Ok(response.sequence())
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn partition_id(&self) -> Result<i32> {
+        self.cluster
+            .partition_id(partition_key(&self.name).partition_hash())
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use bytes::{Buf, BytesMut};
+
+    use crate::{
+        codec::{Reader, Writer},
+        remote::mock::start_cluster,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn should_add_and_read_by_sequence() {
+        let stored: Arc<Mutex<Vec<Vec<u8>>>> = Arc::new(Mutex::new(Vec::new()));
+        let items = stored.clone();
+        let (_member, cluster) = start_cluster(move |message| {
+            let readable = &mut message.payload();
+            let writeable = &mut BytesMut::new();
+            let mut items = items.lock().unwrap();
+            if (0x1901..=0x190A).contains(&message.r#type()) {
+                assert_eq!(String::read_from(readable).unwrap(), "ringbuffer");
+            }
+            match message.r#type() {
+                0x1902 => {
+                    (items.len() as i64 - 1).write_to(writeable);
+                    Some((0x67, writeable.to_bytes()))
+                }
+                0x1904 => {
+                    10i64.write_to(writeable);
+                    Some((0x67, writeable.to_bytes()))
+                }
+                0x1906 => {
+                    assert_eq!(i32::read_from(readable).unwrap(), OVERWRITE);
+                    items.push(Vec::<u8>::read_from(readable).unwrap());
+                    (items.len() as i64 - 1).write_to(writeable);
+                    Some((0x67, writeable.to_bytes()))
+                }
+                0x1907 => {
+                    let sequence = i64::read_from(readable).unwrap() as usize;
+                    items.get(sequence).map(Vec::as_slice).write_to(writeable);
+                    Some((0x69, writeable.to_bytes()))
+                }
+                _ => None,
+            }
+        })
+        .await;
+        let ringbuffer = Ringbuffer::new("ringbuffer", cluster);
+
+        assert_eq!(ringbuffer.tail_sequence().await.unwrap(), -1);
+        assert_eq!(ringbuffer.add("first").await.unwrap(), 0);
+        assert_eq!(ringbuffer.add("second").await.unwrap(), 1);
+        assert_eq!(stored.lock().unwrap()[0], "first".to_data().unwrap());
+        assert_eq!(ringbuffer.tail_sequence().await.unwrap(), 1);
+        assert_eq!(
+            ringbuffer.read_one::<String>(1).await.unwrap(),
+            Some("second".to_string())
+        );
+        assert_eq!(ringbuffer.read_one::<String>(2).await.unwrap(), None);
+        assert_eq!(ringbuffer.capacity().await.unwrap(), 10);
+    }
+}
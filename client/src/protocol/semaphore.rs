@@ -0,0 +1,118 @@
+use std::sync::Arc;
+
+use crate::{
+    messaging::semaphore::{
+        SemaphoreAcquireRequest, SemaphoreAcquireResponse, SemaphoreAvailablePermitsRequest,
+        SemaphoreAvailablePermitsResponse, SemaphoreInitRequest, SemaphoreInitResponse, SemaphoreReleaseRequest,
+        SemaphoreReleaseResponse,
+    },
+    protocol::partition_key,
+    remote::cluster::Cluster,
+    Result,
+};
+
+pub struct Semaphore {
+    name: String,
+    cluster: Arc<Cluster>,
+}
+
+impl Semaphore {
+    pub(crate) fn new(name: &str, cluster: Arc<Cluster>) -> Self {
+        Semaphore {
+            name: name.to_string(),
+            cluster,
+        }
+    }
+
+    // only the first initialization takes effect, later ones report that the permits were already set
+    pub async fn init(&self, permits: i32) -> Result<bool> {
+        let request = SemaphoreInitRequest::new(&self.name, permits, self.partition_id().await?);
+        let response: SemaphoreInitResponse = self.cluster.dispatch(request).await?;
+        Ok(response.initialized())
+    }
+
+    pub async fn acquire(&self, permits: i32) -> Result<()> {
+        let request = SemaphoreAcquireRequest::new(&self.name, permits, self.partition_id().await?);
+        let _: SemaphoreAcquireResponse = self.cluster.dispatch(request).await?;
+        Ok(())
+    }
+
+    pub async fn release(&self, permits: i32) -> Result<()> {
+        let request = SemaphoreReleaseRequest::new(&self.name, permits, self.partition_id().await?);
+        let _: SemaphoreReleaseResponse = self.cluster.dispatch(request).await?;
+        Ok(())
+    }
+
+    pub async fn available_permits(&self) -> Result<i32> {
+        let request = SemaphoreAvailablePermitsRequest::new(&self.name, self.partition_id().await?);
+        let response: SemaphoreAvailablePermitsResponse = self.cluster.dispatch(request).await?;
+        Ok(response.permits())
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn partition_id(&self) -> Result<i32> {
+        self.cluster
+            .partition_id(partition_key(&self.name).partition_hash())
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use bytes::{Buf, BytesMut};
+
+    use crate::{
+        codec::{Reader, Writer},
+        remote::mock::start_cluster,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn should_track_permits() {
+        let permits: Mutex<Option<i32>> = Mutex::new(None);
+        let (_member, cluster) = start_cluster(move |message| {
+            let readable = &mut message.payload();
+            let writeable = &mut BytesMut::new();
+            if (0x0D01..=0x0D08).contains(&message.r#type()) {
+                assert_eq!(String::read_from(readable).unwrap(), "semaphore");
+            }
+            let mut permits = permits.lock().unwrap();
+            match message.r#type() {
+                0x0D01 => {
+                    let initial = i32::read_from(readable).unwrap();
+                    permits.is_none().write_to(writeable);
+                    permits.get_or_insert(initial);
+                    Some((0x65, writeable.to_bytes()))
+                }
+                0x0D02 => {
+                    *permits = permits.map(|available| available - i32::read_from(readable).unwrap());
+                    Some((0x64, writeable.to_bytes()))
+                }
+                0x0D03 => {
+                    permits.unwrap_or(0).write_to(writeable);
+                    Some((0x66, writeable.to_bytes()))
+                }
+                0x0D06 => {
+                    *permits = permits.map(|available| available + i32::read_from(readable).unwrap());
+                    Some((0x64, writeable.to_bytes()))
+                }
+                _ => None,
+            }
+        })
+        .await;
+        let semaphore = Semaphore::new("semaphore", cluster);
+
+        assert!(semaphore.init(3).await.unwrap());
+        assert!(!semaphore.init(5).await.unwrap());
+        semaphore.acquire(2).await.unwrap();
+        assert_eq!(semaphore.available_permits().await.unwrap(), 1);
+        semaphore.release(1).await.unwrap();
+        assert_eq!(semaphore.available_permits().await.unwrap(), 2);
+    }
+}
@@ -0,0 +1,124 @@
+use std::{convert::TryInto, sync::Arc};
+
+use crate::{
+    messaging::set::{
+        SetAddRequest, SetAddResponse, SetContainsRequest, SetContainsResponse, SetRemoveRequest, SetRemoveResponse,
+        SetSizeRequest, SetSizeResponse,
+    },
+    protocol::partition_key,
+    remote::cluster::Cluster,
+    serialization::ToData,
+    Result,
+};
+
+pub struct Set {
+    name: String,
+    cluster: Arc<Cluster>,
+}
+
+impl Set {
+    pub(crate) fn new(name: &str, cluster: Arc<Cluster>) -> Self {
+        Set {
+            name: name.to_string(),
+            cluster,
+        }
+    }
+
+    pub async fn add<T: ToData + ?Sized>(&self, value: &T) -> Result<bool> {
+        let value = value.to_data()?;
+        let request = SetAddRequest::new(&self.name, &value, self.partition_id().await?);
+        let response: SetAddResponse = self.cluster.dispatch(request).await?;
+        Ok(response.added())
+    }
+
+    pub async fn contains<T: ToData + ?Sized>(&self, value: &T) -> Result<bool> {
+        let value = value.to_data()?;
+        let request = SetContainsRequest::new(&self.name, &value, self.partition_id().await?);
+        let response: SetContainsResponse = self.cluster.dispatch(request).await?;
+        Ok(response.contains())
+    }
+
+    pub async fn remove<T: ToData + ?Sized>(&self, value: &T) -> Result<bool> {
+        let value = value.to_data()?;
+        let request = SetRemoveRequest::new(&self.name, &value, self.partition_id().await?);
+        let response: SetRemoveResponse = self.cluster.dispatch(request).await?;
+        Ok(response.removed())
+    }
+
+    pub async fn size(&self) -> Result<usize> {
+        let request = SetSizeRequest::new(&self.name, self.partition_id().await?);
+        let response: SetSizeResponse = self.cluster.dispatch(request).await?;
+        Ok(response.size().try_into().unwrap_or(0))
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn partition_id(&self) -> Result<i32> {
+        self.cluster
+            .partition_id(partition_key(&self.name).partition_hash())
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashSet, sync::Mutex};
+
+    use bytes::{Buf, BytesMut};
+
+    use crate::{
+        codec::{Reader, Writer},
+        remote::mock::start_cluster,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn should_add_contain_and_remove_distinct_values() {
+        let stored: Arc<Mutex<HashSet<Vec<u8>>>> = Arc::new(Mutex::new(HashSet::new()));
+        let set = stored.clone();
+        let (_member, cluster) = start_cluster(move |message| {
+            let readable = &mut message.payload();
+            let writeable = &mut BytesMut::new();
+            let mut set = set.lock().unwrap();
+            if (0x0601..=0x0605).contains(&message.r#type()) {
+                assert_eq!(String::read_from(readable).unwrap(), "set");
+            }
+            match message.r#type() {
+                0x0601 => {
+                    (set.len() as i32).write_to(writeable);
+                    Some((0x66, writeable.to_bytes()))
+                }
+                0x0602 => {
+                    set.contains(&Vec::<u8>::read_from(readable).unwrap())
+                        .write_to(writeable);
+                    Some((0x65, writeable.to_bytes()))
+                }
+                0x0604 => {
+                    set.insert(Vec::<u8>::read_from(readable).unwrap()).write_to(writeable);
+                    Some((0x65, writeable.to_bytes()))
+                }
+                0x0605 => {
+                    set.remove(&Vec::<u8>::read_from(readable).unwrap()).write_to(writeable);
+                    Some((0x65, writeable.to_bytes()))
+                }
+                _ => None,
+            }
+        })
+        .await;
+        let set = Set::new("set", cluster);
+
+        assert!(set.add("first").await.unwrap());
+        assert!(!set.add("first").await.unwrap());
+        assert!(set.add(&2i64).await.unwrap());
+        assert_eq!(set.size().await.unwrap(), 2);
+        assert!(stored.lock().unwrap().contains(&"first".to_data().unwrap()));
+        assert!(set.contains(&2i64).await.unwrap());
+        assert!(!set.contains(&2i32).await.unwrap());
+        assert!(set.remove(&2i64).await.unwrap());
+        assert!(!set.remove(&2i64).await.unwrap());
+        assert!(!set.contains(&2i64).await.unwrap());
+    }
+}
@@ -0,0 +1,174 @@
+use std::sync::Arc;
+
+use tokio::sync::mpsc;
+
+use crate::{
+    messaging::{
+        topic::{
+            TopicAddMessageListenerRequest, TopicAddMessageListenerResponse, TopicMessage, TopicPublishRequest,
+            TopicPublishResponse, TopicRemoveMessageListenerRequest, TopicRemoveMessageListenerResponse,
+        },
+        ListenerKind,
+    },
+    protocol::partition_key,
+    remote::cluster::Cluster,
+    runtime::{DefaultRuntime, Runtime},
+    serialization::ToData,
+    Result, TryFrom,
+};
+
+pub struct Topic {
+    name: String,
+    cluster: Arc<Cluster>,
+}
+
+impl Topic {
+    pub(crate) fn new(name: &str, cluster: Arc<Cluster>) -> Self {
+        Topic {
+            name: name.to_string(),
+            cluster,
+        }
+    }
+
+    pub async fn publish<T: ToData + ?Sized>(&self, message: &T) -> Result<()> {
+        let message = message.to_data()?;
+        let request = TopicPublishRequest::new(&self.name, &message, self.partition_id().await?);
+        let _: TopicPublishResponse = self.cluster.dispatch(request).await?;
+        Ok(())
+    }
+
+    // the stream yields an error and ends once the member answers the subscription with anything but an event
+    pub async fn add_listener(&self) -> Result<(String, mpsc::UnboundedReceiver<Result<TopicMessage>>)> {
+        let name = self.name.clone();
+        let (registration_id, mut events) = self
+            .cluster
+            .listen::<_, TopicAddMessageListenerResponse, _, TopicRemoveMessageListenerResponse, _>(
+                ListenerKind::Topic,
+                TopicAddMessageListenerRequest::new(&self.name, false),
+                move |registration_id| TopicRemoveMessageListenerRequest::new(name.clone(), registration_id),
+            )
+            .await?;
+
+        let (sender, messages) = mpsc::unbounded_channel();
+        DefaultRuntime::spawn(async move {
+            while let Some(event) = events.recv().await {
+                if sender.send(TryFrom::<TopicMessage>::try_from(event)).is_err() {
+                    break;
+                }
+            }
+        });
+        Ok((registration_id, messages))
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn partition_id(&self) -> Result<i32> {
+        self.cluster
+            .partition_id(partition_key(&self.name).partition_hash())
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::Mutex, time::Duration};
+
+    use bytes::{Buf, Bytes, BytesMut};
+
+    use crate::{
+        codec::{Reader, Writer},
+        config::ClientConfig,
+        remote::mock::{event, exception, frame, registration_payload, start_cluster, MockMember},
+        HazelcastClientError,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn should_publish_message() {
+        let published: Arc<Mutex<Vec<Vec<u8>>>> = Arc::new(Mutex::new(Vec::new()));
+        let received = published.clone();
+        let (_member, cluster) = start_cluster(move |message| {
+            let readable = &mut message.payload();
+            match message.r#type() {
+                0x0401 => {
+                    assert_eq!(String::read_from(readable).unwrap(), "topic");
+                    received.lock().unwrap().push(Vec::<u8>::read_from(readable).unwrap());
+                    Some((0x64, Bytes::new()))
+                }
+                _ => None,
+            }
+        })
+        .await;
+        let topic = Topic::new("topic", cluster);
+
+        topic.publish("message").await.unwrap();
+
+        assert_eq!(*published.lock().unwrap(), vec!["message".to_data().unwrap()]);
+    }
+
+    #[tokio::test]
+    async fn should_receive_published_messages() {
+        let member =
+            MockMember::start_with_frames("member", Duration::from_secs(0), |message| match message.r#type() {
+                0x0402 => {
+                    assert_eq!(String::read_from(&mut message.payload()).unwrap(), "topic");
+                    let events = (1..=2i64).map(|item| {
+                        let writeable = &mut BytesMut::new();
+                        let item = item.to_data().unwrap();
+                        Writer::write_to(&item.as_slice(), writeable);
+                        7i64.write_to(writeable);
+                        "publisher".write_to(writeable);
+                        event(message.id(), 0xCD, &writeable.to_bytes())
+                    });
+                    Some(
+                        std::iter::once(frame(message.id(), 0x68, &registration_payload()))
+                            .chain(events)
+                            .collect(),
+                    )
+                }
+                _ => None,
+            })
+            .await;
+        let cluster = Cluster::init(&ClientConfig::builder().addresses(&[member.address()]).build())
+            .await
+            .unwrap();
+        let topic = Topic::new("topic", Arc::new(cluster));
+
+        let (registration_id, mut messages) = topic.add_listener().await.unwrap();
+
+        assert_eq!(registration_id, "registration-id");
+        for item in 1..=2i64 {
+            let message = messages.recv().await.unwrap().unwrap();
+            assert_eq!(message.message::<i64>().unwrap(), item);
+            assert_eq!(message.publisher(), "publisher");
+        }
+    }
+
+    #[tokio::test]
+    async fn should_end_subscription_with_error_reply() {
+        let member =
+            MockMember::start_with_frames("member", Duration::from_secs(0), |message| match message.r#type() {
+                0x0402 => Some(vec![
+                    frame(message.id(), 0x68, &registration_payload()),
+                    frame(message.id(), 0x6D, &exception(9, "DistributedObjectDestroyedException")),
+                ]),
+                _ => None,
+            })
+            .await;
+        let cluster = Cluster::init(&ClientConfig::builder().addresses(&[member.address()]).build())
+            .await
+            .unwrap();
+        let topic = Topic::new("topic", Arc::new(cluster));
+
+        let (_, mut messages) = topic.add_listener().await.unwrap();
+
+        assert!(matches!(
+            messages.recv().await,
+            Some(Err(HazelcastClientError::ServerFailure(_)))
+        ));
+        assert!(messages.recv().await.is_none());
+    }
+}
@@ -0,0 +1,349 @@
+use std::{convert::TryInto, sync::Arc, time::Duration};
+
+use crate::{
+    messaging::{
+        transaction::{
+            TransactionCommitRequest, TransactionCommitResponse, TransactionCreateRequest, TransactionCreateResponse,
+            TransactionRollbackRequest, TransactionRollbackResponse, TransactionalMapGetRequest,
+            TransactionalMapGetResponse, TransactionalMapPutRequest, TransactionalMapPutResponse,
+            TransactionalMapRemoveRequest, TransactionalMapRemoveResponse,
+        },
+        Address, Request, Response,
+    },
+    remote::cluster::Cluster,
+    serialization::{FromData, ToData},
+    Result,
+};
+
+const THREAD_ID: i64 = 1;
+const DEFAULT_TTL: i64 = -1;
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum TransactionType {
+    TwoPhase,
+    OnePhase,
+}
+
+impl TransactionType {
+    fn id(self) -> i32 {
+        match self {
+            TransactionType::TwoPhase => 1,
+            TransactionType::OnePhase => 2,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct TransactionOptions {
+    timeout: Duration,
+    durability: u32,
+    transaction_type: TransactionType,
+}
+
+impl TransactionOptions {
+    pub fn builder() -> TransactionOptionsBuilder {
+        TransactionOptionsBuilder {
+            options: TransactionOptions::default(),
+        }
+    }
+}
+
+impl Default for TransactionOptions {
+    fn default() -> Self {
+        TransactionOptions {
+            timeout: Duration::from_secs(120),
+            durability: 1,
+            transaction_type: TransactionType::TwoPhase,
+        }
+    }
+}
+
+pub struct TransactionOptionsBuilder {
+    options: TransactionOptions,
+}
+
+impl TransactionOptionsBuilder {
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.options.timeout = timeout;
+        self
+    }
+
+    pub fn durability(mut self, durability: u32) -> Self {
+        self.options.durability = durability;
+        self
+    }
+
+    pub fn transaction_type(mut self, transaction_type: TransactionType) -> Self {
+        self.options.transaction_type = transaction_type;
+        self
+    }
+
+    pub fn build(self) -> TransactionOptions {
+        self.options
+    }
+}
+
+// a transaction is bound to the member it was created on, so all of its operations are forwarded there
+pub struct Transaction {
+    id: String,
+    address: Address,
+    cluster: Arc<Cluster>,
+}
+
+impl Transaction {
+    pub(crate) async fn begin(options: TransactionOptions, cluster: Arc<Cluster>) -> Result<Self> {
+        let address = cluster.address(None).await?;
+        let timeout = options.timeout.as_millis().try_into().unwrap_or(i64::MAX);
+        let durability = options.durability.try_into().unwrap_or(i32::MAX);
+        let request = TransactionCreateRequest::new(timeout, durability, options.transaction_type.id(), THREAD_ID);
+        let response: TransactionCreateResponse = cluster.forward(request, &address).await?;
+
+        Ok(Transaction {
+            id: response.transaction_id(),
+            address,
+            cluster,
+        })
+    }
+
+    pub async fn commit(self) -> Result<()> {
+        let request = TransactionCommitRequest::new(&self.id, THREAD_ID);
+        let _: TransactionCommitResponse = self.cluster.forward(request, &self.address).await?;
+        Ok(())
+    }
+
+    pub async fn rollback(self) -> Result<()> {
+        let request = TransactionRollbackRequest::new(&self.id, THREAD_ID);
+        let _: TransactionRollbackResponse = self.cluster.forward(request, &self.address).await?;
+        Ok(())
+    }
+
+    pub fn map(&self, name: &str) -> TransactionalMap<'_> {
+        TransactionalMap {
+            name: name.to_string(),
+            transaction: self,
+        }
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+pub struct TransactionalMap<'a> {
+    name: String,
+    transaction: &'a Transaction,
+}
+
+impl<'a> TransactionalMap<'a> {
+    pub async fn get<K: ToData + ?Sized, V: FromData>(&self, key: &K) -> Result<Option<V>> {
+        let key = key.to_data()?;
+        let request = TransactionalMapGetRequest::new(&self.name, &self.transaction.id, THREAD_ID, &key);
+        let response: TransactionalMapGetResponse = self.forward(request).await?;
+        response.value().as_deref().map(V::from_data).transpose()
+    }
+
+    pub async fn put<K: ToData + ?Sized, V: ToData + FromData>(&self, key: &K, value: &V) -> Result<Option<V>> {
+        let (key, value) = (key.to_data()?, value.to_data()?);
+        let request =
+            TransactionalMapPutRequest::new(&self.name, &self.transaction.id, THREAD_ID, &key, &value, DEFAULT_TTL);
+        let response: TransactionalMapPutResponse = self.forward(request).await?;
+        response.value().as_deref().map(V::from_data).transpose()
+    }
+
+    pub async fn remove<K: ToData + ?Sized, V: FromData>(&self, key: &K) -> Result<Option<V>> {
+        let key = key.to_data()?;
+        let request = TransactionalMapRemoveRequest::new(&self.name, &self.transaction.id, THREAD_ID, &key);
+        let response: TransactionalMapRemoveResponse = self.forward(request).await?;
+        response.value().as_deref().map(V::from_data).transpose()
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn forward<RQ, RS>(&self, request: RQ) -> Result<RS>
+    where
+        RQ: Request,
+        RS: Response,
+    {
+        self.transaction
+            .cluster
+            .forward(request, &self.transaction.address)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, sync::Mutex};
+
+    use bytes::{Buf, BytesMut};
+
+    use crate::{
+        codec::{Reader, Writer},
+        config::{ClientConfig, MapConfig},
+        protocol::map::Map,
+        remote::mock::{partitions_payload, MockMember},
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn should_put_and_get_within_transaction() {
+        let committed = Arc::new(Mutex::new(Vec::new()));
+        let commits = committed.clone();
+        let entries = Arc::new(Mutex::new(HashMap::new()));
+        let member = MockMember::start_with("member", move |message| {
+            let readable = &mut message.payload();
+            let writeable = &mut BytesMut::new();
+            match message.r#type() {
+                0x1702 => {
+                    "transaction-id".write_to(writeable);
+                    Some((0x68, writeable.to_bytes()))
+                }
+                0x1006 | 0x1002 => {
+                    let _name = String::read_from(readable).unwrap();
+                    assert_eq!(String::read_from(readable).unwrap(), "transaction-id");
+                    let _thread_id = i64::read_from(readable).unwrap();
+                    let key = Vec::<u8>::read_from(readable).unwrap();
+                    let previous = match message.r#type() {
+                        0x1006 => entries
+                            .lock()
+                            .unwrap()
+                            .insert(key, Vec::<u8>::read_from(readable).unwrap()),
+                        _ => entries.lock().unwrap().get(&key).cloned(),
+                    };
+                    previous.as_deref().write_to(writeable);
+                    Some((0x69, writeable.to_bytes()))
+                }
+                0x1701 => {
+                    commits.lock().unwrap().push(String::read_from(readable).unwrap());
+                    Some((0x64, writeable.to_bytes()))
+                }
+                _ => None,
+            }
+        })
+        .await;
+        let cluster = Cluster::init(&ClientConfig::builder().addresses(&[member.address()]).build())
+            .await
+            .unwrap();
+
+        let transaction = Transaction::begin(TransactionOptions::default(), Arc::new(cluster))
+            .await
+            .unwrap();
+        assert_eq!(transaction.id(), "transaction-id");
+
+        let map = transaction.map("map-name");
+        assert_eq!(map.put(&1i64, &2i64).await.unwrap(), None);
+        assert_eq!(map.put(&1i64, &3i64).await.unwrap(), Some(2));
+        assert_eq!(map.get(&1i64).await.unwrap(), Some(3i64));
+
+        transaction.commit().await.unwrap();
+        assert_eq!(*committed.lock().unwrap(), vec!["transaction-id".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn should_roll_back_transaction() {
+        let rolled_back = Arc::new(Mutex::new(Vec::new()));
+        let rollbacks = rolled_back.clone();
+        let member = MockMember::start_with("member", move |message| {
+            let readable = &mut message.payload();
+            let writeable = &mut BytesMut::new();
+            match message.r#type() {
+                0x1702 => {
+                    assert_eq!(i64::read_from(readable).unwrap(), 1000);
+                    assert_eq!(i32::read_from(readable).unwrap(), 2);
+                    assert_eq!(i32::read_from(readable).unwrap(), 2);
+                    assert_eq!(i64::read_from(readable).unwrap(), THREAD_ID);
+                    assert_eq!(readable.remaining(), 0);
+                    "transaction-id".write_to(writeable);
+                    Some((0x68, writeable.to_bytes()))
+                }
+                0x1703 => {
+                    rollbacks.lock().unwrap().push(String::read_from(readable).unwrap());
+                    Some((0x64, writeable.to_bytes()))
+                }
+                _ => None,
+            }
+        })
+        .await;
+        let cluster = Cluster::init(&ClientConfig::builder().addresses(&[member.address()]).build())
+            .await
+            .unwrap();
+        let options = TransactionOptions::builder()
+            .timeout(Duration::from_secs(1))
+            .durability(2)
+            .transaction_type(TransactionType::OnePhase)
+            .build();
+
+        let transaction = Transaction::begin(options, Arc::new(cluster)).await.unwrap();
+        transaction.rollback().await.unwrap();
+
+        assert_eq!(*rolled_back.lock().unwrap(), vec!["transaction-id".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn should_read_committed_value_through_map() {
+        let (pending, committed) = (
+            Arc::new(Mutex::new(HashMap::new())),
+            Arc::new(Mutex::new(HashMap::new())),
+        );
+        let member = MockMember::start_with("member", move |message| {
+            let readable = &mut message.payload();
+            let writeable = &mut BytesMut::new();
+            match message.r#type() {
+                0x8 => Some((0x6C, partitions_payload(1))),
+                0x1702 => {
+                    "transaction-id".write_to(writeable);
+                    Some((0x68, writeable.to_bytes()))
+                }
+                0x1006 => {
+                    let _name = String::read_from(readable).unwrap();
+                    let _transaction_id = String::read_from(readable).unwrap();
+                    let _thread_id = i64::read_from(readable).unwrap();
+                    let key = Vec::<u8>::read_from(readable).unwrap();
+                    let value = Vec::<u8>::read_from(readable).unwrap();
+                    pending.lock().unwrap().insert(key, value);
+                    Option::<&[u8]>::None.write_to(writeable);
+                    Some((0x69, writeable.to_bytes()))
+                }
+                0x1701 => {
+                    committed.lock().unwrap().extend(pending.lock().unwrap().drain());
+                    Some((0x64, writeable.to_bytes()))
+                }
+                0x102 => {
+                    let _name = String::read_from(readable).unwrap();
+                    let key = Vec::<u8>::read_from(readable).unwrap();
+                    committed
+                        .lock()
+                        .unwrap()
+                        .get(&key)
+                        .map(Vec::as_slice)
+                        .write_to(writeable);
+                    Some((0x69, writeable.to_bytes()))
+                }
+                _ => None,
+            }
+        })
+        .await;
+        let cluster = Cluster::init(&ClientConfig::builder().addresses(&[member.address()]).build())
+            .await
+            .unwrap();
+        let cluster = Arc::new(cluster);
+        let mut map = Map::new("map-name", MapConfig::default(), cluster.clone());
+
+        let transaction = Transaction::begin(TransactionOptions::default(), cluster)
+            .await
+            .unwrap();
+        let previous: Option<String> = transaction
+            .map("map-name")
+            .put("key", &"value".to_string())
+            .await
+            .unwrap();
+        assert_eq!(previous, None);
+        assert_eq!(map.get::<_, String>("key").await.unwrap(), None);
+        transaction.commit().await.unwrap();
+
+        assert_eq!(map.get::<_, String>("key").await.unwrap(), Some("value".to_string()));
+    }
+}
@@ -0,0 +1,30 @@
+use bytes::Bytes;
+
+use crate::{
+    messaging::map::MapContainsValueRequest,
+    remote::{
+        fragment::{self, FragmentAssembler, MAX_PENDING_LENGTH},
+        Message, LENGTH_FIELD_LENGTH,
+    },
+};
+
+// the frames of a message carrying the given number of bytes, fragmented the way the channel writes them
+pub fn frames(payload_length: usize, max_frame_size: usize) -> Vec<Bytes> {
+    let value = vec![0u8; payload_length];
+    let frame = Message::encode(1, &MapContainsValueRequest::new("bench", &value)).payload();
+    if frame.len() + LENGTH_FIELD_LENGTH <= max_frame_size {
+        return vec![frame];
+    }
+    fragment::split(&frame, max_frame_size)
+}
+
+// decodes the frames as the channel reads them, returning the payload length of every assembled message
+pub fn assemble(frames: &[Bytes]) -> usize {
+    let mut assembler = FragmentAssembler::new(MAX_PENDING_LENGTH);
+    frames
+        .iter()
+        .filter_map(|frame| Message::decode(frame.clone()).ok())
+        .filter_map(|message| assembler.assemble(message))
+        .map(|message| message.payload().len())
+        .sum()
+}
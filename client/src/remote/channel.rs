@@ -1,86 +1,264 @@
 use std::{
+    collections::HashMap,
     error::Error,
+    future::Future,
+    io,
     net::SocketAddr,
     pin::Pin,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
     task::{Context, Poll},
 };
 
-use bytes::{Buf, Bytes, BytesMut};
+use bytes::{Buf, BytesMut};
+use log::{log_enabled, trace, warn, Level};
 use tokio::{
-    net::tcp::{ReadHalf, WriteHalf},
+    io::{AsyncRead, AsyncWrite},
     prelude::*,
     stream::Stream,
     sync::{mpsc, oneshot},
 };
 use tokio_util::codec::{FramedRead, FramedWrite, LengthDelimitedCodec};
 
-use crate::remote::{Message, LENGTH_FIELD_ADJUSTMENT, LENGTH_FIELD_LENGTH, LENGTH_FIELD_OFFSET, PROTOCOL_SEQUENCE};
+use crate::{
+    remote::{
+        fragment::{self, FragmentAssembler, MAX_PENDING_LENGTH},
+        transport::{Secured, Transport},
+        Message, LENGTH_FIELD_ADJUSTMENT, LENGTH_FIELD_LENGTH, LENGTH_FIELD_OFFSET, PROTOCOL_SEQUENCE,
+    },
+    runtime::{DefaultRuntime, Runtime},
+};
 
 type Result<T> = std::result::Result<T, Box<dyn Error + Send + Sync>>;
-type Responder = oneshot::Sender<Message>;
+type Responder = oneshot::Sender<Result<Message>>;
+type Listeners = Arc<Mutex<HashMap<u64, mpsc::UnboundedSender<Message>>>>;
+
+const TRACE_PAYLOAD_LIMIT: usize = 256;
 
 enum Event {
     Egress((Message, Responder)),
+    Cancellation(u64),
     Ingress(BytesMut),
+    Shutdown,
 }
 
 pub(in crate::remote) struct Channel {
     egress: mpsc::UnboundedSender<(Message, Responder)>,
+    cancellations: mpsc::UnboundedSender<u64>,
+    listeners: Listeners,
+    connected: Arc<AtomicBool>,
+    shutdown: Mutex<Option<oneshot::Sender<()>>>,
 }
 
 impl Channel {
-    pub(in crate::remote) async fn connect(address: &SocketAddr) -> Result<Self> {
-        use std::collections::HashMap;
-        use tokio::{net::TcpStream, stream::StreamExt};
+    pub(in crate::remote) async fn connect(
+        address: &SocketAddr,
+        transport: &Transport,
+        max_frame_size: usize,
+    ) -> Result<Self> {
+        let stream = DefaultRuntime::connect(address).await?;
+        match transport.secure(stream).await? {
+            Secured::Plain(stream) => Channel::start(stream, max_frame_size).await,
+            #[cfg(feature = "tls")]
+            Secured::Tls(stream) => Channel::start(stream, max_frame_size).await,
+        }
+    }
+
+    async fn start<S>(mut stream: S, max_frame_size: usize) -> Result<Self>
+    where
+        S: AsyncRead + AsyncWrite + Send + Unpin + 'static,
+    {
+        use tokio::stream::StreamExt;
 
-        let mut stream = TcpStream::connect(address).await?;
         stream.write_all(&PROTOCOL_SEQUENCE).await?;
 
         let (sender, receiver) = mpsc::unbounded_channel();
-        tokio::spawn(async move {
-            let (reader, writer) = stream.split();
-            let mut writer = Writer::new(writer);
-            let mut events = Events::new(receiver, reader);
+        let (cancellations, cancellation_receiver) = mpsc::unbounded_channel();
+        let listeners: Listeners = Arc::new(Mutex::new(HashMap::new()));
+        let subscriptions = listeners.clone();
+        let connected = Arc::new(AtomicBool::new(true));
+        let connection = connected.clone();
+        let (shutdown, shutdown_receiver) = oneshot::channel();
+        DefaultRuntime::spawn(async move {
+            let (reader, writer) = tokio::io::split(stream);
+            let mut writer = Writer::new(writer, max_frame_size);
+            let mut events = Events::new(receiver, cancellation_receiver, reader, shutdown_receiver);
 
             let mut correlations = HashMap::with_capacity(1024);
-            while let Some(event) = events.next().await {
-                match event {
-                    Ok(Event::Egress((message, responder))) => {
-                        writer.write(message.payload()).await?;
+            let mut fragments = FragmentAssembler::new(MAX_PENDING_LENGTH);
+            let result = loop {
+                match events.next().await {
+                    Some(Ok(Event::Egress((message, responder)))) => {
+                        // the caller of the message that failed to be written learns the cause right away, while the
+                        // connection is only given up when the socket itself failed rather than the codec refusing it
+                        if let Err(e) = writer.write(&message).await {
+                            let (cause, refused) = (e.to_string(), e.kind() == io::ErrorKind::InvalidInput);
+                            let _ = responder.send(Err(e.into()));
+                            if refused {
+                                continue;
+                            }
+                            break Err(cause.into());
+                        }
                         correlations.insert(message.id(), responder);
                     }
-                    Ok(Event::Ingress(mut frame)) => {
-                        let message: Message = frame.to_bytes().into();
-                        match correlations
-                            .remove(&message.id())
-                            .expect("missing correlation!")
-                            .send(message)
-                        {
-                            _ => {} // TODO:
+                    Some(Ok(Event::Cancellation(id))) => {
+                        correlations.remove(&id);
+                    }
+                    Some(Ok(Event::Ingress(mut frame))) => {
+                        // a frame too short to carry a header cannot be correlated, so it is dropped
+                        let message = match Message::decode(frame.to_bytes()) {
+                            Ok(message) => message,
+                            Err(e) => {
+                                warn!("Dropping malformed frame - {}", e);
+                                continue;
+                            }
+                        };
+                        let message = match fragments.assemble(message) {
+                            Some(message) => message,
+                            None => continue,
+                        };
+                        if !message.is_event() {
+                            if let Some(responder) = correlations.remove(&message.id()) {
+                                let _ = responder.send(Ok(message));
+                                continue;
+                            }
+                        }
+                        route(&subscriptions, message);
+                    }
+                    Some(Ok(Event::Shutdown)) => {
+                        for (_, responder) in correlations.drain() {
+                            let _ = responder.send(Err("client shutting down".into()));
                         }
+                        break Ok(());
                     }
-                    Err(e) => return Err(e),
+                    Some(Err(e)) => break Err(e),
+                    None => break Ok(()),
                 }
-            }
-            Ok(())
+            };
+            // ending the listener streams lets subscribers notice the connection is gone, while dropping the pending
+            // correlations fails their callers
+            connection.store(false, Ordering::SeqCst);
+            subscriptions.lock().expect("poisoned lock!").clear();
+            fragments.clear();
+            result
         });
 
-        Ok(Channel { egress: sender })
+        Ok(Channel {
+            egress: sender,
+            cancellations,
+            listeners,
+            connected,
+            shutdown: Mutex::new(Some(shutdown)),
+        })
+    }
+
+    pub(in crate::remote) fn register_listener(&self, correlation_id: u64) -> mpsc::UnboundedReceiver<Message> {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        self.listeners
+            .lock()
+            .expect("poisoned lock!")
+            .insert(correlation_id, sender);
+        receiver
+    }
+
+    pub(in crate::remote) fn deregister_listener(&self, correlation_id: u64) {
+        self.listeners.lock().expect("poisoned lock!").remove(&correlation_id);
+    }
+
+    // the connection is closed once pending invocations are failed, rather than when the last sender goes away
+    pub(in crate::remote) fn close(&self) {
+        if let Some(shutdown) = self.shutdown.lock().expect("poisoned lock!").take() {
+            let _ = shutdown.send(());
+        }
+    }
+
+    pub(in crate::remote) fn is_connected(&self) -> bool {
+        self.connected.load(Ordering::SeqCst)
+    }
+
+    pub(in crate::remote) fn is_listening(&self, correlation_id: u64) -> bool {
+        self.listeners
+            .lock()
+            .expect("poisoned lock!")
+            .contains_key(&correlation_id)
     }
 
+    pub(in crate::remote) fn has_listeners(&self) -> bool {
+        !self.listeners.lock().expect("poisoned lock!").is_empty()
+    }
+
+    // messages are written in the order they are submitted, which keeps the order of operations on a partition
     pub(in crate::remote) async fn send(&self, message: Message) -> Result<Message> {
         let (sender, receiver) = oneshot::channel();
+        let mut pending = Pending {
+            id: message.id(),
+            cancellations: &self.cancellations,
+            settled: false,
+        };
         self.egress.send((message, sender))?;
-        Ok(receiver.await?)
+        let response = receiver.await;
+        pending.settled = true;
+        response?
     }
 }
 
-struct Writer<'a> {
-    writer: FramedWrite<WriteHalf<'a>, LengthDelimitedCodec>,
+// a caller giving up on its response (e.g. once it timed out) drops the correlation, so a late response is discarded
+// rather than the correlation being kept for the lifetime of the connection
+struct Pending<'a> {
+    id: u64,
+    cancellations: &'a mpsc::UnboundedSender<u64>,
+    settled: bool,
+}
+
+impl Drop for Pending<'_> {
+    fn drop(&mut self) {
+        if !self.settled {
+            let _ = self.cancellations.send(self.id);
+        }
+    }
 }
 
-impl<'a> Writer<'a> {
-    fn new(writer: WriteHalf<'a>) -> Self {
+// events are delivered to the listener registered for their correlation, while any other message arriving for
+// a listener (e.g. an error because its structure was destroyed) is the final one and ends the subscription
+fn route(listeners: &Listeners, message: Message) {
+    let mut listeners = listeners.lock().expect("poisoned lock!");
+    let id = message.id();
+    if message.is_event() {
+        match listeners.get(&id) {
+            Some(listener) => {
+                if listener.send(message).is_err() {
+                    listeners.remove(&id);
+                }
+            }
+            None => trace!(
+                "Ignoring event (id: {}, type: {:#06x}) of unknown listener.",
+                id,
+                message.r#type()
+            ),
+        }
+    } else {
+        match listeners.remove(&id) {
+            Some(listener) => {
+                let _ = listener.send(message);
+            }
+            None => warn!(
+                "Ignoring message (id: {}, type: {:#06x}) of unknown correlation.",
+                id,
+                message.r#type()
+            ),
+        }
+    }
+}
+
+struct Writer<W> {
+    writer: FramedWrite<W, LengthDelimitedCodec>,
+    max_frame_size: usize,
+}
+
+impl<W: AsyncWrite + Unpin> Writer<W> {
+    fn new(writer: W, max_frame_size: usize) -> Self {
         let writer = LengthDelimitedCodec::builder()
             .length_field_offset(LENGTH_FIELD_OFFSET)
             .length_field_length(LENGTH_FIELD_LENGTH)
@@ -88,23 +266,59 @@ impl<'a> Writer<'a> {
             .little_endian()
             .new_write(writer);
 
-        Writer { writer }
+        Writer { writer, max_frame_size }
     }
 
-    async fn write(&mut self, frame: Bytes) -> Result<()> {
+    async fn write(&mut self, message: &Message) -> io::Result<()> {
         use futures::SinkExt;
 
-        Ok(self.writer.send(frame).await?)
+        let frame = message.payload();
+        if log_enabled!(Level::Trace) {
+            trace!(
+                "Sending message (id: {}, type: {:#06x}, length: {}): {}",
+                message.id(),
+                message.r#type(),
+                frame.len(),
+                hex(&frame, TRACE_PAYLOAD_LIMIT)
+            );
+        }
+        if frame.len() + LENGTH_FIELD_LENGTH <= self.max_frame_size {
+            return self.writer.send(frame).await;
+        }
+        for fragment in fragment::split(&frame, self.max_frame_size) {
+            self.writer.send(fragment).await?;
+        }
+        Ok(())
     }
 }
 
-struct Events<'a> {
+fn hex(bytes: &[u8], limit: usize) -> String {
+    let mut formatted = bytes
+        .iter()
+        .take(limit)
+        .map(|byte| format!("{:02x}", byte))
+        .collect::<Vec<String>>()
+        .join(" ");
+    if bytes.len() > limit {
+        formatted.push_str(&format!(" ... ({} more)", bytes.len() - limit));
+    }
+    formatted
+}
+
+struct Events<R> {
     egress: mpsc::UnboundedReceiver<(Message, Responder)>,
-    ingress: FramedRead<ReadHalf<'a>, LengthDelimitedCodec>,
+    cancellations: mpsc::UnboundedReceiver<u64>,
+    ingress: FramedRead<R, LengthDelimitedCodec>,
+    shutdown: oneshot::Receiver<()>,
 }
 
-impl<'a> Events<'a> {
-    fn new(messages: mpsc::UnboundedReceiver<(Message, Responder)>, reader: ReadHalf<'a>) -> Self {
+impl<R: AsyncRead> Events<R> {
+    fn new(
+        messages: mpsc::UnboundedReceiver<(Message, Responder)>,
+        cancellations: mpsc::UnboundedReceiver<u64>,
+        reader: R,
+        shutdown: oneshot::Receiver<()>,
+    ) -> Self {
         let reader = LengthDelimitedCodec::builder()
             .length_field_offset(LENGTH_FIELD_OFFSET)
             .length_field_length(LENGTH_FIELD_LENGTH)
@@ -114,20 +328,29 @@ impl<'a> Events<'a> {
 
         Events {
             egress: messages,
+            cancellations,
             ingress: reader,
+            shutdown,
         }
     }
 }
 
-impl Stream for Events<'_> {
+impl<R: AsyncRead + Unpin> Stream for Events<R> {
     type Item = Result<Event>;
 
     fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        match Pin::new(&mut self.shutdown).poll(cx) {
+            Poll::Pending => {}
+            _ => return Poll::Ready(Some(Ok(Event::Shutdown))),
+        }
         match Pin::new(&mut self.egress).poll_next(cx) {
             Poll::Ready(Some(payload)) => return Poll::Ready(Some(Ok(Event::Egress(payload)))),
             Poll::Ready(None) => return Poll::Ready(None),
             Poll::Pending => {}
         }
+        if let Poll::Ready(Some(id)) = Pin::new(&mut self.cancellations).poll_next(cx) {
+            return Poll::Ready(Some(Ok(Event::Cancellation(id))));
+        }
 
         Poll::Ready(match futures::ready!(Pin::new(&mut self.ingress).poll_next(cx)) {
             Some(Ok(frame)) => Some(Ok(Event::Ingress(frame))),
@@ -136,3 +359,510 @@ impl Stream for Events<'_> {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+    use log::{LevelFilter, Log, Metadata, Record};
+    use tokio::{
+        io::{ReadHalf, WriteHalf},
+        net::{TcpListener, TcpStream},
+    };
+
+    use crate::{
+        messaging::ping::{PingRequest, PingResponse},
+        remote::DEFAULT_MAX_FRAME_SIZE,
+        HazelcastClientError, TryFrom,
+    };
+
+    use super::*;
+
+    static LOGGER: CapturingLogger = CapturingLogger {
+        records: Mutex::new(Vec::new()),
+    };
+
+    struct CapturingLogger {
+        records: Mutex<Vec<String>>,
+    }
+
+    impl Log for CapturingLogger {
+        fn enabled(&self, _: &Metadata) -> bool {
+            true
+        }
+
+        fn log(&self, record: &Record) {
+            self.records.lock().unwrap().push(record.args().to_string());
+        }
+
+        fn flush(&self) {}
+    }
+
+    #[tokio::test]
+    async fn should_trace_outbound_message_as_hex() {
+        let _ = log::set_logger(&LOGGER);
+        log::set_max_level(LevelFilter::Trace);
+
+        let message: Message = (5, PingRequest::new()).into();
+        let mut writer = Writer::new(Vec::new(), DEFAULT_MAX_FRAME_SIZE);
+        writer.write(&message).await.unwrap();
+
+        let records = LOGGER.records.lock().unwrap();
+        assert!(records.contains(
+            &"Sending message (id: 5, type: 0x000f, length: 18): \
+              01 c0 0f 00 05 00 00 00 00 00 00 00 ff ff ff ff 16 00"
+                .to_string()
+        ));
+    }
+
+    #[tokio::test]
+    async fn should_send_messages_of_concurrent_tasks_in_submission_order() {
+        let mut listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move { echo(&mut listener, 4 * 25).await });
+
+        let channel = Arc::new(
+            Channel::connect(&address, &Transport::default(), DEFAULT_MAX_FRAME_SIZE)
+                .await
+                .unwrap(),
+        );
+        let tasks = (0..4u64).map(|task| {
+            let channel = channel.clone();
+            tokio::spawn(async move {
+                let sends = (0..25).map(|sequence| channel.send((task * 1000 + sequence, PingRequest::new()).into()));
+                futures::future::join_all(sends).await
+            })
+        });
+        futures::future::join_all(tasks).await;
+
+        let received = server.await.unwrap();
+        for task in 0..4u64 {
+            let ids: Vec<u64> = received.iter().copied().filter(|id| id / 1000 == task).collect();
+            assert_eq!(
+                ids,
+                (0..25).map(|sequence| task * 1000 + sequence).collect::<Vec<u64>>()
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn should_route_exception_to_waiting_caller() {
+        let mut listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move { reply_with_exception(&mut listener).await });
+
+        let channel = Channel::connect(&address, &Transport::default(), DEFAULT_MAX_FRAME_SIZE)
+            .await
+            .unwrap();
+        let message = channel.send((7, PingRequest::new()).into()).await.unwrap();
+
+        assert_eq!(message.id(), 7);
+        match TryFrom::<PingResponse>::try_from(message) {
+            Err(HazelcastClientError::ServerFailure(_)) => {}
+            _ => panic!("expected server failure!"),
+        }
+    }
+
+    #[tokio::test]
+    async fn should_fail_caller_of_truncated_response_without_disconnecting() {
+        let mut listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move { reply_with_truncated_frames(&mut listener).await });
+
+        let channel = Channel::connect(&address, &Transport::default(), DEFAULT_MAX_FRAME_SIZE)
+            .await
+            .unwrap();
+        let message = channel.send((7, PingRequest::new()).into()).await.unwrap();
+
+        assert_eq!(message.id(), 7);
+        match TryFrom::<PingResponse>::try_from(message) {
+            Err(HazelcastClientError::MalformedMessage(_)) => {}
+            _ => panic!("expected malformed message!"),
+        }
+        assert!(channel.is_connected());
+    }
+
+    #[tokio::test]
+    async fn should_keep_serving_after_message_of_unknown_correlation() {
+        let mut listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move { reply_with_unknown_correlation(&mut listener).await });
+
+        let channel = Channel::connect(&address, &Transport::default(), DEFAULT_MAX_FRAME_SIZE)
+            .await
+            .unwrap();
+        for id in 7..9 {
+            let message = channel.send((id, PingRequest::new()).into()).await.unwrap();
+            assert_eq!(message.id(), id);
+        }
+        assert!(channel.is_connected());
+    }
+
+    #[tokio::test]
+    async fn should_read_response_split_across_writes() {
+        let mut listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move { reply_in_two_writes(&mut listener).await });
+
+        let channel = Channel::connect(&address, &Transport::default(), DEFAULT_MAX_FRAME_SIZE)
+            .await
+            .unwrap();
+        let message = channel.send((7, PingRequest::new()).into()).await.unwrap();
+
+        assert_eq!(message.id(), 7);
+        assert_eq!(message.payload().bytes(), [1, 2, 3, 4]);
+    }
+
+    #[tokio::test]
+    async fn should_drop_correlation_of_abandoned_invocation() {
+        use std::time::Duration;
+
+        let mut listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let (release, released) = oneshot::channel();
+        tokio::spawn(async move { reply_once_released(&mut listener, released).await });
+
+        let channel = Channel::connect(&address, &Transport::default(), DEFAULT_MAX_FRAME_SIZE)
+            .await
+            .unwrap();
+        let invocation = channel.send((7, PingRequest::new()).into());
+        assert!(tokio::time::timeout(Duration::from_millis(50), invocation)
+            .await
+            .is_err());
+
+        // a retained correlation would swallow the late response instead of handing it to a listener of the same id
+        let mut events = channel.register_listener(7);
+        release.send(()).unwrap();
+
+        assert_eq!(events.recv().await.unwrap().id(), 7);
+    }
+
+    #[tokio::test]
+    async fn should_reassemble_fragmented_response() {
+        let mut listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move { reply_with_fragments(&mut listener).await });
+
+        let channel = Channel::connect(&address, &Transport::default(), DEFAULT_MAX_FRAME_SIZE)
+            .await
+            .unwrap();
+        let message = channel.send((7, PingRequest::new()).into()).await.unwrap();
+
+        assert_eq!(message.id(), 7);
+        assert_eq!(message.r#type(), 0x69);
+        assert_eq!(message.payload().bytes(), [0, 3, 0, 0, 0, 1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn should_route_events_to_registered_listener() {
+        use crate::remote::mock::event;
+
+        let mut listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let events = vec![event(3, 0xCD, &[1]), event(3, 0xCD, &[2]), event(4, 0xCD, &[3])];
+        tokio::spawn(async move { push(&mut listener, events).await });
+
+        let channel = Channel::connect(&address, &Transport::default(), DEFAULT_MAX_FRAME_SIZE)
+            .await
+            .unwrap();
+        let mut receiver = channel.register_listener(3);
+        channel.send((1, PingRequest::new()).into()).await.unwrap();
+
+        let first = receiver.recv().await.unwrap();
+        assert!(first.is_event());
+        assert_eq!(first.payload().bytes(), [1]);
+        assert_eq!(receiver.recv().await.unwrap().payload().bytes(), [2]);
+    }
+
+    #[tokio::test]
+    async fn should_route_events_sharing_pending_correlation_to_listener() {
+        use crate::remote::mock::event;
+
+        let mut listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let events = (0..10u8).map(|i| event(1, 0xCD, &[i])).collect();
+        tokio::spawn(async move { push(&mut listener, events).await });
+
+        let channel = Channel::connect(&address, &Transport::default(), DEFAULT_MAX_FRAME_SIZE)
+            .await
+            .unwrap();
+        let mut receiver = channel.register_listener(1);
+        let response = channel.send((1, PingRequest::new()).into()).await.unwrap();
+
+        assert!(!response.is_event());
+        for i in 0..10u8 {
+            assert_eq!(receiver.recv().await.unwrap().payload().bytes(), [i]);
+        }
+    }
+
+    #[tokio::test]
+    async fn should_end_listener_stream_on_final_message() {
+        use crate::remote::mock::{event, frame};
+
+        let mut listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let events = vec![event(3, 0xCD, &[1]), frame(3, 0x6D, &[]), event(3, 0xCD, &[2])];
+        tokio::spawn(async move { push(&mut listener, events).await });
+
+        let channel = Channel::connect(&address, &Transport::default(), DEFAULT_MAX_FRAME_SIZE)
+            .await
+            .unwrap();
+        let mut receiver = channel.register_listener(3);
+        channel.send((1, PingRequest::new()).into()).await.unwrap();
+
+        assert!(receiver.recv().await.unwrap().is_event());
+        let last = receiver.recv().await.unwrap();
+        assert!(!last.is_event());
+        assert_eq!(last.r#type(), 0x6D);
+        assert!(receiver.recv().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn should_end_listener_stream_on_deregistration() {
+        let mut listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move { push(&mut listener, vec![]).await });
+
+        let channel = Channel::connect(&address, &Transport::default(), DEFAULT_MAX_FRAME_SIZE)
+            .await
+            .unwrap();
+        let mut receiver = channel.register_listener(3);
+        assert!(channel.has_listeners());
+        channel.deregister_listener(3);
+
+        assert!(receiver.recv().await.is_none());
+        assert!(!channel.has_listeners());
+    }
+
+    #[tokio::test]
+    async fn should_end_listener_streams_when_connection_is_closed() {
+        let mut listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (mut reader, _) = accept(&mut listener).await;
+            tokio::stream::StreamExt::next(&mut reader).await;
+        });
+
+        let channel = Channel::connect(&address, &Transport::default(), DEFAULT_MAX_FRAME_SIZE)
+            .await
+            .unwrap();
+        let mut receiver = channel.register_listener(3);
+        assert!(channel.send((1, PingRequest::new()).into()).await.is_err());
+
+        assert!(receiver.recv().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn should_fail_only_caller_of_message_that_cannot_be_written() {
+        use crate::messaging::map::MapContainsValueRequest;
+
+        let mut listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move { push(&mut listener, vec![]).await });
+
+        // without fragmentation the frame exceeds what the codec is willing to write
+        let channel = Channel::connect(&address, &Transport::default(), 32 * 1024 * 1024)
+            .await
+            .unwrap();
+        let oversized = vec![0u8; 16 * 1024 * 1024];
+        let request = MapContainsValueRequest::new("map-name", &oversized);
+
+        match channel.send((1, request).into()).await {
+            Err(e) => assert!(e.to_string().contains("frame size too big")),
+            Ok(_) => panic!("expected write failure!"),
+        }
+        assert_eq!(channel.send((2, PingRequest::new()).into()).await.unwrap().id(), 2);
+        assert!(channel.is_connected());
+    }
+
+    #[tokio::test]
+    async fn should_fail_pending_and_subsequent_messages_when_connection_is_dropped() {
+        let mut listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (mut reader, _) = accept(&mut listener).await;
+            tokio::stream::StreamExt::next(&mut reader).await;
+        });
+
+        let channel = Channel::connect(&address, &Transport::default(), DEFAULT_MAX_FRAME_SIZE)
+            .await
+            .unwrap();
+        assert!(channel.is_connected());
+
+        assert!(channel.send((1, PingRequest::new()).into()).await.is_err());
+        assert!(!channel.is_connected());
+        assert!(channel.send((2, PingRequest::new()).into()).await.is_err());
+    }
+
+    async fn push(listener: &mut TcpListener, events: Vec<Bytes>) {
+        use crate::remote::mock::frame;
+        use futures::SinkExt;
+        use tokio::stream::StreamExt;
+
+        let (mut reader, mut writer) = accept(listener).await;
+
+        let request = Message::decode(reader.next().await.unwrap().unwrap().to_bytes()).unwrap();
+        for event in events {
+            writer.send(event).await.unwrap();
+        }
+        writer.send(frame(request.id(), 0x64, &[])).await.unwrap();
+        while reader.next().await.is_some() {}
+    }
+
+    async fn reply_with_exception(listener: &mut TcpListener) {
+        use crate::codec::Writer as _;
+        use futures::SinkExt;
+        use tokio::stream::StreamExt;
+
+        let (mut reader, mut writer) = accept(listener).await;
+
+        let frame = reader.next().await.unwrap().unwrap().to_bytes();
+        let message = Message::decode(frame).unwrap();
+
+        let exception = &mut BytesMut::new();
+        1u8.write_to(exception);
+        0xC0u8.write_to(exception);
+        0x6Du16.write_to(exception);
+        message.id().write_to(exception);
+        (-1i32).write_to(exception);
+        22u16.write_to(exception);
+        128i32.write_to(exception);
+        "NullPointerException".write_to(exception);
+        Option::<&str>::None.write_to(exception);
+        0u32.write_to(exception);
+        0u32.write_to(exception);
+        Option::<&str>::None.write_to(exception);
+        writer.send(exception.to_bytes()).await.unwrap();
+    }
+
+    async fn reply_with_fragments(listener: &mut TcpListener) {
+        use crate::remote::mock::frame_with;
+        use futures::SinkExt;
+        use tokio::stream::StreamExt;
+
+        let (mut reader, mut writer) = accept(listener).await;
+
+        let request = Message::decode(reader.next().await.unwrap().unwrap().to_bytes()).unwrap();
+        writer
+            .send(frame_with(0x80, request.id(), 0x69, &[0, 3, 0]))
+            .await
+            .unwrap();
+        writer
+            .send(frame_with(0x00, request.id(), 0x69, &[0, 0]))
+            .await
+            .unwrap();
+        writer
+            .send(frame_with(0x40, request.id(), 0x69, &[1, 2, 3]))
+            .await
+            .unwrap();
+        while reader.next().await.is_some() {}
+    }
+
+    async fn reply_with_unknown_correlation(listener: &mut TcpListener) {
+        use crate::remote::mock::frame;
+        use futures::SinkExt;
+        use tokio::stream::StreamExt;
+
+        let (mut reader, mut writer) = accept(listener).await;
+
+        while let Some(Ok(received)) = reader.next().await {
+            let request = Message::decode(received.freeze()).unwrap();
+            writer.send(frame(request.id() + 100, 0x64, &[])).await.unwrap();
+            writer.send(frame(request.id(), 0x64, &[])).await.unwrap();
+        }
+    }
+
+    async fn reply_once_released(listener: &mut TcpListener, released: oneshot::Receiver<()>) {
+        use crate::remote::mock::frame;
+        use futures::SinkExt;
+        use tokio::stream::StreamExt;
+
+        let (mut reader, mut writer) = accept(listener).await;
+
+        let request = Message::decode(reader.next().await.unwrap().unwrap().to_bytes()).unwrap();
+        released.await.unwrap();
+        writer.send(frame(request.id(), 0x64, &[])).await.unwrap();
+        while reader.next().await.is_some() {}
+    }
+
+    // the length field and the header arrive first, so nothing may be yielded until the rest of the frame follows
+    async fn reply_in_two_writes(listener: &mut TcpListener) {
+        use crate::remote::mock::frame;
+        use std::time::Duration;
+        use tokio::stream::StreamExt;
+
+        let (mut reader, mut writer) = accept(listener).await;
+
+        let request = Message::decode(reader.next().await.unwrap().unwrap().to_bytes()).unwrap();
+        let response = frame(request.id(), 0x64, &[1, 2, 3, 4]);
+        let length = (response.len() + LENGTH_FIELD_LENGTH) as u32;
+        let stream = writer.get_mut();
+        stream.write_all(&length.to_le_bytes()).await.unwrap();
+        stream.write_all(&response[..10]).await.unwrap();
+        stream.flush().await.unwrap();
+        tokio::time::delay_for(Duration::from_millis(50)).await;
+        stream.write_all(&response[10..]).await.unwrap();
+        while reader.next().await.is_some() {}
+    }
+
+    // a frame cut short within its header is dropped, while one cut short within its payload reaches the caller
+    async fn reply_with_truncated_frames(listener: &mut TcpListener) {
+        use crate::remote::mock::frame;
+        use futures::SinkExt;
+        use tokio::stream::StreamExt;
+
+        let (mut reader, mut writer) = accept(listener).await;
+
+        let request = Message::decode(reader.next().await.unwrap().unwrap().to_bytes()).unwrap();
+        let response = frame(request.id(), 0x6D, &128i32.to_le_bytes());
+        writer.send(response.slice(..response.len() / 2)).await.unwrap();
+        writer.send(response).await.unwrap();
+        while reader.next().await.is_some() {}
+    }
+
+    async fn echo(listener: &mut TcpListener, count: usize) -> Vec<u64> {
+        use futures::SinkExt;
+        use tokio::stream::StreamExt;
+
+        let (mut reader, mut writer) = accept(listener).await;
+
+        let mut ids = Vec::with_capacity(count);
+        while ids.len() < count {
+            let frame = reader.next().await.unwrap().unwrap().to_bytes();
+            let message = Message::decode(frame.clone()).unwrap();
+            ids.push(message.id());
+            writer.send(frame).await.unwrap();
+        }
+        ids
+    }
+
+    async fn accept(
+        listener: &mut TcpListener,
+    ) -> (
+        FramedRead<ReadHalf<TcpStream>, LengthDelimitedCodec>,
+        FramedWrite<WriteHalf<TcpStream>, LengthDelimitedCodec>,
+    ) {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        let mut protocol = [0u8; 3];
+        stream.read_exact(&mut protocol).await.unwrap();
+        assert_eq!(protocol, PROTOCOL_SEQUENCE);
+
+        let (reader, writer) = tokio::io::split(stream);
+        (
+            Events::new(
+                mpsc::unbounded_channel().1,
+                mpsc::unbounded_channel().1,
+                reader,
+                oneshot::channel().1,
+            )
+            .ingress,
+            Writer::new(writer, DEFAULT_MAX_FRAME_SIZE).writer,
+        )
+    }
+
+    #[test]
+    fn should_truncate_hex() {
+        assert_eq!(hex(&[], 2), "");
+        assert_eq!(hex(&[0x01, 0xab], 2), "01 ab");
+        assert_eq!(hex(&[0x01, 0xab, 0xff], 2), "01 ab ... (1 more)");
+    }
+}
@@ -1,59 +1,117 @@
 use std::{
     collections::{HashMap, HashSet},
+    future::Future,
     hash::Hash,
     net::SocketAddr,
     pin::Pin,
     sync::{
-        atomic::{AtomicUsize, Ordering},
-        Arc,
+        atomic::{AtomicBool, AtomicI64, AtomicUsize, Ordering},
+        Arc, Mutex, Weak,
     },
     task::{Context, Poll},
-    time::Duration,
+    time::{Duration, Instant},
 };
 
+use futures::{
+    future::{self, BoxFuture, Either},
+    stream::BoxStream,
+};
 use log::{error, info};
 use tokio::{
     stream::{Stream, StreamExt},
-    sync::{oneshot, RwLock},
-    time::Interval,
+    sync::{mpsc, oneshot, RwLock},
 };
 
 use crate::{
-    messaging::{Address, Request, Response},
-    remote::member::Member,
-    HazelcastClientError::{ClusterNonOperational, NodeNonOperational},
+    config::ClientConfig,
+    messaging::{
+        error::Exception,
+        membership::{MembershipEvent, MembershipEventKind},
+        Address, ListenerInfo, ListenerKind, MemberInfo, Registration, Request, Response,
+    },
+    remote::{
+        member::{Identity, Member},
+        partition,
+        transport::Transport,
+        Backoff, Heartbeat, Message, Retries, RoutingMode, Timeouts,
+    },
+    runtime::{DefaultRuntime, Runtime},
+    HazelcastClientError::{
+        ClusterNonOperational, CommunicationFailure, NodeNonOperational, OperationTimeout, ServerFailure,
+    },
     Result,
 };
 
 pub(crate) struct Cluster {
     members: Arc<Members>,
-    _pinger: Pinger,
+    partitions: Arc<RwLock<Option<PartitionTable>>>,
+    routing_mode: RoutingMode,
+    timeouts: Timeouts,
+    retries: Retries,
+    pinger: Mutex<Option<Pinger>>,
+    reconnector: Mutex<Option<Reconnector>>,
+    reaper: Mutex<Option<Reaper>>,
+    membership_listener: Mutex<Option<MembershipListener>>,
+    listeners: Mutex<HashMap<String, Listener>>,
+    lock_owners: AtomicI64,
+    lock_references: AtomicI64,
+    closed: AtomicBool,
 }
 
 impl Cluster {
-    pub(crate) async fn init<E>(endpoints: E, username: &str, password: &str) -> Result<Self>
-    where
-        E: IntoIterator<Item = SocketAddr>,
-    {
-        let members = Arc::new(Members::from(endpoints, username, password).await?);
-        let pinger = Pinger::ping(members.clone());
-        // TODO: reconnector...
+    pub(crate) async fn init(config: &ClientConfig) -> Result<Self> {
+        let members = Arc::new(Members::from(config).await?);
+        let partitions = Arc::new(RwLock::new(None));
+        let pinger = Pinger::ping(members.clone(), config.heartbeat);
+        let reconnector = Reconnector::reconnect(members.clone(), RECONNECT_INTERVAL);
+        let reaper = config
+            .idle_timeout
+            .map(|idle_timeout| Reaper::reap(members.clone(), partitions.clone(), config.routing_mode, idle_timeout));
+        let membership_listener = MembershipListener::listen(members.clone()).await;
 
         Ok(Cluster {
             members,
-            _pinger: pinger,
+            partitions,
+            routing_mode: config.routing_mode,
+            timeouts: config.timeouts,
+            retries: config.retries,
+            pinger: Mutex::new(Some(pinger)),
+            reconnector: Mutex::new(Some(reconnector)),
+            reaper: Mutex::new(reaper),
+            membership_listener: Mutex::new(membership_listener),
+            listeners: Mutex::new(HashMap::new()),
+            lock_owners: AtomicI64::new(1),
+            lock_references: AtomicI64::new(0),
+            closed: AtomicBool::new(false),
         })
     }
 
+    pub(crate) async fn shutdown(&self) {
+        if self.close() {
+            self.members.close().await;
+        }
+    }
+
+    fn close(&self) -> bool {
+        if self.closed.swap(true, Ordering::SeqCst) {
+            return false;
+        }
+        self.pinger.lock().expect("poisoned lock!").take();
+        self.reconnector.lock().expect("poisoned lock!").take();
+        self.reaper.lock().expect("poisoned lock!").take();
+        self.membership_listener.lock().expect("poisoned lock!").take();
+        self.listeners.lock().expect("poisoned lock!").clear();
+        true
+    }
+
     pub(crate) async fn dispatch<RQ, RS>(&self, request: RQ) -> Result<RS>
     where
         RQ: Request,
         RS: Response,
     {
-        match self.members.get().await {
-            Some(member) => member.send(request).await,
-            None => Err(ClusterNonOperational),
-        }
+        let partition_id = request.partition_id();
+        self.invoke(request, Target::Partition(partition_id), self.timeouts.dispatch)
+            .await
     }
 
     pub(crate) async fn forward<RQ, RS>(&self, request: RQ, address: &Address) -> Result<RS>
@@ -61,10 +119,132 @@ impl Cluster {
         RQ: Request,
         RS: Response,
     {
-        match self.members.get_by(address).await {
-            Some(member) => member.send(request).await,
-            None => Err(NodeNonOperational),
+        self.invoke(request, Target::Address(address), self.timeouts.forward)
+            .await
+    }
+
+    // the target is resolved again for every retry, after dropping the partition owners that may have moved meanwhile
+    async fn invoke<RQ, RS>(&self, request: RQ, target: Target<'_>, timeout: Duration) -> Result<RS>
+    where
+        RQ: Request,
+        RS: Response,
+    {
+        let mut failed = 0;
+        loop {
+            let member = self.resolve(&target).await?;
+            match self.send(&member, &request, timeout).await {
+                Err(ServerFailure(e)) if failed < self.retries.count && is_retryable(e.as_ref()) => {
+                    failed += 1;
+                    let delay = self.retries.backoff.delay(failed);
+                    info!("Invocation on {} failed, retrying in {:?} - {}", member, delay, e);
+                    if let Target::Partition(_) = target {
+                        *self.partitions.write().await = None;
+                    }
+                    DefaultRuntime::delay(delay).await;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    async fn resolve(&self, target: &Target<'_>) -> Result<Arc<Member>> {
+        match *target {
+            Target::Partition(_) if self.routing_mode == RoutingMode::Unisocket => {
+                self.members.get().await.ok_or(ClusterNonOperational)
+            }
+            Target::Partition(partition_id) => {
+                let owner = match self.partition_owner(partition_id).await {
+                    Some(owner) => self.members.get_by(&owner).await,
+                    None => None,
+                };
+                match owner {
+                    Some(owner) => Ok(owner),
+                    None => self.members.get_for(partition_id).await.ok_or(ClusterNonOperational),
+                }
+            }
+            Target::Address(address) => self.members.get_by(address).await.ok_or(NodeNonOperational),
+        }
+    }
+
+    async fn send<RQ, RS>(&self, member: &Member, request: &RQ, timeout: Duration) -> Result<RS>
+    where
+        RQ: Request,
+        RS: Response,
+    {
+        if !member.is_connected() {
+            self.members.disable(member).await;
+            return Err(CommunicationFailure(
+                format!("connection to {} is closed", member).into(),
+            ));
         }
+        member.touch();
+        let result = timed(timeout, member.send(request)).await;
+        if let Err(CommunicationFailure(e)) = &result {
+            error!("Communication with {} failed - {}", member, e);
+            self.members.disable(member).await;
+        }
+        result
+    }
+
+    // the listener lives on the connection it was registered with, which is also where it is deregistered
+    pub(crate) async fn listen<RQ, RS, DRQ, DRS, F>(
+        &self,
+        kind: ListenerKind,
+        request: RQ,
+        deregistration: F,
+    ) -> Result<(String, mpsc::UnboundedReceiver<Message>)>
+    where
+        RQ: Request,
+        RS: Response + Registration,
+        DRQ: Request + Send + Sync + 'static,
+        DRS: Response,
+        F: Fn(String) -> DRQ + Send + Sync + 'static,
+    {
+        let member = self.members.get().await.ok_or(ClusterNonOperational)?;
+        let (response, correlation_id, events) =
+            timed(self.timeouts.dispatch, member.subscribe::<RQ, RS>(request)).await?;
+
+        let registration_id = response.registration_id().to_string();
+        let deregister: Deregistration = Box::new(move |member, registration_id| {
+            let request = deregistration(registration_id);
+            Box::pin(async move {
+                let _: DRS = member.send(&request).await?;
+                Ok(())
+            })
+        });
+        self.listeners.lock().expect("poisoned lock!").insert(
+            registration_id.clone(),
+            Listener {
+                info: ListenerInfo::new(&registration_id, kind),
+                member,
+                correlation_id,
+                deregister,
+            },
+        );
+        Ok((registration_id, events))
+    }
+
+    pub(crate) fn listeners(&self) -> Vec<ListenerInfo> {
+        let mut listeners: Vec<ListenerInfo> = self
+            .listeners
+            .lock()
+            .expect("poisoned lock!")
+            .values()
+            .map(|listener| listener.info.clone())
+            .collect();
+        listeners.sort_by(|a, b| a.registration_id().cmp(b.registration_id()));
+        listeners
+    }
+
+    pub(crate) async fn remove_listener(&self, registration_id: &str) -> Result<bool> {
+        let listener = match self.listeners.lock().expect("poisoned lock!").remove(registration_id) {
+            Some(listener) => listener,
+            None => return Ok(false),
+        };
+        listener.member.unsubscribe(listener.correlation_id);
+        let deregistration = (listener.deregister)(listener.member.clone(), registration_id.to_string());
+        timed(self.timeouts.dispatch, deregistration).await?;
+        Ok(true)
     }
 
     pub(crate) async fn address(&self, address: Option<Address>) -> Result<Address> {
@@ -80,6 +260,74 @@ impl Cluster {
         }
     }
 
+    pub(crate) async fn partition_id(&self, partition_hash: i32) -> Result<i32> {
+        Ok(partition::partition_id(partition_hash, self.partition_count().await?))
+    }
+
+    pub(crate) async fn partition_owner(&self, partition_id: i32) -> Option<Address> {
+        match &*self.partitions.read().await {
+            Some(partitions) => partitions.owners.get(&partition_id).cloned(),
+            None => None,
+        }
+    }
+
+    // the owners are fetched again once the members change, the partition count never changes though
+    pub(crate) async fn partition_count(&self) -> Result<i32> {
+        use crate::messaging::partition::{GetPartitionsRequest, GetPartitionsResponse};
+        use std::convert::TryInto;
+
+        let version = self.members.version().await;
+        if let Some(partitions) = &*self.partitions.read().await {
+            if partitions.version == version {
+                return Ok(partitions.count);
+            }
+        }
+
+        for _ in 0..PARTITION_COUNT_ATTEMPTS {
+            let response: GetPartitionsResponse = self.dispatch(GetPartitionsRequest::new()).await?;
+            let owners: HashMap<i32, Address> = response
+                .partitions()
+                .iter()
+                .flat_map(|(owner, partition_ids)| partition_ids.iter().map(move |id| (*id, owner.clone())))
+                .collect();
+            let count = owners.len().try_into().expect("unable to convert!");
+            if count > 0 {
+                *self.partitions.write().await = Some(PartitionTable { count, owners, version });
+                return Ok(count);
+            }
+
+            info!(
+                "Partitions are not assigned yet, retrying in {:?}.",
+                PARTITION_COUNT_DELAY
+            );
+            DefaultRuntime::delay(PARTITION_COUNT_DELAY).await;
+        }
+        Err(ClusterNonOperational)
+    }
+
+    pub(crate) fn membership_events(&self) -> mpsc::UnboundedReceiver<MembershipEvent> {
+        self.members.subscribe()
+    }
+
+    // reported by the first member the client authenticated with
+    pub(crate) fn server_version(&self) -> Option<&str> {
+        self.members.server_version.as_deref()
+    }
+
+    pub(crate) async fn membership_version(&self) -> u64 {
+        self.members.version().await
+    }
+
+    // members tell lock holders apart by thread id, so each lock handle stands in for a thread of its own
+    pub(crate) fn next_lock_owner_id(&self) -> i64 {
+        self.lock_owners.fetch_add(1, Ordering::SeqCst)
+    }
+
+    // reference ids let members recognize retried lock invocations, so they have to be unique across the client
+    pub(crate) fn next_lock_reference_id(&self) -> i64 {
+        self.lock_references.fetch_add(1, Ordering::SeqCst)
+    }
+
     pub(crate) async fn to_string(&self) -> String {
         let members = self.members.get_all().await;
 
@@ -93,25 +341,89 @@ impl Cluster {
     }
 }
 
-const PING_INTERVAL: Duration = Duration::from_secs(300);
+impl Drop for Cluster {
+    fn drop(&mut self) {
+        self.close();
+    }
+}
+
+enum Target<'a> {
+    Partition(i32),
+    Address(&'a Address),
+}
+
+fn is_retryable(error: &(dyn std::error::Error + Send + Sync + 'static)) -> bool {
+    error.downcast_ref::<Exception>().is_some_and(Exception::is_retryable)
+}
+
+async fn timed<T, F>(timeout: Duration, future: F) -> Result<T>
+where
+    F: Future<Output = Result<T>>,
+{
+    futures::pin_mut!(future);
+    match future::select(future, DefaultRuntime::delay(timeout)).await {
+        Either::Left((result, _)) => result,
+        Either::Right(_) => Err(OperationTimeout(timeout)),
+    }
+}
+
+type Deregistration = Box<dyn Fn(Arc<Member>, String) -> BoxFuture<'static, Result<()>> + Send + Sync>;
+
+struct Listener {
+    info: ListenerInfo,
+    member: Arc<Member>,
+    correlation_id: u64,
+    deregister: Deregistration,
+}
+
+struct PartitionTable {
+    count: i32,
+    owners: HashMap<i32, Address>,
+    version: u64,
+}
+
+const PARTITION_COUNT_ATTEMPTS: usize = 50;
+const PARTITION_COUNT_DELAY: Duration = Duration::from_millis(100);
+
+const RECONNECT_INTERVAL: Duration = Duration::from_secs(1);
 
 struct Pinger {
     _handle: oneshot::Sender<()>,
 }
 
 impl Pinger {
-    fn ping(members: Arc<Members>) -> Self {
+    // a single timer drives the heartbeats of all members, staggered over half of the interval to avoid bursts
+    fn ping(members: Arc<Members>, heartbeat: Heartbeat) -> Self {
         use crate::messaging::ping::{PingRequest, PingResponse};
+        use std::convert::TryInto;
 
+        let Heartbeat { interval, timeout } = heartbeat;
         let (handle, receiver) = oneshot::channel();
-        tokio::spawn(async move {
-            let mut ticks = Ticks::new(PING_INTERVAL, receiver);
-            while let Some(_) = ticks.next().await {
-                for member in members.get_all().await {
-                    if let Err(_) = member.send::<PingRequest, PingResponse>(PingRequest::new()).await {
-                        error!("Pinging {} failed.", member);
-                        members.disable(&*member).await
+        DefaultRuntime::spawn(async move {
+            let mut ticks = Stoppable::new(DefaultRuntime::interval(interval), receiver);
+            while ticks.next().await.is_some() {
+                // members closed in the meantime are skipped rather than kept alive until their turn, and every ping is
+                // awaited on its own so that a member stalling does not hold back the heartbeats of the others
+                let current: Vec<Weak<Member>> = members.get_all().await.iter().map(Arc::downgrade).collect();
+                let count: u32 = current.len().try_into().unwrap_or(u32::MAX);
+                for (i, member) in current.into_iter().enumerate() {
+                    if i > 0 {
+                        DefaultRuntime::delay(interval / count.saturating_mul(2)).await;
                     }
+                    let member = match member.upgrade() {
+                        Some(member) => member,
+                        None => continue,
+                    };
+                    let members = members.clone();
+                    DefaultRuntime::spawn(async move {
+                        if timed(timeout, member.send::<PingRequest, PingResponse>(&PingRequest::new()))
+                            .await
+                            .is_err()
+                        {
+                            error!("Pinging {} failed.", member);
+                            members.disable(&member).await
+                        }
+                    });
                 }
             }
         });
@@ -120,35 +432,296 @@ impl Pinger {
     }
 }
 
+struct Reconnector {
+    _handle: oneshot::Sender<()>,
+}
+
+impl Reconnector {
+    fn reconnect(members: Arc<Members>, interval: Duration) -> Self {
+        let (handle, receiver) = oneshot::channel();
+        DefaultRuntime::spawn(async move {
+            let mut ticks = Stoppable::new(DefaultRuntime::interval(interval), receiver);
+            while ticks.next().await.is_some() {
+                members.reconnect().await;
+            }
+        });
+
+        Reconnector { _handle: handle }
+    }
+}
+
+struct Reaper {
+    _handle: oneshot::Sender<()>,
+}
+
+impl Reaper {
+    fn reap(
+        members: Arc<Members>,
+        partitions: Arc<RwLock<Option<PartitionTable>>>,
+        routing_mode: RoutingMode,
+        idle_timeout: Duration,
+    ) -> Self {
+        let (handle, receiver) = oneshot::channel();
+        DefaultRuntime::spawn(async move {
+            let mut ticks = Stoppable::new(DefaultRuntime::interval(idle_timeout / 2), receiver);
+            while ticks.next().await.is_some() {
+                // smart routing sends invocations straight to the partition owners, so their connections stay open
+                let owners = match (&*partitions.read().await, routing_mode) {
+                    (Some(partitions), RoutingMode::Smart) => partitions.owners.values().cloned().collect(),
+                    _ => HashSet::new(),
+                };
+                members.reap(idle_timeout, &owners).await;
+            }
+        });
+
+        Reaper { _handle: handle }
+    }
+}
+
+struct MembershipListener {
+    _handle: oneshot::Sender<()>,
+}
+
+impl MembershipListener {
+    async fn listen(members: Arc<Members>) -> Option<Self> {
+        use crate::messaging::membership::{AddMembershipListenerRequest, AddMembershipListenerResponse};
+
+        let member = members.get().await?;
+        let events = match member
+            .subscribe::<_, AddMembershipListenerResponse>(AddMembershipListenerRequest::new(false))
+            .await
+        {
+            Ok((_, _, events)) => events,
+            Err(e) => {
+                error!("Failed to listen to membership changes on {} - {}", member, e);
+                return None;
+            }
+        };
+
+        let (handle, receiver) = oneshot::channel();
+        DefaultRuntime::spawn(async move {
+            let mut events = Stoppable::new(Box::pin(events), receiver);
+            while let Some(event) = events.next().await {
+                members.observe(event).await;
+            }
+        });
+
+        Some(MembershipListener { _handle: handle })
+    }
+}
+
+const ZONE_ATTRIBUTE: &str = "hazelcast.partition.group.zone";
+
 struct Members {
     registry: RwLock<Registry<Address, Member>>,
+    infos: RwLock<HashMap<Address, MemberInfo>>,
+    subscribers: Mutex<Vec<mpsc::UnboundedSender<MembershipEvent>>>,
+    routing_mode: RoutingMode,
+    username: String,
+    password: String,
+    identity: Option<Identity>,
+    server_version: Option<String>,
+    local_zone: Option<String>,
+    backoff: Backoff,
+    attempts: Mutex<HashMap<Address, (u32, Instant)>>,
+    max_concurrent_invocations: Option<usize>,
+    max_frame_size: usize,
+    transport: Transport,
+    connect_timeout: Duration,
 }
 
 impl Members {
-    async fn from<'a, E>(endpoints: E, username: &str, password: &str) -> Result<Self>
-    where
-        E: IntoIterator<Item = SocketAddr>,
-    {
+    async fn from(config: &ClientConfig) -> Result<Self> {
+        let transport = Transport::new(config).map_err(|e| CommunicationFailure(Box::new(e)))?;
         let mut registry = Registry::new();
-        for endpoint in endpoints.into_iter().collect::<HashSet<SocketAddr>>() {
+        let mut identity = None;
+        let mut server_version = None;
+        let mut addresses = HashSet::new();
+        for endpoint in config.addresses.iter().collect::<HashSet<&SocketAddr>>() {
             info!("Trying to connect to {} as owner member.", endpoint);
-            match Member::connect(&endpoint, username, password).await {
-                Ok(member) => registry.enable(member.address().clone(), member),
+            let connection = Member::connect(
+                endpoint,
+                &transport,
+                config.group_name(),
+                &config.password,
+                identity.as_ref(),
+                config.max_concurrent_invocations,
+                config.max_frame_size,
+            );
+            match timed(config.connect_timeout, connection).await {
+                // several addresses may lead to the same member, which needs a single connection only
+                Ok(member) if !addresses.insert(member.address().clone()) => {
+                    info!("Already connected to {}, dropping connection to {}.", member, endpoint)
+                }
+                Ok(member) => {
+                    identity.get_or_insert_with(|| member.identity());
+                    if server_version.is_none() {
+                        server_version = member.server_version().map(str::to_string);
+                    }
+                    registry.enable(member.address().clone(), member);
+                    if config.routing_mode == RoutingMode::Unisocket {
+                        break;
+                    }
+                }
                 Err(e) => error!("Failed to connect to {} - {}", endpoint, e),
             }
         }
 
         Ok(Members {
             registry: RwLock::new(registry),
+            infos: RwLock::new(HashMap::new()),
+            subscribers: Mutex::new(Vec::new()),
+            routing_mode: config.routing_mode,
+            username: config.group_name().to_string(),
+            password: config.password.clone(),
+            identity,
+            server_version,
+            local_zone: config.local_zone.clone(),
+            backoff: config.reconnect_backoff,
+            attempts: Mutex::new(HashMap::new()),
+            max_concurrent_invocations: config.max_concurrent_invocations,
+            max_frame_size: config.max_frame_size,
+            transport,
+            connect_timeout: config.connect_timeout,
         })
     }
 
-    /*async fn enable(&self, address: Address, member: Member) {
-        self.registry.write().await.enable(address, member)
-    }*/
+    async fn observe(&self, event: Message) {
+        use crate::{
+            messaging::membership::{MemberEvent, MemberListEvent, MEMBER_ADDED, MEMBER_REMOVED},
+            TryFrom,
+        };
+
+        match event.r#type() {
+            0xC9 => match TryFrom::<MemberListEvent>::try_from(event) {
+                Ok(event) => {
+                    let members = event.members();
+                    self.discover(members.iter().map(|member| member.address().clone()))
+                        .await;
+                    let infos = members
+                        .into_iter()
+                        .map(|member| (member.address().clone(), MemberInfo::from(member)));
+                    *self.infos.write().await = infos.collect();
+                }
+                Err(e) => error!("Failed to read member list - {}", e),
+            },
+            0xC8 => match TryFrom::<MemberEvent>::try_from(event) {
+                Ok(event) => {
+                    let event_type = event.event_type();
+                    let member = event.member();
+                    let address = member.address().clone();
+                    let info = MemberInfo::from(member);
+                    match event_type {
+                        MEMBER_ADDED => {
+                            self.discover(std::iter::once(address.clone())).await;
+                            self.infos.write().await.insert(address, info.clone());
+                            self.publish(MembershipEvent::new(info, MembershipEventKind::Added));
+                        }
+                        MEMBER_REMOVED => {
+                            self.registry.write().await.forget(&address);
+                            self.infos.write().await.remove(&address);
+                            self.publish(MembershipEvent::new(info, MembershipEventKind::Removed));
+                        }
+                        _ => {}
+                    }
+                }
+                Err(e) => error!("Failed to read member event - {}", e),
+            },
+            _ => {}
+        }
+    }
+
+    // smart clients connect to members they learn about, which is left to the reconnector
+    async fn discover(&self, addresses: impl Iterator<Item = Address>) {
+        if self.routing_mode == RoutingMode::Smart {
+            let mut registry = self.registry.write().await;
+            for address in addresses {
+                registry.discover(address);
+            }
+        }
+    }
+
+    fn subscribe(&self) -> mpsc::UnboundedReceiver<MembershipEvent> {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        self.subscribers.lock().expect("poisoned lock!").push(sender);
+        receiver
+    }
+
+    fn publish(&self, event: MembershipEvent) {
+        self.subscribers
+            .lock()
+            .expect("poisoned lock!")
+            .retain(|subscriber| subscriber.send(event.clone()).is_ok());
+    }
+
+    // members failing to reconnect are tried again after an exponentially growing delay
+    async fn reconnect(&self) {
+        let disabled = self.registry.read().await.disabled();
+        for address in disabled {
+            if let Some((_, next_attempt)) = self.attempts.lock().expect("poisoned lock!").get(&address) {
+                if Instant::now() < *next_attempt {
+                    continue;
+                }
+            }
+            let endpoint = match address.resolve().await {
+                Ok(endpoint) => endpoint,
+                Err(e) => {
+                    error!("Failed to resolve {} - {}", address, e);
+                    continue;
+                }
+            };
+            info!("Trying to reconnect to {}.", address);
+            let connection = Member::connect(
+                &endpoint,
+                &self.transport,
+                &self.username,
+                &self.password,
+                self.identity.as_ref(),
+                self.max_concurrent_invocations,
+                self.max_frame_size,
+            );
+            match timed(self.connect_timeout, connection).await {
+                Ok(member) => {
+                    info!("Reconnected to {}.", member);
+                    self.attempts.lock().expect("poisoned lock!").remove(&address);
+                    self.registry.write().await.enable(address, member);
+                }
+                Err(e) => {
+                    let mut attempts = self.attempts.lock().expect("poisoned lock!");
+                    let failed = attempts.get(&address).map_or(1, |(failed, _)| failed + 1);
+                    let delay = self.backoff.delay(failed);
+                    error!("Failed to reconnect to {}, retrying in {:?} - {}", address, delay, e);
+                    attempts.insert(address, (failed, Instant::now() + delay));
+                }
+            }
+        }
+    }
 
+    // members in the local zone are preferred, falling back to any member when none of them is there
     async fn get(&self) -> Option<Arc<Member>> {
-        self.registry.read().await.get()
+        let registry = self.registry.read().await;
+        match &self.local_zone {
+            Some(zone) => {
+                let infos = self.infos.read().await;
+                registry
+                    .get_matching(|member| {
+                        infos
+                            .get(member.address())
+                            .and_then(|info| info.attributes().get(ZONE_ATTRIBUTE))
+                            .is_some_and(|member_zone| member_zone == zone)
+                    })
+                    .or_else(|| registry.get())
+            }
+            None => registry.get(),
+        }
+    }
+
+    async fn get_for(&self, partition_id: i32) -> Option<Arc<Member>> {
+        if partition_id < 0 {
+            self.get().await
+        } else {
+            self.registry.read().await.get_for(partition_id)
+        }
     }
 
     async fn get_by(&self, address: &Address) -> Option<Arc<Member>> {
@@ -162,13 +735,51 @@ impl Members {
     async fn disable(&self, member: &Member) {
         self.registry.write().await.disable(member)
     }
+
+    // connections carrying listeners or leading to partition owners are kept, as is the most recently used one so that
+    // the cluster stays reachable, while reaped members owning partitions again are handed back to the reconnector
+    async fn reap(&self, idle_timeout: Duration, owners: &HashSet<Address>) {
+        let mut registry = self.registry.write().await;
+        for owner in owners {
+            registry.wake(owner);
+        }
+        let owners: Vec<Arc<Member>> = owners.iter().filter_map(|owner| registry.get_by(owner)).collect();
+        let mut idle: Vec<Arc<Member>> = registry
+            .get_all()
+            .into_iter()
+            .filter(|member| !member.has_listeners() && !owners.iter().any(|owner| Arc::ptr_eq(owner, member)))
+            .collect();
+        idle.sort_by_key(|member| member.last_used());
+        for member in idle {
+            if registry.get_all().len() <= 1 || member.last_used().elapsed() < idle_timeout {
+                break;
+            }
+            info!("Closing idle connection to {}.", member);
+            registry.reap(&member);
+        }
+    }
+
+    async fn version(&self) -> u64 {
+        self.registry.read().await.version()
+    }
+
+    // invocations still in flight hold on to their member, so its connection is closed explicitly
+    async fn close(&self) {
+        let mut registry = self.registry.write().await;
+        for member in registry.get_all() {
+            member.close();
+        }
+        registry.clear()
+    }
 }
 
 struct Registry<K, V> {
     enabled: Vec<Arc<V>>,
     enabled_by_key: HashMap<K, Arc<V>>,
     disabled: HashSet<K>,
+    idle: HashSet<K>,
     sequencer: AtomicUsize,
+    version: u64,
 }
 
 impl<K, V> Registry<K, V>
@@ -181,15 +792,35 @@ where
             enabled: Vec::new(),
             enabled_by_key: HashMap::new(),
             disabled: HashSet::new(),
+            idle: HashSet::new(),
             sequencer: AtomicUsize::new(0),
+            version: 0,
         }
     }
 
     fn enable(&mut self, key: K, value: V) {
         self.disabled.remove(&key);
+        self.idle.remove(&key);
         let value = Arc::new(value);
         self.enabled.push(value.clone());
         self.enabled_by_key.insert(key, value);
+        self.version += 1;
+    }
+
+    fn discover(&mut self, key: K) {
+        if !self.enabled_by_key.contains_key(&key) && !self.idle.contains(&key) {
+            self.disabled.insert(key);
+        }
+    }
+
+    // a value that left for good is neither used nor reconnected anymore
+    fn forget(&mut self, key: &K) {
+        self.disabled.remove(key);
+        self.idle.remove(key);
+        if let Some(value) = self.enabled_by_key.remove(key) {
+            self.enabled.retain(|v| !Arc::ptr_eq(v, &value));
+            self.version += 1;
+        }
     }
 
     fn get(&self) -> Option<Arc<V>> {
@@ -201,6 +832,30 @@ where
         }
     }
 
+    fn get_matching<P>(&self, predicate: P) -> Option<Arc<V>>
+    where
+        P: Fn(&V) -> bool,
+    {
+        let matching: Vec<&Arc<V>> = self.enabled.iter().filter(|value| predicate(value)).collect();
+        if matching.is_empty() {
+            None
+        } else {
+            let sequence = self.sequencer.fetch_add(1, Ordering::SeqCst);
+            matching.get(sequence % matching.len()).map(|value| Arc::clone(value))
+        }
+    }
+
+    fn get_for(&self, partition_id: i32) -> Option<Arc<V>> {
+        use std::convert::TryFrom;
+
+        match usize::try_from(partition_id) {
+            Ok(partition_id) if !self.enabled.is_empty() => {
+                self.enabled.get(partition_id % self.enabled.len()).map(Arc::clone)
+            }
+            _ => self.get(),
+        }
+    }
+
     fn get_by(&self, key: &K) -> Option<Arc<V>> {
         self.enabled_by_key.get(key).map(Arc::clone)
     }
@@ -209,95 +864,1434 @@ where
         self.enabled.iter().map(Arc::clone).collect()
     }
 
+    // idle values are only left alone while another value is enabled, so that the last one going away brings them back
     fn disable(&mut self, value: &V) {
+        if let Some(key) = self.take(value) {
+            self.disabled.insert(key);
+        }
+        if self.enabled.is_empty() {
+            self.disabled.extend(self.idle.drain());
+        }
+    }
+
+    // an idle value stays known but is not reconnected until woken up again
+    fn reap(&mut self, value: &V) {
+        if let Some(key) = self.take(value) {
+            self.idle.insert(key);
+        }
+    }
+
+    fn wake(&mut self, key: &K) {
+        if let Some(key) = self.idle.take(key) {
+            self.disabled.insert(key);
+        }
+    }
+
+    fn take(&mut self, value: &V) -> Option<K> {
         self.enabled
             .iter()
             .position(|v| **v == *value)
             .map(|i| self.enabled.remove(i));
-        if let Some(key) = self
+        let key = self
             .enabled_by_key
             .iter()
-            .filter(|(_, v)| ***v == *value)
-            .nth(0)
-            .map(|(k, _)| k.clone())
-        {
-            self.enabled_by_key.remove(&key);
-            self.disabled.insert(key);
-        }
+            .find(|(_, v)| ***v == *value)
+            .map(|(k, _)| k.clone())?;
+        self.enabled_by_key.remove(&key);
+        self.version += 1;
+        Some(key)
+    }
+
+    fn version(&self) -> u64 {
+        self.version
+    }
+
+    fn disabled(&self) -> Vec<K> {
+        self.disabled.iter().cloned().collect()
+    }
+
+    fn clear(&mut self) {
+        self.enabled.clear();
+        self.enabled_by_key.clear();
+        self.disabled.clear();
+        self.idle.clear();
+        self.version += 1;
     }
 }
 
-struct Ticks {
-    interval: Interval,
+struct Stoppable<T> {
+    stream: BoxStream<'static, T>,
     receiver: oneshot::Receiver<()>,
 }
 
-impl Ticks {
-    fn new(interval: Duration, receiver: oneshot::Receiver<()>) -> Self {
-        Ticks {
-            interval: tokio::time::interval(interval),
-            receiver,
-        }
+impl<T> Stoppable<T> {
+    fn new(stream: BoxStream<'static, T>, receiver: oneshot::Receiver<()>) -> Self {
+        Stoppable { stream, receiver }
     }
 }
 
-impl Stream for Ticks {
-    type Item = ();
+impl<T> Stream for Stoppable<T> {
+    type Item = T;
 
     fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        use std::future::Future;
-
         match Pin::new(&mut self.receiver).poll(cx) {
             Poll::Pending => {}
             _ => return Poll::Ready(None),
         }
 
-        Poll::Ready(match futures::ready!(Pin::new(&mut self.interval).poll_next(cx)) {
-            None => None,
-            _ => Some(()),
-        })
+        Pin::new(&mut self.stream).poll_next(cx)
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::{remote::mock::MockMember, serialization::Data};
+
     use super::*;
 
-    #[test]
-    fn should_get_none_for_empty_registry() {
-        let registry: Registry<&str, &str> = Registry::new();
+    fn config(addresses: &[SocketAddr], routing_mode: RoutingMode, timeouts: Timeouts) -> ClientConfig {
+        let mut config = ClientConfig::builder().addresses(addresses).credentials("", "").build();
+        config.routing_mode = routing_mode;
+        config.timeouts = timeouts;
+        config
+    }
 
-        assert!(registry.get().is_none());
-        assert!(registry.get_by(&"some-key").is_none());
-        assert!(registry.get_all().is_empty());
+    #[tokio::test]
+    async fn should_wait_for_partitions_to_be_assigned() {
+        use crate::{codec::Writer, messaging::Address};
+        use bytes::{Buf, BytesMut};
+
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = requests.clone();
+        let member = MockMember::start_with("member", move |message| match message.r#type() {
+            0x8 => {
+                let writeable = &mut BytesMut::new();
+                if counter.fetch_add(1, Ordering::SeqCst) == 0 {
+                    0u32.write_to(writeable);
+                } else {
+                    1u32.write_to(writeable);
+                    Address::from(&"127.0.0.1:5701".parse().unwrap()).write_to(writeable);
+                    (&(0..271).collect::<Vec<i32>>()[..]).write_to(writeable);
+                }
+                1i32.write_to(writeable);
+                Some((0x6C, writeable.to_bytes()))
+            }
+            _ => None,
+        })
+        .await;
+
+        let cluster = Cluster::init(&config(&[member.address()], RoutingMode::Smart, Timeouts::default()))
+            .await
+            .unwrap();
+
+        assert!((0..271).contains(
+            &cluster
+                .partition_id(Data::from(&[0u8, 0, 0, 0, 0, 0, 0, 1, 2][..]).partition_hash())
+                .await
+                .unwrap()
+        ));
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+        assert_eq!(cluster.partition_count().await.unwrap(), 271);
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
     }
 
-    #[test]
-    fn should_get_some_after_enable() {
-        let mut registry = Registry::new();
+    #[tokio::test]
+    async fn should_dispatch_request_to_owner_of_key_partition() {
+        use crate::{
+            codec::Writer,
+            messaging::{
+                map::{MapGetRequest, MapGetResponse},
+                Address,
+            },
+        };
+        use bytes::{Buf, BytesMut};
+        use std::sync::Mutex;
 
-        let key = "some-key";
-        let value = "some=value";
+        let owner_address = Arc::new(Mutex::new(None));
+        let gets = Arc::new(Mutex::new(Vec::new()));
+        let mut members = Vec::new();
+        for id in &["first", "second"] {
+            let (owner_address, gets, name) = (owner_address.clone(), gets.clone(), id.to_string());
+            let member = MockMember::start_with(id, move |message| match message.r#type() {
+                0x8 => {
+                    let owner: SocketAddr = owner_address.lock().unwrap().expect("missing owner!");
+                    let writeable = &mut BytesMut::new();
+                    1u32.write_to(writeable);
+                    Address::from(&owner).write_to(writeable);
+                    (&(0..271).collect::<Vec<i32>>()[..]).write_to(writeable);
+                    1i32.write_to(writeable);
+                    Some((0x6C, writeable.to_bytes()))
+                }
+                0x102 => {
+                    gets.lock().unwrap().push(name.clone());
+                    let writeable = &mut BytesMut::new();
+                    Option::<&[u8]>::None.write_to(writeable);
+                    Some((0x69, writeable.to_bytes()))
+                }
+                _ => None,
+            })
+            .await;
+            members.push(member);
+        }
+        *owner_address.lock().unwrap() = Some(members[1].address());
+        let cluster = Cluster::init(&config(
+            &[members[0].address(), members[1].address()],
+            RoutingMode::Smart,
+            Timeouts::default(),
+        ))
+        .await
+        .unwrap();
 
-        registry.enable(key, value);
+        for key in &[[0u8, 0, 0, 0, 0, 0, 0, 1, 1], [0, 0, 0, 0, 0, 0, 0, 1, 2]] {
+            let partition_id = cluster
+                .partition_id(Data::from(&key[..]).partition_hash())
+                .await
+                .unwrap();
+            assert_eq!(
+                cluster.partition_owner(partition_id).await,
+                Some(Address::from(&members[1].address()))
+            );
+            let _: MapGetResponse = cluster
+                .dispatch(MapGetRequest::new("map-name", key, 1, partition_id))
+                .await
+                .unwrap();
+        }
 
-        assert_eq!(*registry.get().unwrap(), value);
-        assert_eq!(*registry.get_by(&key).unwrap(), value);
-        assert_eq!(*registry.get_all()[0], value);
+        assert_eq!(*gets.lock().unwrap(), vec!["second", "second"]);
     }
 
-    #[test]
-    fn should_get_none_after_disable() {
-        let mut registry = Registry::new();
+    #[tokio::test]
+    async fn should_give_up_on_member_stalling_connection() {
+        use tokio::net::TcpListener;
 
-        let key = "some-key";
-        let value = "some=value";
+        let mut stalling = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let stalling_address = stalling.local_addr().unwrap();
+        tokio::spawn(async move {
+            let mut connections = Vec::new();
+            while let Ok((stream, _)) = stalling.accept().await {
+                connections.push(stream);
+            }
+        });
+        let member = MockMember::start("member").await;
+        let mut config = config(
+            &[stalling_address, member.address()],
+            RoutingMode::Smart,
+            Timeouts::default(),
+        );
+        config.connect_timeout = Duration::from_millis(100);
 
-        registry.enable(key, value);
-        registry.disable(&value);
+        let cluster = tokio::time::timeout(Duration::from_secs(5), Cluster::init(&config))
+            .await
+            .unwrap()
+            .unwrap();
 
-        assert!(registry.get().is_none());
+        assert_eq!(cluster.members.registry.read().await.get_all().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn should_expose_server_version_and_partition_count() {
+        use crate::remote::mock::partitions_payload;
+
+        let member = MockMember::start_with("member", |message| match message.r#type() {
+            0x8 => Some((0x6C, partitions_payload(271))),
+            _ => None,
+        })
+        .await;
+
+        let cluster = Cluster::init(&config(&[member.address()], RoutingMode::Smart, Timeouts::default()))
+            .await
+            .unwrap();
+
+        assert_eq!(cluster.server_version(), Some("3.12.12"));
+        assert_eq!(cluster.partition_count().await.unwrap(), 271);
+    }
+
+    #[tokio::test]
+    async fn should_honor_dispatch_timeout_rather_than_forward_timeout() {
+        use crate::messaging::ping::{PingRequest, PingResponse};
+
+        let member = MockMember::start_with_latency("member", Duration::from_millis(100), |_| None).await;
+        let timeouts = Timeouts {
+            dispatch: Duration::from_secs(5),
+            forward: Duration::from_millis(10),
+        };
+
+        let cluster = Cluster::init(&config(&[member.address()], RoutingMode::Smart, timeouts))
+            .await
+            .unwrap();
+        let address = cluster.address(None).await.unwrap();
+
+        assert!(cluster.dispatch::<_, PingResponse>(PingRequest::new()).await.is_ok());
+        match cluster.forward::<_, PingResponse>(PingRequest::new(), &address).await {
+            Err(OperationTimeout(timeout)) => assert_eq!(timeout, timeouts.forward),
+            _ => panic!("expected forward to time out!"),
+        }
+    }
+
+    #[tokio::test]
+    async fn should_queue_invocations_beyond_limit() {
+        use crate::messaging::map::{MapGetRequest, MapGetResponse};
+
+        let received = Arc::new(AtomicUsize::new(0));
+        let gets = received.clone();
+        let member = MockMember::start_with_frames("member", Duration::from_secs(0), move |message| {
+            match message.r#type() {
+                0x102 => {
+                    gets.fetch_add(1, Ordering::SeqCst);
+                    Some(vec![])
+                }
+                _ => None,
+            }
+        })
+        .await;
+        let timeouts = Timeouts {
+            dispatch: Duration::from_secs(5),
+            forward: Duration::from_millis(300),
+        };
+        let mut config = config(&[member.address()], RoutingMode::Smart, timeouts);
+        config.max_concurrent_invocations = Some(2);
+
+        let cluster = Arc::new(Cluster::init(&config).await.unwrap());
+        let address = cluster.address(None).await.unwrap();
+        for _ in 0..5 {
+            let (cluster, address) = (cluster.clone(), address.clone());
+            tokio::spawn(async move {
+                let request = MapGetRequest::new("map-name", &[1], 1, -1);
+                let _ = cluster.forward::<_, MapGetResponse>(request, &address).await;
+            });
+        }
+        tokio::time::delay_for(Duration::from_millis(100)).await;
+
+        assert_eq!(received.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn should_time_out_slow_dispatch() {
+        use crate::messaging::ping::{PingRequest, PingResponse};
+
+        let member = MockMember::start_with_latency("member", Duration::from_millis(100), |_| None).await;
+        let timeouts = Timeouts {
+            dispatch: Duration::from_millis(10),
+            forward: Duration::from_secs(5),
+        };
+
+        let cluster = Cluster::init(&config(&[member.address()], RoutingMode::Smart, timeouts))
+            .await
+            .unwrap();
+        let address = cluster.address(None).await.unwrap();
+
+        match cluster.dispatch::<_, PingResponse>(PingRequest::new()).await {
+            Err(OperationTimeout(timeout)) => assert_eq!(timeout, timeouts.dispatch),
+            _ => panic!("expected dispatch to time out!"),
+        }
+        assert!(cluster
+            .forward::<_, PingResponse>(PingRequest::new(), &address)
+            .await
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn should_retry_forward_failing_with_retryable_error() {
+        use crate::messaging::{
+            error::TARGET_NOT_MEMBER,
+            map::{MapLoadAllRequest, MapLoadAllResponse},
+        };
+        use crate::remote::mock::exception;
+
+        let loads = Arc::new(AtomicUsize::new(0));
+        let attempts = loads.clone();
+        let member = MockMember::start_with("member", move |message| match message.r#type() {
+            0x124 if attempts.fetch_add(1, Ordering::SeqCst) == 0 => {
+                Some((0x6D, exception(TARGET_NOT_MEMBER, "TargetNotMemberException")))
+            }
+            _ => None,
+        })
+        .await;
+        let mut config = config(&[member.address()], RoutingMode::Smart, Timeouts::default());
+        config.retries = retries(3);
+
+        let cluster = Cluster::init(&config).await.unwrap();
+        let address = cluster.address(None).await.unwrap();
+
+        assert!(cluster
+            .forward::<_, MapLoadAllResponse>(MapLoadAllRequest::new("map-name", true), &address)
+            .await
+            .is_ok());
+        assert_eq!(loads.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn should_fail_once_retries_are_exhausted() {
+        use crate::messaging::{
+            error::HAZELCAST_INSTANCE_NOT_ACTIVE,
+            map::{MapLoadAllRequest, MapLoadAllResponse},
+        };
+        use crate::remote::mock::exception;
+
+        let loads = Arc::new(AtomicUsize::new(0));
+        let attempts = loads.clone();
+        let member = MockMember::start_with("member", move |message| match message.r#type() {
+            0x124 => {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                Some((
+                    0x6D,
+                    exception(HAZELCAST_INSTANCE_NOT_ACTIVE, "HazelcastInstanceNotActiveException"),
+                ))
+            }
+            _ => None,
+        })
+        .await;
+        let mut config = config(&[member.address()], RoutingMode::Smart, Timeouts::default());
+        config.retries = retries(2);
+
+        let cluster = Cluster::init(&config).await.unwrap();
+
+        assert!(matches!(
+            cluster
+                .dispatch::<_, MapLoadAllResponse>(MapLoadAllRequest::new("map-name", true))
+                .await,
+            Err(ServerFailure(_))
+        ));
+        assert_eq!(loads.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn should_not_retry_non_retryable_error() {
+        use crate::messaging::map::{MapLoadAllRequest, MapLoadAllResponse};
+        use crate::remote::mock::exception;
+
+        let loads = Arc::new(AtomicUsize::new(0));
+        let attempts = loads.clone();
+        let member = MockMember::start_with("member", move |message| match message.r#type() {
+            0x124 => {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                Some((0x6D, exception(39, "NullPointerException")))
+            }
+            _ => None,
+        })
+        .await;
+        let mut config = config(&[member.address()], RoutingMode::Smart, Timeouts::default());
+        config.retries = retries(2);
+
+        let cluster = Cluster::init(&config).await.unwrap();
+
+        assert!(cluster
+            .dispatch::<_, MapLoadAllResponse>(MapLoadAllRequest::new("map-name", true))
+            .await
+            .is_err());
+        assert_eq!(loads.load(Ordering::SeqCst), 1);
+    }
+
+    fn retries(count: u32) -> Retries {
+        Retries {
+            count,
+            backoff: Backoff {
+                base: Duration::from_millis(1),
+                max: Duration::from_millis(1),
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn should_stagger_pings_of_members_within_interval() {
+        use std::{sync::Mutex, time::Instant};
+
+        let pings = Arc::new(Mutex::new(Vec::new()));
+        let mut members = Vec::new();
+        for id in &["first", "second", "third"] {
+            let pings = pings.clone();
+            let member = MockMember::start_with(id, move |message| {
+                if message.r#type() == 0xF {
+                    pings.lock().unwrap().push(Instant::now());
+                }
+                None
+            })
+            .await;
+            members.push(member);
+        }
+        let endpoints: Vec<SocketAddr> = members.iter().map(|member| member.address()).collect();
+        let config = config(&endpoints, RoutingMode::Smart, Timeouts::default());
+        let members = Arc::new(Members::from(&config).await.unwrap());
+
+        let start = Instant::now();
+        let interval = Duration::from_millis(300);
+        let _pinger = Pinger::ping(
+            members,
+            Heartbeat {
+                interval,
+                timeout: Duration::from_secs(60),
+            },
+        );
+        DefaultRuntime::delay(interval - Duration::from_millis(50)).await;
+
+        let pings = pings.lock().unwrap();
+        assert_eq!(pings.len(), 3);
+        assert!(pings.iter().all(|ping| ping.duration_since(start) < interval));
+        let first = pings.iter().min().unwrap();
+        let last = pings.iter().max().unwrap();
+        assert!(last.duration_since(*first) >= 2 * interval / 6);
+    }
+
+    #[tokio::test]
+    async fn should_keep_pinging_members_while_another_stalls() {
+        let pings = Arc::new(AtomicUsize::new(0));
+        let counter = pings.clone();
+        let prompt = MockMember::start_with("prompt", move |message| {
+            if message.r#type() == 0xF {
+                counter.fetch_add(1, Ordering::SeqCst);
+            }
+            None
+        })
+        .await;
+        let stalling = MockMember::start_with_latency("stalling", Duration::from_secs(5), |_| None).await;
+        let config = config(
+            &[stalling.address(), prompt.address()],
+            RoutingMode::Smart,
+            Timeouts::default(),
+        );
+        let members = Arc::new(Members::from(&config).await.unwrap());
+
+        let _pinger = Pinger::ping(
+            members,
+            Heartbeat {
+                interval: Duration::from_millis(100),
+                timeout: Duration::from_secs(2),
+            },
+        );
+        DefaultRuntime::delay(Duration::from_millis(550)).await;
+
+        assert!(pings.load(Ordering::SeqCst) >= 3);
+    }
+
+    #[tokio::test]
+    async fn should_ping_members_at_heartbeat_interval() {
+        let pings = Arc::new(AtomicUsize::new(0));
+        let counter = pings.clone();
+        let member = MockMember::start_with("member", move |message| {
+            if message.r#type() == 0xF {
+                counter.fetch_add(1, Ordering::SeqCst);
+            }
+            None
+        })
+        .await;
+        let mut config = config(&[member.address()], RoutingMode::Smart, Timeouts::default());
+        config.heartbeat = Heartbeat {
+            interval: Duration::from_millis(100),
+            timeout: Duration::from_secs(1),
+        };
+
+        let _cluster = Cluster::init(&config).await.unwrap();
+        DefaultRuntime::delay(Duration::from_millis(350)).await;
+
+        assert!(pings.load(Ordering::SeqCst) >= 3);
+    }
+
+    #[tokio::test]
+    async fn should_disable_member_not_answering_ping_in_time() {
+        let member =
+            MockMember::start_with_frames("member", Duration::from_millis(0), |message| match message.r#type() {
+                0xF => Some(vec![]),
+                _ => None,
+            })
+            .await;
+        let config = config(&[member.address()], RoutingMode::Smart, Timeouts::default());
+        let members = Arc::new(Members::from(&config).await.unwrap());
+        assert!(members.get().await.is_some());
+
+        let heartbeat = Heartbeat {
+            interval: Duration::from_secs(60),
+            timeout: Duration::from_millis(100),
+        };
+        let _pinger = Pinger::ping(members.clone(), heartbeat);
+        DefaultRuntime::delay(Duration::from_millis(300)).await;
+
+        assert!(members.get().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn should_continue_pn_counter_get_after_member_reconnects() {
+        use crate::{codec::Writer, protocol::pn_counter::PnCounter};
+        use bytes::{Buf, BytesMut};
+
+        let member =
+            MockMember::start_with_latency("member", Duration::from_millis(100), |message| match message.r#type() {
+                0x2001 => {
+                    let writeable = &mut BytesMut::new();
+                    69i64.write_to(writeable);
+                    0u32.write_to(writeable);
+                    Some((0x7F, writeable.to_bytes()))
+                }
+                _ => None,
+            })
+            .await;
+        let cluster = Cluster::init(&config(&[member.address()], RoutingMode::Smart, Timeouts::default()))
+            .await
+            .unwrap();
+        let mut counter = PnCounter::new("counter", Arc::new(cluster));
+
+        let disconnect = async {
+            DefaultRuntime::delay(Duration::from_millis(20)).await;
+            member.disconnect();
+        };
+        let (value, _) = futures::join!(counter.get(), disconnect);
+
+        assert_eq!(value.unwrap(), 69);
+        assert_eq!(member.connections(), 2);
+    }
+
+    #[tokio::test]
+    async fn should_prefer_members_of_local_zone() {
+        let (first, second) = zoned_members().await;
+        let mut config = config(
+            &[first.address(), second.address()],
+            RoutingMode::Smart,
+            Timeouts::default(),
+        );
+        config.local_zone = Some("zone-b".to_string());
+        let cluster = Cluster::init(&config).await.unwrap();
+        while cluster.members.infos.read().await.len() < 2 {
+            DefaultRuntime::delay(Duration::from_millis(10)).await;
+        }
+
+        for _ in 0..4 {
+            assert_eq!(
+                cluster.members.get().await.unwrap().address(),
+                &Address::from(&second.address())
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn should_fall_back_to_any_member_when_none_is_in_local_zone() {
+        let (first, second) = zoned_members().await;
+        let mut config = config(
+            &[first.address(), second.address()],
+            RoutingMode::Smart,
+            Timeouts::default(),
+        );
+        config.local_zone = Some("zone-c".to_string());
+        let cluster = Cluster::init(&config).await.unwrap();
+        while cluster.members.infos.read().await.len() < 2 {
+            DefaultRuntime::delay(Duration::from_millis(10)).await;
+        }
+
+        let addresses: HashSet<Address> = futures::future::join_all((0..4).map(|_| cluster.members.get()))
+            .await
+            .into_iter()
+            .map(|member| member.unwrap().address().clone())
+            .collect();
+        assert_eq!(addresses.len(), 2);
+    }
+
+    // like real members, both authenticate with the client's id, which differs from their uuids in the member list
+    async fn zoned_members() -> (MockMember, MockMember) {
+        use crate::{
+            codec::Writer,
+            remote::mock::{event, frame, registration_payload},
+        };
+        use bytes::{Buf, BytesMut};
+        use std::sync::Mutex;
+
+        let addresses: Arc<Mutex<Vec<SocketAddr>>> = Arc::new(Mutex::new(Vec::new()));
+        let mut members = Vec::new();
+        for _ in 0..2 {
+            let addresses = addresses.clone();
+            let member = MockMember::start_with_frames("client", Duration::from_secs(0), move |message| match message
+                .r#type()
+            {
+                0x4 => {
+                    let writeable = &mut BytesMut::new();
+                    2u32.write_to(writeable);
+                    let zones = [("first-uuid", "zone-a"), ("second-uuid", "zone-b")];
+                    for (address, (id, zone)) in addresses.lock().unwrap().iter().zip(&zones) {
+                        Address::from(address).write_to(writeable);
+                        id.write_to(writeable);
+                        false.write_to(writeable);
+                        1u32.write_to(writeable);
+                        ZONE_ATTRIBUTE.write_to(writeable);
+                        zone.write_to(writeable);
+                    }
+                    Some(vec![
+                        frame(message.id(), 0x68, &registration_payload()),
+                        event(message.id(), 0xC9, &writeable.to_bytes()),
+                    ])
+                }
+                _ => None,
+            })
+            .await;
+            members.push(member);
+        }
+        *addresses.lock().unwrap() = members.iter().map(MockMember::address).collect();
+        let second = members.pop().unwrap();
+        (members.pop().unwrap(), second)
+    }
+
+    #[tokio::test]
+    async fn should_connect_to_and_report_member_joining_cluster() {
+        use crate::{
+            codec::Writer,
+            messaging::{
+                map::{MapLoadAllRequest, MapLoadAllResponse},
+                membership::MEMBER_ADDED,
+            },
+            remote::mock::{event, frame},
+        };
+        use bytes::{Buf, BytesMut};
+
+        let joining = MockMember::start("joining").await;
+        let writeable = &mut BytesMut::new();
+        Address::from(&joining.address()).write_to(writeable);
+        "joining".write_to(writeable);
+        false.write_to(writeable);
+        0u32.write_to(writeable);
+        MEMBER_ADDED.write_to(writeable);
+        let member_added = writeable.to_bytes();
+
+        // the event follows a later request, so that it cannot arrive before the test subscribes
+        let subscription = Arc::new(Mutex::new(None));
+        let member = MockMember::start_with_frames("member", Duration::from_secs(0), move |message| {
+            match message.r#type() {
+                0x4 => {
+                    *subscription.lock().unwrap() = Some(message.id());
+                    None
+                }
+                0x124 => {
+                    let subscription = subscription.lock().unwrap().unwrap();
+                    Some(vec![
+                        frame(message.id(), 0x64, &[]),
+                        event(subscription, 0xC8, &member_added),
+                    ])
+                }
+                _ => None,
+            }
+        })
+        .await;
+        let cluster = Cluster::init(&config(&[member.address()], RoutingMode::Smart, Timeouts::default()))
+            .await
+            .unwrap();
+        let mut events = cluster.membership_events();
+
+        let _: MapLoadAllResponse = cluster
+            .dispatch(MapLoadAllRequest::new("map-name", true))
+            .await
+            .unwrap();
+
+        let event = events.recv().await.unwrap();
+        assert_eq!(event.kind(), MembershipEventKind::Added);
+        assert_eq!(event.member().id(), "joining");
+        while cluster.members.get_all().await.len() < 2 {
+            DefaultRuntime::delay(Duration::from_millis(10)).await;
+        }
+        assert_eq!(joining.connections(), 1);
+    }
+
+    #[tokio::test]
+    async fn should_list_and_remove_listeners() {
+        use crate::codec::Writer;
+        use crate::messaging::partition::{
+            AddPartitionLostListenerRequest, AddPartitionLostListenerResponse, RemovePartitionLostListenerRequest,
+            RemovePartitionLostListenerResponse,
+        };
+        use bytes::{Buf, BytesMut};
+
+        let (registrations, removals) = (Arc::new(AtomicUsize::new(0)), Arc::new(AtomicUsize::new(0)));
+        let removed = removals.clone();
+        let member = MockMember::start_with("member", move |message| match message.r#type() {
+            0xA => {
+                let writeable = &mut BytesMut::new();
+                format!("registration-{}", registrations.fetch_add(1, Ordering::SeqCst)).write_to(writeable);
+                Some((0x68, writeable.to_bytes()))
+            }
+            0xB => {
+                removed.fetch_add(1, Ordering::SeqCst);
+                let writeable = &mut BytesMut::new();
+                true.write_to(writeable);
+                Some((0x65, writeable.to_bytes()))
+            }
+            _ => None,
+        })
+        .await;
+        let cluster = Cluster::init(&config(&[member.address()], RoutingMode::Smart, Timeouts::default()))
+            .await
+            .unwrap();
+
+        for _ in 0..2 {
+            let request = AddPartitionLostListenerRequest::new(false);
+            cluster
+                .listen::<_, AddPartitionLostListenerResponse, _, RemovePartitionLostListenerResponse, _>(
+                    ListenerKind::PartitionLost,
+                    request,
+                    RemovePartitionLostListenerRequest::new,
+                )
+                .await
+                .unwrap();
+        }
+        let listeners = cluster.listeners();
+        assert_eq!(listeners.len(), 2);
+        assert_eq!(listeners[0].registration_id(), "registration-0");
+        assert_eq!(listeners[0].kind(), ListenerKind::PartitionLost);
+
+        assert!(cluster.remove_listener("registration-0").await.unwrap());
+        assert!(!cluster.remove_listener("registration-0").await.unwrap());
+
+        assert_eq!(removals.load(Ordering::SeqCst), 1);
+        let listeners = cluster.listeners();
+        assert_eq!(listeners.len(), 1);
+        assert_eq!(listeners[0].registration_id(), "registration-1");
+    }
+
+    #[tokio::test]
+    async fn should_reap_idle_connection_while_keeping_active_one() {
+        use crate::{
+            messaging::ping::{PingRequest, PingResponse},
+            remote::mock::exception,
+        };
+
+        let no_listener = |message: &crate::remote::Message| match message.r#type() {
+            0x4 => Some((0x6D, exception(21, "HazelcastInstanceNotActiveException"))),
+            _ => None,
+        };
+        let (idle, active) = (
+            MockMember::start_with("idle", no_listener).await,
+            MockMember::start_with("active", no_listener).await,
+        );
+        let mut config = config(
+            &[idle.address(), active.address()],
+            RoutingMode::Smart,
+            Timeouts::default(),
+        );
+        config.idle_timeout = Some(Duration::from_millis(200));
+        let cluster = Cluster::init(&config).await.unwrap();
+        let address = Address::from(&active.address());
+
+        for _ in 0..10 {
+            let _: PingResponse = cluster.forward(PingRequest::new(), &address).await.unwrap();
+            DefaultRuntime::delay(Duration::from_millis(50)).await;
+        }
+
+        let members = cluster.members.get_all().await;
+        assert_eq!(members.len(), 1);
+        assert_eq!(members[0].address(), &Address::from(&active.address()));
+        drop(members);
+        for _ in 0..50 {
+            if idle.open_connections() == 0 {
+                break;
+            }
+            DefaultRuntime::delay(Duration::from_millis(10)).await;
+        }
+        assert_eq!(idle.open_connections(), 0);
+        assert_eq!(active.open_connections(), 1);
+    }
+
+    #[tokio::test]
+    async fn should_keep_idle_connection_to_partition_owner() {
+        use crate::{
+            messaging::ping::{PingRequest, PingResponse},
+            remote::mock::exception,
+        };
+
+        let no_listener = |message: &crate::remote::Message| match message.r#type() {
+            0x4 => Some((0x6D, exception(21, "HazelcastInstanceNotActiveException"))),
+            _ => None,
+        };
+        let (owner, active) = (
+            MockMember::start_with("owner", no_listener).await,
+            MockMember::start_with("active", no_listener).await,
+        );
+        let mut config = config(
+            &[owner.address(), active.address()],
+            RoutingMode::Smart,
+            Timeouts::default(),
+        );
+        config.idle_timeout = Some(Duration::from_millis(200));
+        let cluster = Cluster::init(&config).await.unwrap();
+        let owners = vec![(0, Address::from(&owner.address()))].into_iter().collect();
+        *cluster.partitions.write().await = Some(PartitionTable {
+            count: 1,
+            owners,
+            version: cluster.members.version().await,
+        });
+        let address = Address::from(&active.address());
+
+        for _ in 0..10 {
+            let _: PingResponse = cluster.forward(PingRequest::new(), &address).await.unwrap();
+            DefaultRuntime::delay(Duration::from_millis(50)).await;
+        }
+
+        assert_eq!(cluster.members.get_all().await.len(), 2);
+        assert_eq!(owner.open_connections(), 1);
+    }
+
+    #[tokio::test]
+    async fn should_reconnect_reaped_member_once_it_owns_partitions() {
+        use crate::{
+            messaging::ping::{PingRequest, PingResponse},
+            remote::mock::exception,
+        };
+
+        let no_listener = |message: &crate::remote::Message| match message.r#type() {
+            0x4 => Some((0x6D, exception(21, "HazelcastInstanceNotActiveException"))),
+            _ => None,
+        };
+        let (idle, active) = (
+            MockMember::start_with("idle", no_listener).await,
+            MockMember::start_with("active", no_listener).await,
+        );
+        let mut config = config(
+            &[idle.address(), active.address()],
+            RoutingMode::Smart,
+            Timeouts::default(),
+        );
+        config.idle_timeout = Some(Duration::from_millis(200));
+        let cluster = Cluster::init(&config).await.unwrap();
+        let (idle_address, active_address) = (Address::from(&idle.address()), Address::from(&active.address()));
+
+        for _ in 0..30 {
+            let _: PingResponse = cluster.forward(PingRequest::new(), &active_address).await.unwrap();
+            DefaultRuntime::delay(Duration::from_millis(50)).await;
+        }
+        assert!(cluster.members.get_by(&idle_address).await.is_none());
+        assert_eq!(idle.connections(), 1);
+
+        let owners = vec![(0, idle_address.clone())].into_iter().collect();
+        *cluster.partitions.write().await = Some(PartitionTable {
+            count: 1,
+            owners,
+            version: cluster.members.version().await,
+        });
+        for _ in 0..300 {
+            if cluster.members.get_by(&idle_address).await.is_some() {
+                break;
+            }
+            DefaultRuntime::delay(Duration::from_millis(10)).await;
+        }
+
+        assert!(cluster.members.get_by(&idle_address).await.is_some());
+        assert_eq!(idle.connections(), 2);
+    }
+
+    #[tokio::test]
+    async fn should_fail_send_to_member_with_closed_connection() {
+        use crate::messaging::ping::{PingRequest, PingResponse};
+
+        let member = MockMember::start("member").await;
+        let cluster = Cluster::init(&config(&[member.address()], RoutingMode::Smart, Timeouts::default()))
+            .await
+            .unwrap();
+        cluster.reconnector.lock().unwrap().take();
+        let connected = cluster.members.get().await.unwrap();
+
+        member.disconnect();
+        while connected.is_connected() {
+            DefaultRuntime::delay(Duration::from_millis(10)).await;
+        }
+
+        match cluster
+            .send::<_, PingResponse>(&connected, &PingRequest::new(), Duration::from_secs(1))
+            .await
+        {
+            Err(CommunicationFailure(_)) => {}
+            _ => panic!("expected communication failure!"),
+        }
+        assert!(cluster.members.get().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn should_back_off_reconnecting_to_unreachable_member() {
+        let member = MockMember::start("member").await;
+        let mut config = config(&[member.address()], RoutingMode::Smart, Timeouts::default());
+        config.reconnect_backoff = Backoff {
+            base: Duration::from_secs(60),
+            max: Duration::from_secs(60),
+        };
+        let cluster = Cluster::init(&config).await.unwrap();
+        cluster.reconnector.lock().unwrap().take();
+        let connected = cluster.members.get().await.unwrap();
+        let address = Address::from(&"127.0.0.1:1".parse().unwrap());
+        cluster.members.registry.write().await.disabled.insert(address.clone());
+
+        cluster.members.disable(&connected).await;
+        cluster.members.reconnect().await;
+        cluster.members.reconnect().await;
+
+        assert_eq!(member.connections(), 2);
+        let attempts = cluster.members.attempts.lock().unwrap();
+        assert_eq!(attempts.get(&address).map(|(failed, _)| *failed), Some(1));
+    }
+
+    #[tokio::test]
+    async fn should_reconnect_disabled_member() {
+        let member = MockMember::start("member").await;
+        let cluster = Cluster::init(&config(&[member.address()], RoutingMode::Smart, Timeouts::default()))
+            .await
+            .unwrap();
+        cluster.reconnector.lock().unwrap().take();
+        let connected = cluster.members.get().await.unwrap();
+
+        cluster.members.disable(&connected).await;
+        assert!(cluster.members.get().await.is_none());
+        cluster.members.reconnect().await;
+
+        assert_eq!(cluster.members.get_all().await.len(), 1);
+        assert_eq!(member.connections(), 2);
+    }
+
+    #[tokio::test]
+    async fn should_shut_down_idempotently() {
+        use crate::messaging::ping::{PingRequest, PingResponse};
+
+        let member = MockMember::start("member").await;
+        let cluster = Cluster::init(&config(&[member.address()], RoutingMode::Smart, Timeouts::default()))
+            .await
+            .unwrap();
+        assert_eq!(member.open_connections(), 1);
+
+        cluster.shutdown().await;
+        cluster.shutdown().await;
+
+        assert!(cluster.pinger.lock().unwrap().is_none());
+        assert!(cluster.members.get_all().await.is_empty());
+        match cluster.dispatch::<_, PingResponse>(PingRequest::new()).await {
+            Err(ClusterNonOperational) => {}
+            _ => panic!("expected cluster to be non operational!"),
+        }
+        drop(cluster);
+
+        for _ in 0..100 {
+            if member.open_connections() == 0 {
+                return;
+            }
+            DefaultRuntime::delay(Duration::from_millis(10)).await;
+        }
+        panic!("connection was not closed!");
+    }
+
+    #[tokio::test]
+    async fn should_fail_pending_and_subsequent_invocations_on_shutdown() {
+        use crate::messaging::ping::{PingRequest, PingResponse};
+
+        let member =
+            MockMember::start_with_frames("member", Duration::from_millis(0), |message| match message.r#type() {
+                0xF => Some(vec![]),
+                _ => None,
+            })
+            .await;
+        let cluster = Arc::new(
+            Cluster::init(&config(&[member.address()], RoutingMode::Smart, Timeouts::default()))
+                .await
+                .unwrap(),
+        );
+        let pending = cluster.clone();
+        let pending = tokio::spawn(async move { pending.dispatch::<_, PingResponse>(PingRequest::new()).await });
+        DefaultRuntime::delay(Duration::from_millis(50)).await;
+
+        cluster.shutdown().await;
+
+        match tokio::time::timeout(Duration::from_secs(1), pending)
+            .await
+            .unwrap()
+            .unwrap()
+        {
+            Err(CommunicationFailure(e)) => assert_eq!(e.to_string(), "client shutting down"),
+            _ => panic!("expected pending invocation to fail!"),
+        }
+        match tokio::time::timeout(
+            Duration::from_secs(1),
+            cluster.dispatch::<_, PingResponse>(PingRequest::new()),
+        )
+        .await
+        .unwrap()
+        {
+            Err(ClusterNonOperational) => {}
+            _ => panic!("expected cluster to be non operational!"),
+        }
+    }
+
+    #[tokio::test]
+    async fn should_close_connections_on_drop() {
+        let member = MockMember::start("member").await;
+        let cluster = Cluster::init(&config(&[member.address()], RoutingMode::Smart, Timeouts::default()))
+            .await
+            .unwrap();
+        assert_eq!(member.open_connections(), 1);
+
+        drop(cluster);
+
+        for _ in 0..100 {
+            if member.open_connections() == 0 {
+                return;
+            }
+            DefaultRuntime::delay(Duration::from_millis(10)).await;
+        }
+        panic!("connection was not closed!");
+    }
+
+    #[tokio::test]
+    async fn should_connect_to_all_members_in_smart_mode() {
+        let (first, second) = (MockMember::start("first").await, MockMember::start("second").await);
+
+        let cluster = Cluster::init(&config(
+            &[first.address(), second.address()],
+            RoutingMode::Smart,
+            Timeouts::default(),
+        ))
+        .await
+        .unwrap();
+
+        assert_eq!(cluster.members.get_all().await.len(), 2);
+        assert_eq!(first.connections() + second.connections(), 2);
+    }
+
+    #[tokio::test]
+    async fn should_connect_once_to_member_reachable_at_several_addresses() {
+        use crate::remote::mock::authentication_payload;
+
+        let first = MockMember::start("member").await;
+        let address = first.address();
+        let second = MockMember::start_with("member", move |message| match message.r#type() {
+            0x2 => Some((0x6B, authentication_payload(&address, "client", "member"))),
+            _ => None,
+        })
+        .await;
+
+        let cluster = Cluster::init(&config(
+            &[first.address(), second.address()],
+            RoutingMode::Smart,
+            Timeouts::default(),
+        ))
+        .await
+        .unwrap();
+
+        assert_eq!(cluster.members.get_all().await.len(), 1);
+        assert_eq!(first.connections() + second.connections(), 2);
+    }
+
+    #[tokio::test]
+    async fn should_reuse_identity_of_first_authentication_and_keep_members_distinct() {
+        use crate::codec::Reader;
+        use std::sync::Mutex;
+
+        let identities = Arc::new(Mutex::new(Vec::new()));
+        let mut members = Vec::new();
+        for id in &["first", "second"] {
+            let (identities, name) = (identities.clone(), id.to_string());
+            let member = MockMember::start_with(id, move |message| {
+                if message.r#type() == 0x2 {
+                    let readable = &mut message.payload();
+                    let (_username, _password) = (String::read_from(readable), String::read_from(readable));
+                    let id = Option::<String>::read_from(readable).unwrap();
+                    let owner_id = Option::<String>::read_from(readable).unwrap();
+                    identities.lock().unwrap().push((name.clone(), id, owner_id));
+                }
+                None
+            })
+            .await;
+            members.push(member);
+        }
+
+        let cluster = Cluster::init(&config(
+            &[members[0].address(), members[1].address()],
+            RoutingMode::Smart,
+            Timeouts::default(),
+        ))
+        .await
+        .unwrap();
+
+        let identities = identities.lock().unwrap().clone();
+        assert_eq!(identities.len(), 2);
+        let (owner, id, owner_id) = &identities[0];
+        assert_eq!((id, owner_id), (&None, &None));
+        assert!(identities[1].1.as_deref().is_some_and(|id| id.starts_with("client-")));
+        assert_eq!(identities[1].2.as_ref(), Some(owner));
+
+        let connected = cluster.members.get_all().await;
+        assert_eq!(connected.len(), 2);
+        assert!(*connected[0] != *connected[1]);
+        cluster.members.disable(&connected[0]).await;
+        let remaining = cluster.members.get_all().await;
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].address(), connected[1].address());
+    }
+
+    #[tokio::test]
+    async fn should_keep_members_authenticated_with_same_id() {
+        let (first, second) = (MockMember::start("client").await, MockMember::start("client").await);
+
+        let cluster = Cluster::init(&config(
+            &[first.address(), second.address()],
+            RoutingMode::Smart,
+            Timeouts::default(),
+        ))
+        .await
+        .unwrap();
+
+        assert_eq!(cluster.members.get_all().await.len(), 2);
+        assert_eq!(first.connections(), 1);
+        assert_eq!(second.connections(), 1);
+    }
+
+    #[tokio::test]
+    async fn should_connect_to_single_member_in_unisocket_mode() {
+        let (first, second) = (MockMember::start("first").await, MockMember::start("second").await);
+
+        let cluster = Cluster::init(&config(
+            &[first.address(), second.address()],
+            RoutingMode::Unisocket,
+            Timeouts::default(),
+        ))
+        .await
+        .unwrap();
+
+        assert_eq!(cluster.members.get_all().await.len(), 1);
+        assert_eq!(first.connections() + second.connections(), 1);
+    }
+
+    #[tokio::test]
+    async fn should_dispatch_to_single_connection_in_unisocket_mode_despite_partition_owner() {
+        use crate::{
+            codec::Writer,
+            messaging::{
+                map::{MapGetRequest, MapGetResponse},
+                Address,
+            },
+        };
+        use bytes::{Buf, BytesMut};
+        use std::sync::Mutex;
+
+        let owner_address = Arc::new(Mutex::new(None));
+        let gets = Arc::new(Mutex::new(Vec::new()));
+        let mut members = Vec::new();
+        for index in 0..2 {
+            let (owner_address, gets) = (owner_address.clone(), gets.clone());
+            let member = MockMember::start_with("member", move |message| match message.r#type() {
+                0x8 => {
+                    let owner: SocketAddr = owner_address.lock().unwrap().expect("missing owner!");
+                    let writeable = &mut BytesMut::new();
+                    1u32.write_to(writeable);
+                    Address::from(&owner).write_to(writeable);
+                    (&(0..271).collect::<Vec<i32>>()[..]).write_to(writeable);
+                    1i32.write_to(writeable);
+                    Some((0x6C, writeable.to_bytes()))
+                }
+                0x102 => {
+                    gets.lock().unwrap().push(index);
+                    let writeable = &mut BytesMut::new();
+                    Option::<&[u8]>::None.write_to(writeable);
+                    Some((0x69, writeable.to_bytes()))
+                }
+                _ => None,
+            })
+            .await;
+            members.push(member);
+        }
+        let cluster = Cluster::init(&config(
+            &[members[0].address(), members[1].address()],
+            RoutingMode::Unisocket,
+            Timeouts::default(),
+        ))
+        .await
+        .unwrap();
+        // the partitions are owned by the member the client is not connected to
+        let (connected, owner) = match members[0].connections() {
+            1 => (0, 1),
+            _ => (1, 0),
+        };
+        *owner_address.lock().unwrap() = Some(members[owner].address());
+
+        for key in &[[0u8, 0, 0, 0, 0, 0, 0, 1, 1], [0, 0, 0, 0, 0, 0, 0, 1, 2]] {
+            let partition_id = cluster
+                .partition_id(Data::from(&key[..]).partition_hash())
+                .await
+                .unwrap();
+            assert_eq!(
+                cluster.partition_owner(partition_id).await,
+                Some(Address::from(&members[owner].address()))
+            );
+            let _: MapGetResponse = cluster
+                .dispatch(MapGetRequest::new("map-name", key, 1, partition_id))
+                .await
+                .unwrap();
+        }
+
+        assert_eq!(*gets.lock().unwrap(), vec![connected, connected]);
+        assert_eq!(members[owner].connections(), 0);
+    }
+
+    #[test]
+    fn should_get_none_for_empty_registry() {
+        let registry: Registry<&str, &str> = Registry::new();
+
+        assert!(registry.get().is_none());
+        assert!(registry.get_by(&"some-key").is_none());
+        assert!(registry.get_all().is_empty());
+    }
+
+    #[test]
+    fn should_get_some_after_enable() {
+        let mut registry = Registry::new();
+
+        let key = "some-key";
+        let value = "some=value";
+
+        registry.enable(key, value);
+
+        assert_eq!(*registry.get().unwrap(), value);
+        assert_eq!(*registry.get_by(&key).unwrap(), value);
+        assert_eq!(*registry.get_all()[0], value);
+    }
+
+    #[test]
+    fn should_get_none_after_disable() {
+        let mut registry = Registry::new();
+
+        let key = "some-key";
+        let value = "some=value";
+
+        registry.enable(key, value);
+        registry.disable(&value);
+
+        assert!(registry.get().is_none());
         assert!(registry.get_by(&key).is_none());
         assert!(registry.get_all().is_empty());
     }
+
+    #[test]
+    fn should_list_disabled_keys() {
+        let mut registry = Registry::new();
+
+        registry.enable("some-key", "some-value");
+        registry.enable("other-key", "other-value");
+        registry.disable(&"some-value");
+
+        assert_eq!(registry.disabled(), vec!["some-key"]);
+        registry.enable("some-key", "some-value");
+        assert!(registry.disabled().is_empty());
+    }
+
+    #[test]
+    fn should_get_same_value_for_same_partition() {
+        let mut registry = Registry::new();
+
+        registry.enable("some-key", "some-value");
+        registry.enable("other-key", "other-value");
+
+        let value = registry.get_for(7).unwrap();
+        for _ in 0..10 {
+            assert_eq!(registry.get_for(7).unwrap(), value);
+        }
+        assert_ne!(registry.get_for(-1).unwrap(), registry.get_for(-1).unwrap());
+    }
+
+    #[test]
+    fn should_get_only_matching_values() {
+        let mut registry = Registry::new();
+
+        registry.enable("some-key", "some-value");
+        registry.enable("other-key", "other-value");
+
+        for _ in 0..4 {
+            assert_eq!(
+                *registry.get_matching(|value| *value == "other-value").unwrap(),
+                "other-value"
+            );
+        }
+        assert!(registry.get_matching(|value| *value == "unknown-value").is_none());
+    }
+
+    #[test]
+    fn should_reap_value_without_disabling_it_until_woken() {
+        let mut registry = Registry::new();
+
+        registry.enable("some-key", "some-value");
+        registry.enable("other-key", "other-value");
+        let version = registry.version();
+        registry.reap(&"some-value");
+
+        assert!(registry.get_by(&"some-key").is_none());
+        assert_eq!(registry.get_all(), vec![Arc::new("other-value")]);
+        assert!(registry.disabled().is_empty());
+        assert_ne!(registry.version(), version);
+
+        registry.discover("some-key");
+        assert!(registry.disabled().is_empty());
+        registry.wake(&"some-key");
+        assert_eq!(registry.disabled(), vec!["some-key"]);
+    }
+
+    #[test]
+    fn should_disable_reaped_values_when_last_value_is_disabled() {
+        let mut registry = Registry::new();
+
+        registry.enable("some-key", "some-value");
+        registry.enable("other-key", "other-value");
+        registry.reap(&"some-value");
+        registry.disable(&"other-value");
+
+        let mut disabled = registry.disabled();
+        disabled.sort();
+        assert_eq!(disabled, vec!["other-key", "some-key"]);
+    }
+
+    #[test]
+    fn should_discover_only_keys_not_enabled() {
+        let mut registry = Registry::new();
+
+        registry.enable("some-key", "some-value");
+        registry.discover("some-key");
+        registry.discover("other-key");
+
+        assert_eq!(registry.get_all(), vec![Arc::new("some-value")]);
+        assert_eq!(registry.disabled(), vec!["other-key"]);
+    }
+
+    #[test]
+    fn should_forget_enabled_and_disabled_keys() {
+        let mut registry = Registry::new();
+
+        registry.enable("some-key", "some-value");
+        registry.enable("other-key", "other-value");
+        registry.discover("third-key");
+        let version = registry.version();
+        registry.forget(&"some-key");
+        registry.forget(&"third-key");
+
+        assert!(registry.get_by(&"some-key").is_none());
+        assert_eq!(registry.get_all(), vec![Arc::new("other-value")]);
+        assert!(registry.disabled().is_empty());
+        assert_ne!(registry.version(), version);
+    }
+
+    #[test]
+    fn should_get_none_after_clear() {
+        let mut registry = Registry::new();
+
+        registry.enable("some-key", "some-value");
+        registry.enable("other-key", "other-value");
+        let version = registry.version();
+        registry.clear();
+
+        assert!(registry.get().is_none());
+        assert!(registry.get_by(&"some-key").is_none());
+        assert!(registry.get_all().is_empty());
+        assert_ne!(registry.version(), version);
+    }
+
+    #[test]
+    fn should_change_version_on_enable_and_disable() {
+        let mut registry = Registry::new();
+
+        let key = "some-key";
+        let value = "some=value";
+
+        let initial = registry.version();
+        registry.enable(key, value);
+        let enabled = registry.version();
+        registry.disable(&value);
+        let disabled = registry.version();
+        registry.disable(&value);
+
+        assert_ne!(initial, enabled);
+        assert_ne!(enabled, disabled);
+        assert_eq!(registry.version(), disabled);
+    }
 }
@@ -0,0 +1,247 @@
+use std::collections::HashMap;
+
+use bytes::{Bytes, BytesMut};
+use log::warn;
+
+use crate::remote::{Message, BEGIN_MESSAGE, END_MESSAGE, HEADER_LENGTH, LENGTH_FIELD_LENGTH, UNFRAGMENTED_MESSAGE};
+
+// messages whose end never arrives would otherwise hold on to their fragments for as long as the connection lives
+pub(in crate::remote) const MAX_PENDING_LENGTH: usize = 128 * 1024 * 1024;
+
+// large messages arrive split into fragments sharing a correlation id, the first of which determines the type
+pub(in crate::remote) struct FragmentAssembler {
+    pending: HashMap<u64, (u8, u16, BytesMut)>,
+    pending_length: usize,
+    max_pending_length: usize,
+}
+
+impl FragmentAssembler {
+    pub(in crate::remote) fn new(max_pending_length: usize) -> Self {
+        FragmentAssembler {
+            pending: HashMap::new(),
+            pending_length: 0,
+            max_pending_length,
+        }
+    }
+
+    // a message whose fragments would push the buffered total past the limit is dropped as a whole
+    pub(in crate::remote) fn assemble(&mut self, message: Message) -> Option<Message> {
+        let Message(id, flags, r#type, payload) = message;
+        let end = flags & END_MESSAGE != 0;
+        match (flags & BEGIN_MESSAGE != 0, end) {
+            (true, true) => return Some(Message(id, flags, r#type, payload)),
+            (true, false) => {
+                self.discard(id);
+                self.pending.insert(id, (flags, r#type, BytesMut::new()));
+            }
+            (false, _) if !self.pending.contains_key(&id) => {
+                warn!("Dropping fragment of message (id: {}) without its beginning.", id);
+                return None;
+            }
+            (false, _) => {}
+        }
+
+        if self.pending_length + payload.len() > self.max_pending_length {
+            self.discard(id);
+            warn!(
+                "Dropping message (id: {}) as {} bytes of fragments are buffered already.",
+                id, self.pending_length
+            );
+            return None;
+        }
+        self.pending_length += payload.len();
+        let (_, _, assembled) = self.pending.get_mut(&id).expect("missing fragments!");
+        assembled.extend_from_slice(&payload);
+        if !end {
+            return None;
+        }
+        let (flags, r#type, assembled) = self.pending.remove(&id).expect("missing fragments!");
+        self.pending_length -= assembled.len();
+        Some(Message(id, flags | UNFRAGMENTED_MESSAGE, r#type, assembled.freeze()))
+    }
+
+    // messages still missing fragments once the connection is closed will never be completed
+    pub(in crate::remote) fn clear(&mut self) {
+        if !self.pending.is_empty() {
+            warn!(
+                "Dropping {} incomplete message(s) of closed connection.",
+                self.pending.len()
+            );
+        }
+        self.pending.clear();
+        self.pending_length = 0;
+    }
+
+    fn discard(&mut self, id: u64) {
+        if let Some((_, _, assembled)) = self.pending.remove(&id) {
+            self.pending_length -= assembled.len();
+        }
+    }
+}
+
+// every fragment repeats the header of the frame, flagged as the beginning, the end or neither
+pub(in crate::remote) fn split(frame: &Bytes, max_frame_size: usize) -> Vec<Bytes> {
+    let header_length = HEADER_LENGTH - LENGTH_FIELD_LENGTH;
+    let (header, payload) = (&frame[..header_length], &frame[header_length..]);
+    let chunks: Vec<&[u8]> = payload.chunks(max_frame_size - HEADER_LENGTH).collect();
+    let last = chunks.len() - 1;
+    chunks
+        .iter()
+        .enumerate()
+        .map(|(index, chunk)| {
+            let mut fragment = BytesMut::with_capacity(header_length + chunk.len());
+            fragment.extend_from_slice(header);
+            fragment[1] &= !UNFRAGMENTED_MESSAGE;
+            if index == 0 {
+                fragment[1] |= BEGIN_MESSAGE;
+            }
+            if index == last {
+                fragment[1] |= END_MESSAGE;
+            }
+            fragment.extend_from_slice(chunk);
+            fragment.freeze()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+
+    use crate::remote::EVENT_MESSAGE;
+
+    use super::*;
+
+    #[test]
+    fn should_pass_unfragmented_message_through() {
+        let message = Message(1, UNFRAGMENTED_MESSAGE, 0x69, Bytes::from_static(&[1, 2, 3]));
+
+        let mut assembler = FragmentAssembler::new(MAX_PENDING_LENGTH);
+        assert_eq!(
+            assembler.assemble(Message(1, UNFRAGMENTED_MESSAGE, 0x69, Bytes::from_static(&[1, 2, 3]))),
+            Some(message)
+        );
+    }
+
+    #[test]
+    fn should_reassemble_fragmented_message() {
+        let unfragmented = Message(
+            7,
+            UNFRAGMENTED_MESSAGE | EVENT_MESSAGE,
+            0xCB,
+            Bytes::from_static(&[1, 2, 3, 4]),
+        );
+
+        let mut assembler = FragmentAssembler::new(MAX_PENDING_LENGTH);
+        let first = Message(7, BEGIN_MESSAGE | EVENT_MESSAGE, 0xCB, Bytes::from_static(&[1, 2]));
+        let interleaved = Message(8, UNFRAGMENTED_MESSAGE, 0x64, Bytes::new());
+        let second = Message(7, END_MESSAGE, 0, Bytes::from_static(&[3, 4]));
+
+        assert_eq!(assembler.assemble(first), None);
+        assert!(assembler.assemble(interleaved).is_some());
+        assert_eq!(assembler.assemble(second), Some(unfragmented));
+    }
+
+    #[test]
+    fn should_split_frame_into_fragments_that_reassemble_to_it() {
+        use crate::messaging::map::MapContainsValueRequest;
+
+        let value = vec![7u8; 2 * 1024 * 1024];
+        let message: Message = (3, MapContainsValueRequest::new("map-name", &value)).into();
+        let frame = message.payload();
+
+        let fragments = split(&frame, 1024 * 1024);
+        assert_eq!(fragments.len(), 3);
+        assert!(fragments
+            .iter()
+            .all(|fragment| fragment.len() + LENGTH_FIELD_LENGTH <= 1024 * 1024));
+
+        let mut assembler = FragmentAssembler::new(MAX_PENDING_LENGTH);
+        let mut assembled: Vec<Message> = fragments
+            .into_iter()
+            .filter_map(|fragment| assembler.assemble(Message::decode(fragment).unwrap()))
+            .collect();
+        assert_eq!(assembled.len(), 1);
+        assert_eq!(assembled.pop(), Some(Message::decode(frame).unwrap()));
+    }
+
+    #[test]
+    fn should_drop_fragment_without_beginning() {
+        let mut assembler = FragmentAssembler::new(MAX_PENDING_LENGTH);
+
+        assert_eq!(
+            assembler.assemble(Message(7, END_MESSAGE, 0, Bytes::from_static(&[3, 4]))),
+            None
+        );
+    }
+
+    #[test]
+    fn should_drop_message_exceeding_pending_length() {
+        let mut assembler = FragmentAssembler::new(4);
+
+        assert_eq!(
+            assembler.assemble(Message(7, BEGIN_MESSAGE, 0xCB, Bytes::from_static(&[1, 2, 3]))),
+            None
+        );
+        assert_eq!(
+            assembler.assemble(Message(8, BEGIN_MESSAGE, 0xCB, Bytes::from_static(&[1, 2]))),
+            None
+        );
+        assert_eq!(
+            assembler.assemble(Message(8, END_MESSAGE, 0, Bytes::from_static(&[3]))),
+            None
+        );
+        assert_eq!(
+            assembler.assemble(Message(7, END_MESSAGE, 0, Bytes::from_static(&[4]))),
+            Some(Message(
+                7,
+                UNFRAGMENTED_MESSAGE,
+                0xCB,
+                Bytes::from_static(&[1, 2, 3, 4])
+            ))
+        );
+
+        assert_eq!(
+            assembler.assemble(Message(8, BEGIN_MESSAGE, 0xCB, Bytes::from_static(&[1, 2]))),
+            None
+        );
+        assert_eq!(
+            assembler.assemble(Message(8, END_MESSAGE, 0, Bytes::from_static(&[3, 4]))),
+            Some(Message(
+                8,
+                UNFRAGMENTED_MESSAGE,
+                0xCB,
+                Bytes::from_static(&[1, 2, 3, 4])
+            ))
+        );
+    }
+
+    #[test]
+    fn should_drop_incomplete_messages_when_cleared() {
+        let mut assembler = FragmentAssembler::new(4);
+
+        assert_eq!(
+            assembler.assemble(Message(7, BEGIN_MESSAGE, 0xCB, Bytes::from_static(&[1, 2, 3, 4]))),
+            None
+        );
+        assembler.clear();
+
+        assert_eq!(
+            assembler.assemble(Message(7, END_MESSAGE, 0, Bytes::from_static(&[5]))),
+            None
+        );
+        assert_eq!(
+            assembler.assemble(Message(8, BEGIN_MESSAGE, 0xCB, Bytes::from_static(&[1, 2]))),
+            None
+        );
+        assert_eq!(
+            assembler.assemble(Message(8, END_MESSAGE, 0, Bytes::from_static(&[3, 4]))),
+            Some(Message(
+                8,
+                UNFRAGMENTED_MESSAGE,
+                0xCB,
+                Bytes::from_static(&[1, 2, 3, 4])
+            ))
+        );
+    }
+}
@@ -1,13 +1,20 @@
 use std::{
+    error::Error,
     net::SocketAddr,
-    sync::atomic::{AtomicUsize, Ordering},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+    time::Instant,
 };
 
 use derive_more::Display;
+use tokio::sync::{mpsc, Semaphore};
 
 use crate::{
+    codec::DecodeError,
     messaging::{Address, Request, Response},
-    remote::{channel::Channel, CLIENT_TYPE, CLIENT_VERSION, PROTOCOL_VERSION},
+    remote::{channel::Channel, transport::Transport, Message, CLIENT_TYPE, CLIENT_VERSION, PROTOCOL_VERSION},
     HazelcastClientError::{AuthenticationFailure, CommunicationFailure},
     {Result, TryFrom},
 };
@@ -18,76 +25,246 @@ pub(in crate::remote) struct Member {
     id: String,
     owner_id: String,
     address: Address,
+    server_version: Option<String>,
+    last_used: Mutex<Instant>,
 
     sender: Sender,
 }
 
 impl Member {
-    pub(in crate::remote) async fn connect(endpoint: &SocketAddr, username: &str, password: &str) -> Result<Self> {
+    pub(in crate::remote) async fn connect(
+        endpoint: &SocketAddr,
+        transport: &Transport,
+        username: &str,
+        password: &str,
+        identity: Option<&Identity>,
+        max_concurrent_invocations: Option<usize>,
+        max_frame_size: usize,
+    ) -> Result<Self> {
         use crate::messaging::authentication::{AuthenticationRequest, AuthenticationResponse, AuthenticationStatus};
 
-        let channel = match Channel::connect(endpoint).await {
+        let channel = match Channel::connect(endpoint, transport, max_frame_size).await {
             Ok(channel) => channel,
             Err(e) => return Err(CommunicationFailure(e)),
         };
-        let sender = Sender::new(channel);
+        let sender = Sender::new(channel, max_concurrent_invocations);
 
-        let request = AuthenticationRequest::new(username, password, CLIENT_TYPE, PROTOCOL_VERSION, CLIENT_VERSION);
-        let response: AuthenticationResponse = sender.send(request).await?;
+        let request = AuthenticationRequest::new(
+            username,
+            password,
+            identity.map(|identity| identity.id.as_str()),
+            identity.map(|identity| identity.owner_id.as_str()),
+            CLIENT_TYPE,
+            PROTOCOL_VERSION,
+            CLIENT_VERSION,
+        );
+        let response: AuthenticationResponse = sender.send(&request).await?;
         match AuthenticationResponse::status(&response) {
             AuthenticationStatus::Authenticated => Ok(Member {
-                id: response.id().as_ref().expect("missing id!").clone(),
-                owner_id: response.owner_id().as_ref().expect("missing owner id!").clone(),
-                address: response.address().as_ref().expect("missing address!").clone(),
+                id: required(response.id(), "id")?,
+                owner_id: required(response.owner_id(), "owner id")?,
+                address: required(response.address(), "address")?,
+                server_version: response.server_version().clone(),
+                last_used: Mutex::new(Instant::now()),
                 sender,
             }),
             status => Err(AuthenticationFailure(status.to_string())),
         }
     }
 
-    pub(in crate::remote) async fn send<RQ: Request, RS: Response>(&self, request: RQ) -> Result<RS> {
+    pub(in crate::remote) async fn send<RQ: Request, RS: Response>(&self, request: &RQ) -> Result<RS> {
         self.sender.send(request).await
     }
 
+    pub(in crate::remote) async fn subscribe<RQ: Request, RS: Response>(
+        &self,
+        request: RQ,
+    ) -> Result<(RS, u64, mpsc::UnboundedReceiver<Message>)> {
+        self.sender.subscribe(request).await
+    }
+
+    pub(in crate::remote) fn unsubscribe(&self, correlation_id: u64) {
+        self.sender.channel.deregister_listener(correlation_id)
+    }
+
+    pub(in crate::remote) fn close(&self) {
+        self.sender.channel.close()
+    }
+
+    pub(in crate::remote) fn touch(&self) {
+        *self.last_used.lock().expect("poisoned lock!") = Instant::now();
+    }
+
+    pub(in crate::remote) fn last_used(&self) -> Instant {
+        *self.last_used.lock().expect("poisoned lock!")
+    }
+
+    pub(in crate::remote) fn is_connected(&self) -> bool {
+        self.sender.channel.is_connected()
+    }
+
+    pub(in crate::remote) fn has_listeners(&self) -> bool {
+        self.sender.channel.has_listeners()
+    }
+
     pub(in crate::remote) fn address(&self) -> &Address {
         &self.address
     }
+
+    // members speaking protocol 1.2 or older do not report their version
+    pub(in crate::remote) fn server_version(&self) -> Option<&str> {
+        self.server_version.as_deref()
+    }
+
+    pub(in crate::remote) fn identity(&self) -> Identity {
+        Identity {
+            id: self.id.clone(),
+            owner_id: self.owner_id.clone(),
+        }
+    }
+}
+
+// an authenticated member is expected to identify itself fully
+fn required<T: Clone>(field: &Option<T>, name: &str) -> Result<T> {
+    field
+        .clone()
+        .ok_or_else(|| DecodeError::new(&format!("authentication response without {}", name)).into())
+}
+
+#[derive(Clone, Debug)]
+pub(in crate::remote) struct Identity {
+    id: String,
+    owner_id: String,
 }
 
 impl Eq for Member {}
 
+// the id a member authenticates with is the client's, the same on every connection, so only the address tells
+// members apart
 impl PartialEq for Member {
     fn eq(&self, other: &Self) -> bool {
-        self.id.eq(&other.id)
+        self.address.eq(&other.address)
     }
 }
 
 struct Sender {
-    sequencer: AtomicUsize,
+    sequencer: Sequencer,
     channel: Channel,
+    invocations: Option<Semaphore>,
 }
 
 impl Sender {
-    fn new(channel: Channel) -> Self {
+    fn new(channel: Channel, max_concurrent_invocations: Option<usize>) -> Self {
         Sender {
-            sequencer: AtomicUsize::new(0),
+            sequencer: Sequencer::starting_at(0),
             channel,
+            invocations: max_concurrent_invocations.map(Semaphore::new),
         }
     }
 
-    async fn send<RQ: Request, RS: Response>(&self, request: RQ) -> Result<RS> {
-        use std::convert::TryInto;
+    async fn send<RQ: Request, RS: Response>(&self, request: &RQ) -> Result<RS> {
+        let message = Message::encode(self.next_id(), request);
 
-        let id: u64 = self
-            .sequencer
-            .fetch_add(1, Ordering::SeqCst)
-            .try_into()
-            .expect("unable to convert!");
-        let message = (id, request).into();
+        match self.invoke(message).await {
+            Ok(message) => TryFrom::<RS>::try_from(message),
+            Err(e) => Err(CommunicationFailure(e)),
+        }
+    }
+
+    // the listener is registered up front, so events following the response closely are not lost
+    async fn subscribe<RQ: Request, RS: Response>(
+        &self,
+        request: RQ,
+    ) -> Result<(RS, u64, mpsc::UnboundedReceiver<Message>)> {
+        let id = self.next_id();
+        let events = self.channel.register_listener(id);
 
-        match self.channel.send(message).await {
+        let result = match self.invoke((id, request).into()).await {
             Ok(message) => TryFrom::<RS>::try_from(message),
             Err(e) => Err(CommunicationFailure(e)),
+        };
+        match result {
+            Ok(response) => Ok((response, id, events)),
+            Err(e) => {
+                self.channel.deregister_listener(id);
+                Err(e)
+            }
         }
     }
+
+    // a permit is held until the response arrives, so excess invocations queue up instead of piling onto the member
+    async fn invoke(&self, message: Message) -> std::result::Result<Message, Box<dyn Error + Send + Sync>> {
+        let _permit = match &self.invocations {
+            Some(invocations) => Some(invocations.acquire().await),
+            None => None,
+        };
+        self.channel.send(message).await
+    }
+
+    fn next_id(&self) -> u64 {
+        self.sequencer.next(|id| self.channel.is_listening(id))
+    }
+}
+
+// ids wrap around eventually, so the ones still held by long-lived listeners are skipped rather than reused
+struct Sequencer(AtomicU64);
+
+impl Sequencer {
+    fn starting_at(id: u64) -> Self {
+        Sequencer(AtomicU64::new(id))
+    }
+
+    fn next(&self, taken: impl Fn(u64) -> bool) -> u64 {
+        loop {
+            let id = self.0.fetch_add(1, Ordering::SeqCst);
+            if !taken(id) {
+                return id;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::{Buf, BytesMut};
+
+    use crate::{
+        codec::Writer,
+        remote::{mock::MockMember, DEFAULT_MAX_FRAME_SIZE},
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn should_fail_authentication_with_unknown_status() {
+        let member = MockMember::start_with("member", |message| match message.r#type() {
+            0x2 => {
+                let writeable = &mut BytesMut::new();
+                7u8.write_to(writeable);
+                Option::<Address>::None.write_to(writeable);
+                Option::<&str>::None.write_to(writeable);
+                Option::<&str>::None.write_to(writeable);
+                1u8.write_to(writeable);
+                Some((0x6B, writeable.to_bytes()))
+            }
+            _ => None,
+        })
+        .await;
+        let (address, transport) = (member.address(), Transport::default());
+
+        match Member::connect(&address, &transport, "", "", None, None, DEFAULT_MAX_FRAME_SIZE).await {
+            Err(AuthenticationFailure(status)) => assert_eq!(status, "unknown status 7"),
+            _ => panic!("expected authentication failure!"),
+        }
+    }
+
+    #[test]
+    fn should_keep_producing_distinct_ids_past_wraparound() {
+        let sequencer = Sequencer::starting_at(u64::MAX - 1);
+        let listening = [0, 2];
+
+        let ids: Vec<u64> = (0..4).map(|_| sequencer.next(|id| listening.contains(&id))).collect();
+
+        assert_eq!(ids, vec![u64::MAX - 1, u64::MAX, 1, 3]);
+    }
 }
@@ -0,0 +1,291 @@
+use std::{
+    net::SocketAddr,
+    ops::Deref,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use bytes::{Buf, Bytes, BytesMut};
+use futures::SinkExt;
+use tokio::{
+    io::{AsyncRead, AsyncWrite},
+    net::{TcpListener, TcpStream},
+    prelude::*,
+    stream::StreamExt,
+    sync::broadcast,
+};
+use tokio_util::codec::{length_delimited, LengthDelimitedCodec};
+
+use crate::{
+    codec::{Reader, Writer},
+    config::ClientConfig,
+    messaging::Address,
+    remote::{cluster::Cluster, Message, LENGTH_FIELD_ADJUSTMENT, LENGTH_FIELD_LENGTH, LENGTH_FIELD_OFFSET},
+};
+
+type Handler = dyn Fn(&Message) -> Option<Vec<Bytes>> + Send + Sync;
+
+static CLIENTS: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Clone)]
+enum Acceptor {
+    Plain,
+    #[cfg(feature = "tls")]
+    Tls(tokio_rustls::TlsAcceptor),
+}
+
+pub(crate) struct MockMember {
+    address: SocketAddr,
+    connections: Arc<AtomicUsize>,
+    open_connections: Arc<AtomicUsize>,
+    disconnects: broadcast::Sender<()>,
+}
+
+impl MockMember {
+    pub(crate) async fn start(id: &str) -> Self {
+        Self::start_with(id, |_| None).await
+    }
+
+    pub(crate) async fn start_with<H>(id: &str, handler: H) -> Self
+    where
+        H: Fn(&Message) -> Option<(u16, Bytes)> + Send + Sync + 'static,
+    {
+        Self::start_with_latency(id, Duration::from_secs(0), handler).await
+    }
+
+    pub(crate) async fn start_with_latency<H>(id: &str, latency: Duration, handler: H) -> Self
+    where
+        H: Fn(&Message) -> Option<(u16, Bytes)> + Send + Sync + 'static,
+    {
+        let handler = move |message: &Message| {
+            handler(message).map(|(r#type, payload)| vec![frame(message.id(), r#type, &payload)])
+        };
+        Self::start_with_frames(id, latency, handler).await
+    }
+
+    pub(crate) async fn start_with_frames<H>(id: &str, latency: Duration, handler: H) -> Self
+    where
+        H: Fn(&Message) -> Option<Vec<Bytes>> + Send + Sync + 'static,
+    {
+        Self::start_accepting(id, Acceptor::Plain, latency, handler).await
+    }
+
+    // connections failing the handshake are dropped without being served
+    #[cfg(feature = "tls")]
+    pub(crate) async fn start_secured(id: &str, acceptor: tokio_rustls::TlsAcceptor) -> Self {
+        Self::start_accepting(id, Acceptor::Tls(acceptor), Duration::from_secs(0), |_| None).await
+    }
+
+    async fn start_accepting<H>(id: &str, acceptor: Acceptor, latency: Duration, handler: H) -> Self
+    where
+        H: Fn(&Message) -> Option<Vec<Bytes>> + Send + Sync + 'static,
+    {
+        let mut listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let connections = Arc::new(AtomicUsize::new(0));
+        let open_connections = Arc::new(AtomicUsize::new(0));
+        let (disconnects, _) = broadcast::channel(1);
+
+        let id = id.to_string();
+        let handler: Arc<Handler> = Arc::new(handler);
+        let accepted = connections.clone();
+        let open = open_connections.clone();
+        let disconnected = disconnects.clone();
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                accepted.fetch_add(1, Ordering::SeqCst);
+                open.fetch_add(1, Ordering::SeqCst);
+                let (id, acceptor, handler, open) = (id.clone(), acceptor.clone(), handler.clone(), open.clone());
+                let mut disconnected = disconnected.subscribe();
+                tokio::spawn(async move {
+                    tokio::select! {
+                        _ = accept(stream, acceptor, address, id, latency, handler) => {}
+                        _ = disconnected.recv() => {}
+                    }
+                    open.fetch_sub(1, Ordering::SeqCst);
+                });
+            }
+        });
+
+        MockMember {
+            address,
+            connections,
+            open_connections,
+            disconnects,
+        }
+    }
+
+    pub(crate) fn address(&self) -> SocketAddr {
+        self.address
+    }
+
+    pub(crate) fn connections(&self) -> usize {
+        self.connections.load(Ordering::SeqCst)
+    }
+
+    pub(crate) fn open_connections(&self) -> usize {
+        self.open_connections.load(Ordering::SeqCst)
+    }
+
+    pub(crate) fn disconnect(&self) {
+        let _ = self.disconnects.send(());
+    }
+}
+
+// a single member assigning all partitions, which the returned cluster is connected to
+pub(crate) async fn start_cluster<H>(handler: H) -> (MockMember, Arc<Cluster>)
+where
+    H: Fn(&Message) -> Option<(u16, Bytes)> + Send + Sync + 'static,
+{
+    let member = MockMember::start_with("member", move |message| match message.r#type() {
+        0x8 => Some((0x6C, partitions_payload(271))),
+        _ => handler(message),
+    })
+    .await;
+    let config = ClientConfig::builder().addresses(&[member.address()]).build();
+    let cluster = Cluster::init(&config).await.unwrap();
+    (member, Arc::new(cluster))
+}
+
+async fn accept(
+    stream: TcpStream,
+    acceptor: Acceptor,
+    address: SocketAddr,
+    id: String,
+    latency: Duration,
+    handler: Arc<Handler>,
+) {
+    match acceptor {
+        Acceptor::Plain => serve(stream, address, id, latency, handler).await,
+        #[cfg(feature = "tls")]
+        Acceptor::Tls(acceptor) => {
+            if let Ok(stream) = acceptor.accept(stream).await {
+                serve(stream, address, id, latency, handler).await
+            }
+        }
+    }
+}
+
+async fn serve<S: AsyncRead + AsyncWrite + Unpin>(
+    mut stream: S,
+    address: SocketAddr,
+    id: String,
+    latency: Duration,
+    handler: Arc<Handler>,
+) {
+    let mut protocol = [0u8; 3];
+    if stream.read_exact(&mut protocol).await.is_err() {
+        return;
+    }
+
+    let mut framed = codec().new_framed(stream);
+    while let Some(Ok(bytes)) = framed.next().await {
+        let message = Message::decode(bytes.freeze()).unwrap();
+
+        let frames = match handler(&message) {
+            Some(frames) => frames,
+            None => match message.r#type() {
+                0x2 => vec![frame(message.id(), 0x6B, &authenticate(&message, &address, &id))],
+                0x4 => vec![frame(message.id(), 0x68, &registration_payload())],
+                _ => vec![frame(message.id(), 0x64, &[])],
+            },
+        };
+        if message.r#type() != 0x2 {
+            tokio::time::delay_for(latency).await;
+        }
+        for frame in frames {
+            if framed.send(frame).await.is_err() {
+                return;
+            }
+        }
+    }
+}
+
+pub(crate) fn frame(id: u64, r#type: u16, payload: &[u8]) -> Bytes {
+    frame_with(0xC0, id, r#type, payload)
+}
+
+pub(crate) fn event(id: u64, r#type: u16, payload: &[u8]) -> Bytes {
+    frame_with(0xC1, id, r#type, payload)
+}
+
+pub(crate) fn frame_with(flags: u8, id: u64, r#type: u16, payload: &[u8]) -> Bytes {
+    let writeable = &mut BytesMut::new();
+    1u8.write_to(writeable);
+    flags.write_to(writeable);
+    r#type.write_to(writeable);
+    id.write_to(writeable);
+    (-1i32).write_to(writeable);
+    22u16.write_to(writeable);
+    payload.write_to(writeable);
+    writeable.to_bytes()
+}
+
+// like a real member, a client's identity is echoed once it has one and assigned otherwise, owned by this member
+fn authenticate(message: &Message, address: &SocketAddr, member_id: &str) -> Bytes {
+    let readable = &mut message.payload();
+    let (_username, _password) = (
+        String::read_from(readable).unwrap(),
+        String::read_from(readable).unwrap(),
+    );
+    let id = Option::<String>::read_from(readable)
+        .unwrap()
+        .unwrap_or_else(|| format!("client-{}", CLIENTS.fetch_add(1, Ordering::SeqCst)));
+    let owner_id = Option::<String>::read_from(readable)
+        .unwrap()
+        .unwrap_or_else(|| member_id.to_string());
+    authentication_payload(address, &id, &owner_id)
+}
+
+pub(crate) fn authentication_payload(address: &SocketAddr, id: &str, owner_id: &str) -> Bytes {
+    let writeable = &mut BytesMut::new();
+    0u8.write_to(writeable);
+    Some(Address::from(address)).write_to(writeable);
+    Some(id).write_to(writeable);
+    Some(owner_id).write_to(writeable);
+    1u8.write_to(writeable);
+    "3.12.12".write_to(writeable);
+    Option::<u8>::None.write_to(writeable);
+    writeable.to_bytes()
+}
+
+// the partitions are assigned to a fixed address, so requests fall back to any connected member
+pub(crate) fn partitions_payload(partition_count: i32) -> Bytes {
+    let writeable = &mut BytesMut::new();
+    1u32.write_to(writeable);
+    Address::from(&SocketAddr::from(([127, 0, 0, 1], 5701))).write_to(writeable);
+    let partitions: Vec<i32> = (0..partition_count).collect();
+    partitions.deref().write_to(writeable);
+    1i32.write_to(writeable);
+    writeable.to_bytes()
+}
+
+pub(crate) fn registration_payload() -> Bytes {
+    let writeable = &mut BytesMut::new();
+    "registration-id".write_to(writeable);
+    writeable.to_bytes()
+}
+
+pub(crate) fn exception(code: i32, class_name: &str) -> Bytes {
+    let writeable = &mut BytesMut::new();
+    code.write_to(writeable);
+    class_name.write_to(writeable);
+    Some("null").write_to(writeable);
+    0u32.write_to(writeable);
+    0u32.write_to(writeable);
+    Option::<&str>::None.write_to(writeable);
+    writeable.to_bytes()
+}
+
+fn codec() -> length_delimited::Builder {
+    let mut builder = LengthDelimitedCodec::builder();
+    builder
+        .length_field_offset(LENGTH_FIELD_OFFSET)
+        .length_field_length(LENGTH_FIELD_LENGTH)
+        .length_adjustment(LENGTH_FIELD_ADJUSTMENT)
+        .little_endian();
+    builder
+}
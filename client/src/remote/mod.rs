@@ -1,25 +1,120 @@
-use std::convert::TryInto;
+use std::{convert::TryInto, time::Duration};
 
 use bytes::{Buf, Bytes, BytesMut};
+use log::trace;
 
 use crate::{
+    codec::{DecodeError, DecodeResult},
     messaging::{Request, Response},
     HazelcastClientError, TryFrom,
 };
 
+#[cfg(feature = "bench")]
+pub mod bench;
 mod channel;
 pub(crate) mod cluster;
+mod fragment;
 mod member;
+#[cfg(test)]
+pub(crate) mod mock;
+mod partition;
+mod transport;
 
 const PROTOCOL_SEQUENCE: [u8; 3] = [0x43, 0x42, 0x32];
 
+// smart clients connect to every member and send invocations to the owner of their partition, while unisocket
+// clients (e.g. behind a load balancer) keep a single connection and let that member route invocations
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum RoutingMode {
+    Smart,
+    Unisocket,
+}
+
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct Timeouts {
+    pub(crate) dispatch: Duration,
+    pub(crate) forward: Duration,
+}
+
+impl Default for Timeouts {
+    fn default() -> Self {
+        Timeouts {
+            dispatch: Duration::from_secs(120),
+            forward: Duration::from_secs(60),
+        }
+    }
+}
+
+// a member not answering a ping in time is considered dead and left to the reconnector
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) struct Heartbeat {
+    pub(crate) interval: Duration,
+    pub(crate) timeout: Duration,
+}
+
+impl Default for Heartbeat {
+    fn default() -> Self {
+        Heartbeat {
+            interval: Duration::from_secs(5),
+            timeout: Duration::from_secs(60),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) struct Backoff {
+    pub(crate) base: Duration,
+    pub(crate) max: Duration,
+}
+
+impl Backoff {
+    // the delay doubles with every failed attempt, up to the maximum
+    pub(crate) fn delay(&self, failed_attempts: u32) -> Duration {
+        let factor = 2u32.checked_pow(failed_attempts.saturating_sub(1)).unwrap_or(u32::MAX);
+        self.base
+            .checked_mul(factor)
+            .map_or(self.max, |delay| delay.min(self.max))
+    }
+}
+
+impl Default for Backoff {
+    fn default() -> Self {
+        Backoff {
+            base: Duration::from_secs(1),
+            max: Duration::from_secs(30),
+        }
+    }
+}
+
+// invocations failing with a retryable server error are repeated up to the count, after a growing delay
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) struct Retries {
+    pub(crate) count: u32,
+    pub(crate) backoff: Backoff,
+}
+
+impl Default for Retries {
+    fn default() -> Self {
+        Retries {
+            count: 5,
+            backoff: Backoff {
+                base: Duration::from_millis(100),
+                max: Duration::from_secs(2),
+            },
+        }
+    }
+}
+
 const CLIENT_TYPE: &str = "Rust";
-const CLIENT_VERSION: &'static str = env!("CARGO_PKG_VERSION");
+const CLIENT_VERSION: &str = env!("CARGO_PKG_VERSION");
 const PROTOCOL_VERSION: u8 = 1;
 
 const BEGIN_MESSAGE: u8 = 0x80;
 const END_MESSAGE: u8 = 0x40;
 const UNFRAGMENTED_MESSAGE: u8 = BEGIN_MESSAGE | END_MESSAGE;
+const EVENT_MESSAGE: u8 = 0x01;
+
+pub(crate) const DEFAULT_MAX_FRAME_SIZE: usize = 1024 * 1024;
 
 const LENGTH_FIELD_OFFSET: usize = 0;
 const LENGTH_FIELD_LENGTH: usize = 4;
@@ -27,56 +122,68 @@ const LENGTH_FIELD_ADJUSTMENT: isize = -4;
 const HEADER_LENGTH: usize = 22;
 
 #[derive(Eq, PartialEq, Debug)]
-struct Message(u64, u16, Bytes);
+pub(crate) struct Message(u64, u8, u16, Bytes);
 
 impl Message {
-    fn id(&self) -> u64 {
+    pub(crate) fn id(&self) -> u64 {
         self.0
     }
 
-    fn r#type(&self) -> u16 {
-        self.1
+    fn is_event(&self) -> bool {
+        self.1 & EVENT_MESSAGE != 0
+    }
+
+    pub(crate) fn r#type(&self) -> u16 {
+        self.2
     }
 
-    fn payload(&self) -> Bytes {
-        self.2.clone()
+    pub(crate) fn payload(&self) -> Bytes {
+        self.3.clone()
     }
 }
 
 impl<R: Request> From<(u64, R)> for Message {
     fn from(request: (u64, R)) -> Self {
+        Message::encode(request.0, &request.1)
+    }
+}
+
+impl Message {
+    // the request is only borrowed, so it can be encoded again under a new id when the invocation is retried
+    pub(crate) fn encode<R: Request>(id: u64, request: &R) -> Self {
         use crate::codec::Writer;
 
-        let mut frame = BytesMut::with_capacity(HEADER_LENGTH - LENGTH_FIELD_LENGTH + request.1.length());
+        let mut frame = BytesMut::with_capacity(HEADER_LENGTH - LENGTH_FIELD_LENGTH + request.length());
 
         let data_offset: u16 = HEADER_LENGTH.try_into().expect("unable to convert");
 
         PROTOCOL_VERSION.write_to(&mut frame);
         UNFRAGMENTED_MESSAGE.write_to(&mut frame);
         R::r#type().write_to(&mut frame);
-        request.0.write_to(&mut frame);
-        request.1.partition_id().write_to(&mut frame);
+        id.write_to(&mut frame);
+        request.partition_id().write_to(&mut frame);
         data_offset.write_to(&mut frame);
-        request.1.write_to(&mut frame);
+        request.write_to(&mut frame);
 
-        Message(request.0, R::r#type(), frame.to_bytes())
+        Message(id, UNFRAGMENTED_MESSAGE, R::r#type(), frame.to_bytes())
     }
-}
 
-impl From<Bytes> for Message {
-    fn from(mut frame: Bytes) -> Self {
+    pub(crate) fn decode(mut frame: Bytes) -> DecodeResult<Self> {
         use crate::codec::Readable;
 
-        let _version = frame.read_u8();
-        let _flags = frame.read_u8();
-        let message_type = frame.read_u16();
-        let correlation_id = frame.read_u64();
-        let _partition_id = frame.read_i32();
+        let _version = frame.read_u8()?;
+        let flags = frame.read_u8()?;
+        let message_type = frame.read_u16()?;
+        let correlation_id = frame.read_u64()?;
+        let _partition_id = frame.read_i32()?;
 
-        let data_offset: usize = frame.read_u16().try_into().expect("unable to convert!");
-        frame.skip(data_offset - HEADER_LENGTH);
+        let data_offset: usize = frame.read_u16()?.into();
+        let header_extension = data_offset
+            .checked_sub(HEADER_LENGTH)
+            .ok_or_else(|| DecodeError::new("data offset within header"))?;
+        frame.skip(header_extension)?;
 
-        Message(correlation_id, message_type, frame.to_bytes())
+        Ok(Message(correlation_id, flags, message_type, frame.to_bytes()))
     }
 }
 
@@ -85,24 +192,35 @@ impl<R: Response> TryFrom<R> for Message {
 
     fn try_from(self) -> Result<R, Self::Error> {
         use crate::codec::Reader;
-        use crate::messaging::error::Exception;
+        use crate::messaging::error::{Exception, CONSISTENCY_LOST};
 
         let r#type = self.r#type();
         let mut readable = self.payload();
 
         if r#type == R::r#type() {
-            Ok(R::read_from(&mut readable))
+            let response = R::read_from(&mut readable)?;
+            if readable.has_remaining() {
+                trace!(
+                    "Ignoring {} trailing bytes of message (id: {}, type: {:#06x}).",
+                    readable.remaining(),
+                    self.id(),
+                    r#type
+                );
+            }
+            Ok(response)
+        } else if r#type == Exception::r#type() {
+            let exception = Exception::read_from(&mut readable)?;
+            match exception.code() {
+                CONSISTENCY_LOST => Err(HazelcastClientError::ConsistencyLost(Box::new(exception))),
+                _ => Err(HazelcastClientError::ServerFailure(Box::new(exception))),
+            }
         } else {
-            assert_eq!(
-                r#type,
-                Exception::r#type(),
-                "unknown messaging type: {}, expected: {}",
+            Err(DecodeError::new(&format!(
+                "unknown message type: {:#06x}, expected: {:#06x}",
                 r#type,
                 R::r#type()
-            );
-            Err(HazelcastClientError::ServerFailure(Box::new(Exception::read_from(
-                &mut readable,
-            ))))
+            ))
+            .into())
         }
     }
 }
@@ -113,6 +231,20 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn should_double_backoff_delay_up_to_maximum() {
+        let backoff = Backoff {
+            base: Duration::from_millis(100),
+            max: Duration::from_secs(1),
+        };
+
+        assert_eq!(backoff.delay(1), Duration::from_millis(100));
+        assert_eq!(backoff.delay(2), Duration::from_millis(200));
+        assert_eq!(backoff.delay(4), Duration::from_millis(800));
+        assert_eq!(backoff.delay(5), Duration::from_secs(1));
+        assert_eq!(backoff.delay(64), Duration::from_secs(1));
+    }
+
     #[test]
     fn should_convert_to_message_from_request() {
         let id = 1;
@@ -147,12 +279,105 @@ mod tests {
             2, // payload
         ]);
 
-        let message: Message = bytes.into();
+        let message = Message::decode(bytes).unwrap();
         assert_eq!(message.id(), 1);
+        assert!(!message.is_event());
         assert_eq!(message.r#type(), 0x69);
         assert_eq!(message.payload().bytes(), [2]);
     }
 
+    #[test]
+    fn should_fail_to_convert_bytes_shorter_than_header() {
+        let bytes = Bytes::copy_from_slice(&[1, 192, 0x69, 0, 1, 0, 0, 0]);
+
+        assert!(Message::decode(bytes).is_err());
+    }
+
+    #[test]
+    fn should_convert_truncated_message_to_malformed_message() {
+        let message = Message(
+            1,
+            UNFRAGMENTED_MESSAGE,
+            0x6D,
+            Bytes::copy_from_slice(&[128, 0, 0, 0, 20, 0]),
+        );
+
+        match TryFrom::<SomeResponse>::try_from(message) {
+            Err(HazelcastClientError::MalformedMessage(_)) => {}
+            _ => panic!("expected malformed message!"),
+        }
+    }
+
+    #[test]
+    fn should_convert_exception_message_to_server_failure() {
+        let message = Message(1, UNFRAGMENTED_MESSAGE, 0x6D, exception_payload());
+
+        match TryFrom::<SomeResponse>::try_from(message) {
+            Err(HazelcastClientError::ServerFailure(e)) => assert!(e.to_string().contains("NullPointerException")),
+            _ => panic!("expected server failure!"),
+        }
+    }
+
+    #[test]
+    fn should_convert_consistency_lost_message_to_consistency_lost() {
+        use crate::{messaging::error::CONSISTENCY_LOST, remote::mock::exception};
+
+        let payload = exception(CONSISTENCY_LOST, "ConsistencyLostException");
+        let message = Message(1, UNFRAGMENTED_MESSAGE, 0x6D, payload);
+
+        match TryFrom::<SomeResponse>::try_from(message) {
+            Err(HazelcastClientError::ConsistencyLost(e)) => {
+                assert!(e.to_string().contains("ConsistencyLostException"))
+            }
+            _ => panic!("expected consistency lost!"),
+        }
+    }
+
+    #[test]
+    fn should_convert_message_to_response() {
+        let message = Message(
+            1,
+            UNFRAGMENTED_MESSAGE,
+            SomeResponse::r#type(),
+            Bytes::copy_from_slice(&[2]),
+        );
+
+        let response: Result<SomeResponse, HazelcastClientError> = TryFrom::try_from(message);
+        assert_eq!(response.unwrap(), SomeResponse { field: 2 });
+    }
+
+    #[test]
+    fn should_ignore_trailing_bytes_of_response() {
+        use crate::{codec::Writer, messaging::pn_counter::PnCounterGetResponse};
+
+        let writeable = &mut BytesMut::new();
+        12i64.write_to(writeable);
+        1u32.write_to(writeable);
+        "key".write_to(writeable);
+        69i64.write_to(writeable);
+        "field-added-by-newer-server".write_to(writeable);
+        let message = Message(
+            1,
+            UNFRAGMENTED_MESSAGE,
+            PnCounterGetResponse::r#type(),
+            writeable.to_bytes(),
+        );
+
+        let response: PnCounterGetResponse = TryFrom::try_from(message).unwrap();
+        assert_eq!(response.value(), 12);
+        assert_eq!(response.replica_timestamps().len(), 1);
+    }
+
+    fn exception_payload() -> Bytes {
+        crate::remote::mock::exception(128, "NullPointerException")
+    }
+
+    #[derive(Response, Eq, PartialEq, Debug)]
+    #[r#type = 0x70]
+    struct SomeResponse {
+        field: u8,
+    }
+
     #[derive(Request, Eq, PartialEq, Debug)]
     #[r#type = 0x69]
     struct SomeRequest {
@@ -0,0 +1,58 @@
+// mirrors HashUtil.hashToIndex on the member side: negative hashes are negated rather than masked, so keys land on
+// the partition the member expects, and i32::MIN, which has no positive counterpart, is pinned to the first partition
+pub(in crate::remote) fn partition_id(partition_hash: i32, partition_count: i32) -> i32 {
+    match partition_hash {
+        i32::MIN => 0,
+        hash => hash.abs() % partition_count,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::serialization::{serialize_string, Data};
+
+    use super::*;
+
+    #[test]
+    fn should_map_partition_hash_to_partition_id() {
+        assert_eq!(partition_id(42, 271), 42);
+        assert_eq!(partition_id(42, 10), 2);
+        assert_eq!(partition_id(-42, 10), 2);
+        assert_eq!(partition_id(i32::MIN, 271), 0);
+        assert_eq!(partition_id(i32::MAX, 271), i32::MAX % 271);
+    }
+
+    #[test]
+    fn should_negate_rather_than_mask_negative_partition_hash() {
+        let hash = -1_575_444_155;
+
+        assert_eq!(partition_id(hash, 271), 18);
+        assert_ne!(partition_id(hash, 271), (hash & i32::MAX) % 271);
+    }
+
+    #[test]
+    fn should_map_string_keys_to_known_partitions() {
+        for &(key, hash, expected) in &[
+            ("key", 1_476_017_569, 80),
+            ("b", 980_514_167, 124),
+            ("c", -1_575_444_155, 18),
+            ("counter", -317_898_450, 3),
+            ("queue", -1_505_431_440, 153),
+        ] {
+            let data = Data::from(serialize_string(key));
+            assert_eq!(data.partition_hash(), hash);
+            assert_eq!(partition_id(data.partition_hash(), 271), expected);
+        }
+    }
+
+    #[test]
+    fn should_map_key_to_stable_partition_id() {
+        let key = Data::from(vec![0, 0, 0, 0, 0xff, 0xff, 0xff, 0xf5, 0, 0, 0, 3, b'k', b'e', b'y']);
+
+        let expected = partition_id(key.partition_hash(), 271);
+        assert!((0..271).contains(&expected));
+        for _ in 0..3 {
+            assert_eq!(partition_id(key.partition_hash(), 271), expected);
+        }
+    }
+}
@@ -0,0 +1,190 @@
+use std::io;
+#[cfg(feature = "tls")]
+use std::{fs::File, io::BufReader, sync::Arc};
+
+#[cfg(feature = "tls")]
+use tokio_rustls::{
+    client::TlsStream,
+    rustls::{self, internal::pemfile},
+    webpki::DNSNameRef,
+    TlsConnector,
+};
+
+#[cfg(feature = "tls")]
+use crate::config::TlsConfig;
+use crate::{
+    config::ClientConfig,
+    runtime::{DefaultRuntime, Runtime},
+};
+
+type Stream = <DefaultRuntime as Runtime>::TcpStream;
+
+pub(in crate::remote) enum Secured {
+    Plain(Stream),
+    #[cfg(feature = "tls")]
+    Tls(Box<TlsStream<Stream>>),
+}
+
+// settles how the connections to members are secured, shared by every connection the client opens
+#[derive(Clone, Default)]
+pub(in crate::remote) struct Transport {
+    #[cfg(feature = "tls")]
+    tls: Option<(TlsConnector, String)>,
+}
+
+impl Transport {
+    // certificates are loaded once, so a misconfiguration fails the client start instead of every connection attempt
+    #[cfg_attr(not(feature = "tls"), allow(unused_variables))]
+    pub(in crate::remote) fn new(config: &ClientConfig) -> io::Result<Self> {
+        Ok(Transport {
+            #[cfg(feature = "tls")]
+            tls: match &config.tls {
+                Some(tls) => Some((connector(tls)?, tls.server_name.clone())),
+                None => None,
+            },
+        })
+    }
+
+    pub(in crate::remote) async fn secure(&self, stream: Stream) -> io::Result<Secured> {
+        #[cfg(feature = "tls")]
+        {
+            if let Some((connector, server_name)) = &self.tls {
+                let server_name = DNSNameRef::try_from_ascii_str(server_name).map_err(|e| invalid(e.to_string()))?;
+                return Ok(Secured::Tls(Box::new(connector.connect(server_name, stream).await?)));
+            }
+        }
+        Ok(Secured::Plain(stream))
+    }
+}
+
+#[cfg(feature = "tls")]
+fn connector(config: &TlsConfig) -> io::Result<TlsConnector> {
+    let mut tls = rustls::ClientConfig::new();
+    let (trusted, _) = tls
+        .root_store
+        .add_pem_file(&mut BufReader::new(File::open(&config.ca_certificate)?))
+        .map_err(|_| invalid(format!("malformed CA certificate {:?}", config.ca_certificate)))?;
+    if trusted == 0 {
+        return Err(invalid(format!("no CA certificate in {:?}", config.ca_certificate)));
+    }
+    if let Some((certificate, key)) = &config.client_certificate {
+        let chain = pemfile::certs(&mut BufReader::new(File::open(certificate)?))
+            .map_err(|_| invalid(format!("malformed client certificate {:?}", certificate)))?;
+        let key = pemfile::pkcs8_private_keys(&mut BufReader::new(File::open(key)?))
+            .ok()
+            .and_then(|mut keys| keys.pop())
+            .ok_or_else(|| invalid(format!("no PKCS#8 private key in {:?}", key)))?;
+        tls.set_single_client_cert(chain, key)
+            .map_err(|e| invalid(e.to_string()))?;
+    }
+    Ok(TlsConnector::from(Arc::new(tls)))
+}
+
+#[cfg(feature = "tls")]
+fn invalid(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message)
+}
+
+#[cfg(all(test, feature = "tls"))]
+mod tests {
+    use std::path::PathBuf;
+
+    use tokio_rustls::{
+        rustls::{AllowAnyAuthenticatedClient, NoClientAuth, RootCertStore, ServerConfig},
+        TlsAcceptor,
+    };
+
+    use crate::{
+        config::TlsConfig,
+        messaging::{
+            topic::{TopicPublishRequest, TopicPublishResponse},
+            Address,
+        },
+        remote::{member::Member, mock::MockMember, DEFAULT_MAX_FRAME_SIZE},
+        Result,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn should_authenticate_and_send_over_tls() {
+        let mock = MockMember::start_secured("member", acceptor(false)).await;
+        let tls = TlsConfig::builder(certificate("ca.pem"), "localhost").build();
+
+        let member = connect(&mock, tls).await.unwrap();
+
+        assert_eq!(member.address(), &Address::from(&mock.address()));
+        let _: TopicPublishResponse = member.send(&TopicPublishRequest::new("topic", &[1], 0)).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn should_authenticate_over_mutual_tls() {
+        let member = MockMember::start_secured("member", acceptor(true)).await;
+        let tls = TlsConfig::builder(certificate("ca.pem"), "localhost")
+            .client_certificate(certificate("client.pem"), certificate("client.key"))
+            .build();
+
+        assert_eq!(
+            connect(&member, tls).await.unwrap().address(),
+            &Address::from(&member.address())
+        );
+    }
+
+    #[tokio::test]
+    async fn should_fail_to_connect_without_client_certificate_required_by_member() {
+        let member = MockMember::start_secured("member", acceptor(true)).await;
+        let tls = TlsConfig::builder(certificate("ca.pem"), "localhost").build();
+
+        assert!(connect(&member, tls).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn should_fail_to_connect_to_member_with_certificate_for_other_name() {
+        let member = MockMember::start_secured("member", acceptor(false)).await;
+        let tls = TlsConfig::builder(certificate("ca.pem"), "member.example.com").build();
+
+        assert!(connect(&member, tls).await.is_err());
+    }
+
+    #[test]
+    fn should_reject_missing_ca_certificate() {
+        let tls = TlsConfig::builder(certificate("missing.pem"), "localhost").build();
+
+        assert!(Transport::new(&ClientConfig::builder().tls(tls).build()).is_err());
+    }
+
+    async fn connect(member: &MockMember, tls: TlsConfig) -> Result<Member> {
+        let transport = Transport::new(&ClientConfig::builder().tls(tls).build()).unwrap();
+        Member::connect(
+            &member.address(),
+            &transport,
+            "dev",
+            "dev-pass",
+            None,
+            None,
+            DEFAULT_MAX_FRAME_SIZE,
+        )
+        .await
+    }
+
+    fn acceptor(client_authentication: bool) -> TlsAcceptor {
+        let mut config = if client_authentication {
+            let mut roots = RootCertStore::empty();
+            roots
+                .add_pem_file(&mut BufReader::new(File::open(certificate("ca.pem")).unwrap()))
+                .unwrap();
+            ServerConfig::new(AllowAnyAuthenticatedClient::new(roots))
+        } else {
+            ServerConfig::new(NoClientAuth::new())
+        };
+        let chain = pemfile::certs(&mut BufReader::new(File::open(certificate("server.pem")).unwrap())).unwrap();
+        let mut keys =
+            pemfile::pkcs8_private_keys(&mut BufReader::new(File::open(certificate("server.key")).unwrap())).unwrap();
+        config.set_single_cert(chain, keys.remove(0)).unwrap();
+        TlsAcceptor::from(Arc::new(config))
+    }
+
+    fn certificate(name: &str) -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/certs").join(name)
+    }
+}
@@ -0,0 +1,39 @@
+//! Abstraction over the async runtime used to spawn background tasks, drive timers and open TCP connections.
+//!
+//! The tokio implementation is enabled by the default `runtime-tokio` feature, which is also what pulls in tokio's
+//! executor, networking and timers. [`Runtime`] is internal to the crate, so another runtime is plugged in here rather
+//! than by applications: implement the trait for it behind its own feature and point [`DefaultRuntime`] at it. Its
+//! streams have to implement tokio's `AsyncRead`/`AsyncWrite` (e.g. through a compatibility adapter), as the wire
+//! codecs are built on `tokio-util`. Channels from `tokio::sync` are executor agnostic and are used regardless of the
+//! runtime.
+
+use std::{future::Future, io, net::SocketAddr, time::Duration};
+
+use futures::{future::BoxFuture, stream::BoxStream};
+use tokio::io::{AsyncRead, AsyncWrite};
+
+#[cfg(feature = "runtime-tokio")]
+mod tokio_runtime;
+
+#[cfg(feature = "runtime-tokio")]
+pub(crate) type DefaultRuntime = tokio_runtime::TokioRuntime;
+
+#[cfg(not(feature = "runtime-tokio"))]
+compile_error!("no async runtime selected, enable the `runtime-tokio` feature");
+
+pub(crate) trait Runtime {
+    type TcpStream: AsyncRead + AsyncWrite + Send + Unpin + 'static;
+
+    fn spawn<F>(future: F)
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static;
+
+    fn connect(address: &SocketAddr) -> BoxFuture<'_, io::Result<Self::TcpStream>>;
+
+    fn resolve(host: &str, port: u16) -> BoxFuture<'_, io::Result<Vec<SocketAddr>>>;
+
+    fn delay(duration: Duration) -> BoxFuture<'static, ()>;
+
+    fn interval(period: Duration) -> BoxStream<'static, ()>;
+}
@@ -0,0 +1,104 @@
+use std::{future::Future, io, net::SocketAddr, time::Duration};
+
+use futures::{future::BoxFuture, stream::BoxStream, FutureExt, StreamExt};
+use tokio::net::TcpStream;
+
+use crate::runtime::Runtime;
+
+pub(crate) struct TokioRuntime;
+
+impl Runtime for TokioRuntime {
+    type TcpStream = TcpStream;
+
+    fn spawn<F>(future: F)
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        tokio::spawn(future);
+    }
+
+    fn connect(address: &SocketAddr) -> BoxFuture<'_, io::Result<Self::TcpStream>> {
+        TcpStream::connect(address).boxed()
+    }
+
+    fn resolve(host: &str, port: u16) -> BoxFuture<'_, io::Result<Vec<SocketAddr>>> {
+        tokio::net::lookup_host((host, port))
+            .map(|addresses| addresses.map(|addresses| addresses.collect()))
+            .boxed()
+    }
+
+    fn delay(duration: Duration) -> BoxFuture<'static, ()> {
+        tokio::time::delay_for(duration).boxed()
+    }
+
+    fn interval(period: Duration) -> BoxStream<'static, ()> {
+        tokio::time::interval(period).map(|_| ()).boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::{
+        net::TcpListener,
+        prelude::*,
+        sync::oneshot,
+        time::{timeout, Instant},
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn should_spawn() {
+        let (sender, receiver) = oneshot::channel();
+
+        TokioRuntime::spawn(async move { sender.send(69) });
+
+        assert_eq!(receiver.await, Ok(69));
+    }
+
+    #[tokio::test]
+    async fn should_connect() {
+        let mut listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+
+        let mut stream = TokioRuntime::connect(&address).await.unwrap();
+        stream.write_all(&[1, 2, 3]).await.unwrap();
+
+        let (mut accepted, _) = listener.accept().await.unwrap();
+        let mut buffer = [0u8; 3];
+        accepted.read_exact(&mut buffer).await.unwrap();
+        assert_eq!(buffer, [1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn should_resolve() {
+        let addresses = TokioRuntime::resolve("localhost", 5701).await.unwrap();
+
+        assert!(!addresses.is_empty());
+        assert!(addresses
+            .iter()
+            .all(|address| address.ip().is_loopback() && address.port() == 5701));
+    }
+
+    #[tokio::test]
+    async fn should_delay() {
+        let duration = Duration::from_millis(10);
+        let start = Instant::now();
+
+        TokioRuntime::delay(duration).await;
+
+        assert!(start.elapsed() >= duration);
+    }
+
+    #[tokio::test]
+    async fn should_tick() {
+        let period = Duration::from_millis(10);
+        let start = Instant::now();
+
+        let ticks = TokioRuntime::interval(period).take(3).collect::<Vec<()>>();
+
+        assert_eq!(timeout(Duration::from_secs(1), ticks).await.unwrap().len(), 3);
+        assert!(start.elapsed() >= 2 * period);
+    }
+}
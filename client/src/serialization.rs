@@ -0,0 +1,957 @@
+use std::{any::TypeId, collections::HashMap, convert::TryInto};
+
+use bigdecimal::BigDecimal;
+#[cfg(feature = "temporal")]
+use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+use num_bigint::BigInt;
+
+use crate::{
+    HazelcastClientError::{DeserializationFailure, SerializationFailure},
+    Result,
+};
+
+const HEADER_LENGTH: usize = 8;
+const PARTITION_HASH_OFFSET: usize = 0;
+const TYPE_ID_OFFSET: usize = 4;
+const MURMUR_SEED: u32 = 0x0100_0193;
+const IDENTIFIED_DATA_SERIALIZABLE_TYPE_ID: i32 = -2;
+const BOOLEAN_TYPE_ID: i32 = -4;
+const INTEGER_TYPE_ID: i32 = -7;
+const LONG_TYPE_ID: i32 = -8;
+const DOUBLE_TYPE_ID: i32 = -10;
+const STRING_TYPE_ID: i32 = -11;
+const BYTE_ARRAY_TYPE_ID: i32 = -12;
+const BIG_INTEGER_TYPE_ID: i32 = -23;
+const BIG_DECIMAL_TYPE_ID: i32 = -24;
+const JSON_TYPE_ID: i32 = -130;
+#[cfg(feature = "temporal")]
+const LOCAL_DATE_TYPE_ID: i32 = -51;
+#[cfg(feature = "temporal")]
+const LOCAL_TIME_TYPE_ID: i32 = -52;
+#[cfg(feature = "temporal")]
+const LOCAL_DATE_TIME_TYPE_ID: i32 = -53;
+
+/// A serialized value, as produced by [`ToData`], along with what its header tells about it.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Data(Vec<u8>);
+
+impl Data {
+    pub fn bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    pub fn type_id(&self) -> i32 {
+        self.header_field(TYPE_ID_OFFSET)
+    }
+
+    // members hash the payload themselves unless the serializer settled the partition hash up front
+    pub fn partition_hash(&self) -> i32 {
+        match self.header_field(PARTITION_HASH_OFFSET) {
+            0 if self.0.len() >= HEADER_LENGTH => murmur3_x86_32(&self.0[HEADER_LENGTH..], MURMUR_SEED) as i32,
+            hash => hash,
+        }
+    }
+
+    fn header_field(&self, offset: usize) -> i32 {
+        match self.0.get(offset..offset + 4) {
+            Some(field) => i32::from_be_bytes(field.try_into().expect("unable to convert!")),
+            None => 0,
+        }
+    }
+}
+
+impl From<Vec<u8>> for Data {
+    fn from(bytes: Vec<u8>) -> Self {
+        Data(bytes)
+    }
+}
+
+impl From<&[u8]> for Data {
+    fn from(bytes: &[u8]) -> Self {
+        Data(bytes.to_vec())
+    }
+}
+
+fn murmur3_x86_32(data: &[u8], seed: u32) -> u32 {
+    const C1: u32 = 0xcc9e_2d51;
+    const C2: u32 = 0x1b87_3593;
+
+    let mut hash = seed;
+    let mut blocks = data.chunks_exact(4);
+    for block in &mut blocks {
+        let mut k = u32::from_le_bytes(block.try_into().expect("unable to convert!"));
+        k = k.wrapping_mul(C1).rotate_left(15).wrapping_mul(C2);
+        hash ^= k;
+        hash = hash.rotate_left(13).wrapping_mul(5).wrapping_add(0xe654_6b64);
+    }
+
+    let tail = blocks.remainder();
+    if !tail.is_empty() {
+        let mut k = 0u32;
+        for (i, byte) in tail.iter().enumerate() {
+            k ^= u32::from(*byte) << (8 * i);
+        }
+        k = k.wrapping_mul(C1).rotate_left(15).wrapping_mul(C2);
+        hash ^= k;
+    }
+
+    hash ^= data.len() as u32;
+    hash ^= hash >> 16;
+    hash = hash.wrapping_mul(0x85eb_ca6b);
+    hash ^= hash >> 13;
+    hash = hash.wrapping_mul(0xc2b2_ae35);
+    hash ^ (hash >> 16)
+}
+
+pub trait IntEnum: Sized {
+    const TYPE_ID: i32;
+
+    fn discriminant(&self) -> i32;
+
+    fn from_discriminant(discriminant: i32) -> Option<Self>;
+}
+
+pub(crate) fn serialize_string(value: &str) -> Vec<u8> {
+    let mut output = DataOutput::with_header(STRING_TYPE_ID);
+    output.write_string(value);
+    output.into_bytes()
+}
+
+// fields are written big-endian, the way members read them back
+#[derive(Default, Debug)]
+pub struct DataOutput {
+    bytes: Vec<u8>,
+}
+
+impl DataOutput {
+    fn with_header(type_id: i32) -> Self {
+        let mut output = DataOutput::default();
+        output.write_i32(0);
+        output.write_i32(type_id);
+        output
+    }
+
+    pub fn write_bool(&mut self, value: bool) {
+        self.bytes.push(value as u8);
+    }
+
+    pub fn write_i32(&mut self, value: i32) {
+        self.bytes.extend_from_slice(&value.to_be_bytes());
+    }
+
+    pub fn write_i64(&mut self, value: i64) {
+        self.bytes.extend_from_slice(&value.to_be_bytes());
+    }
+
+    pub fn write_f64(&mut self, value: f64) {
+        self.bytes.extend_from_slice(&value.to_be_bytes());
+    }
+
+    // like Java, the length counts UTF-16 code units and each unit is encoded on its own, so characters beyond
+    // the basic multilingual plane take two 3-byte sequences, one per surrogate
+    pub fn write_string(&mut self, value: &str) {
+        self.write_i32(value.encode_utf16().count().try_into().unwrap_or(i32::MAX));
+        for unit in value.encode_utf16() {
+            match unit {
+                0..=0x7F => self.bytes.push(unit as u8),
+                0x80..=0x7FF => self
+                    .bytes
+                    .extend_from_slice(&[0xC0 | (unit >> 6) as u8, 0x80 | (unit & 0x3F) as u8]),
+                _ => self.bytes.extend_from_slice(&[
+                    0xE0 | (unit >> 12) as u8,
+                    0x80 | ((unit >> 6) & 0x3F) as u8,
+                    0x80 | (unit & 0x3F) as u8,
+                ]),
+            }
+        }
+    }
+
+    pub fn write_bytes(&mut self, value: &[u8]) {
+        self.write_i32(value.len().try_into().unwrap_or(i32::MAX));
+        self.bytes.extend_from_slice(value);
+    }
+
+    #[cfg(feature = "temporal")]
+    fn write_date(&mut self, value: &NaiveDate) {
+        self.write_i32(value.year());
+        self.bytes.extend_from_slice(&[value.month() as u8, value.day() as u8]);
+    }
+
+    #[cfg(feature = "temporal")]
+    fn write_time(&mut self, value: &NaiveTime) {
+        self.bytes
+            .extend_from_slice(&[value.hour() as u8, value.minute() as u8, value.second() as u8]);
+        self.write_i32(value.nanosecond().try_into().unwrap_or(i32::MAX));
+    }
+
+    fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+}
+
+#[derive(Debug)]
+pub struct DataInput<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> DataInput<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        DataInput { bytes }
+    }
+
+    fn with_header(bytes: &'a [u8], type_id: i32) -> Result<Self> {
+        let mut input = DataInput::new(bytes);
+        let _partition_hash = input.read_i32()?;
+        let actual_type_id = input.read_i32()?;
+        if actual_type_id != type_id {
+            return Err(DeserializationFailure(format!(
+                "expected type id {} but got {}",
+                type_id, actual_type_id
+            )));
+        }
+        Ok(input)
+    }
+
+    pub fn read_bool(&mut self) -> Result<bool> {
+        Ok(self.read_slice(1)?[0] != 0)
+    }
+
+    pub fn read_i32(&mut self) -> Result<i32> {
+        Ok(i32::from_be_bytes(
+            self.read_slice(4)?.try_into().expect("unable to convert!"),
+        ))
+    }
+
+    pub fn read_i64(&mut self) -> Result<i64> {
+        Ok(i64::from_be_bytes(
+            self.read_slice(8)?.try_into().expect("unable to convert!"),
+        ))
+    }
+
+    pub fn read_f64(&mut self) -> Result<f64> {
+        Ok(f64::from_be_bytes(
+            self.read_slice(8)?.try_into().expect("unable to convert!"),
+        ))
+    }
+
+    // surrogates arrive as separate code units and are only paired up once the whole string is read
+    pub fn read_string(&mut self) -> Result<String> {
+        let length = self.read_i32()?;
+        let mut units = Vec::new();
+        for _ in 0..length {
+            let lead = self.read_slice(1)?[0];
+            let unit = match lead {
+                0x00..=0x7F => u16::from(lead),
+                0xC0..=0xDF => u16::from(lead & 0x1F) << 6 | self.read_continuation()?,
+                0xE0..=0xEF => {
+                    u16::from(lead & 0x0F) << 12 | self.read_continuation()? << 6 | self.read_continuation()?
+                }
+                _ => return Err(DeserializationFailure(format!("invalid lead byte {:#04x}", lead))),
+            };
+            units.push(unit);
+        }
+        String::from_utf16(&units).map_err(|e| DeserializationFailure(format!("invalid string ({})", e)))
+    }
+
+    fn read_continuation(&mut self) -> Result<u16> {
+        match self.read_slice(1)?[0] {
+            byte if byte & 0xC0 == 0x80 => Ok(u16::from(byte & 0x3F)),
+            byte => Err(DeserializationFailure(format!(
+                "invalid continuation byte {:#04x}",
+                byte
+            ))),
+        }
+    }
+
+    pub fn read_bytes(&mut self) -> Result<Vec<u8>> {
+        let length = self.read_i32()?;
+        let length = length
+            .try_into()
+            .map_err(|_| DeserializationFailure(format!("invalid length {}", length)))?;
+        Ok(self.read_slice(length)?.to_vec())
+    }
+
+    #[cfg(feature = "temporal")]
+    fn read_date(&mut self) -> Result<NaiveDate> {
+        let year = self.read_i32()?;
+        let (month, day) = (self.read_slice(1)?[0], self.read_slice(1)?[0]);
+        NaiveDate::from_ymd_opt(year, month.into(), day.into())
+            .ok_or_else(|| DeserializationFailure(format!("invalid date {}-{}-{}", year, month, day)))
+    }
+
+    #[cfg(feature = "temporal")]
+    fn read_time(&mut self) -> Result<NaiveTime> {
+        let (hour, minute, second) = (self.read_slice(1)?[0], self.read_slice(1)?[0], self.read_slice(1)?[0]);
+        let nanosecond = self.read_i32()?;
+        nanosecond
+            .try_into()
+            .ok()
+            .and_then(|nanosecond| NaiveTime::from_hms_nano_opt(hour.into(), minute.into(), second.into(), nanosecond))
+            .ok_or_else(|| {
+                DeserializationFailure(format!("invalid time {}:{}:{}.{}", hour, minute, second, nanosecond))
+            })
+    }
+
+    fn read_slice(&mut self, length: usize) -> Result<&'a [u8]> {
+        if self.bytes.len() < length {
+            return Err(DeserializationFailure(format!(
+                "expected {} more bytes but got {}",
+                length,
+                self.bytes.len()
+            )));
+        }
+        let (slice, remaining) = self.bytes.split_at(length);
+        self.bytes = remaining;
+        Ok(slice)
+    }
+}
+
+pub trait IdentifiedDataSerializable {
+    fn factory_id(&self) -> i32;
+
+    fn class_id(&self) -> i32;
+
+    fn write_data(&self, output: &mut DataOutput);
+
+    fn read_data(&mut self, input: &mut DataInput) -> Result<()>;
+}
+
+// values are only read back into the type registered for their factory and class ids
+#[derive(Default, Debug)]
+pub struct DataSerializer {
+    classes: HashMap<(i32, i32), TypeId>,
+}
+
+impl DataSerializer {
+    pub fn new() -> Self {
+        DataSerializer::default()
+    }
+
+    pub fn register<T: IdentifiedDataSerializable + Default + 'static>(mut self) -> Self {
+        let value = T::default();
+        self.classes
+            .insert((value.factory_id(), value.class_id()), TypeId::of::<T>());
+        self
+    }
+}
+
+#[derive(Debug)]
+pub struct SerializationService {
+    serializer: DataSerializer,
+}
+
+impl SerializationService {
+    pub fn new(serializer: DataSerializer) -> Self {
+        SerializationService { serializer }
+    }
+
+    pub fn serialize<T: IdentifiedDataSerializable>(&self, value: &T) -> Vec<u8> {
+        let mut output = DataOutput::with_header(IDENTIFIED_DATA_SERIALIZABLE_TYPE_ID);
+        output.write_bool(true);
+        output.write_i32(value.factory_id());
+        output.write_i32(value.class_id());
+        value.write_data(&mut output);
+        output.into_bytes()
+    }
+
+    pub fn deserialize<T: IdentifiedDataSerializable + Default + 'static>(&self, bytes: &[u8]) -> Result<T> {
+        let mut input = DataInput::with_header(bytes, IDENTIFIED_DATA_SERIALIZABLE_TYPE_ID)?;
+        if !input.read_bool()? {
+            return Err(DeserializationFailure(
+                "expected identified data serializable".to_string(),
+            ));
+        }
+
+        let (factory_id, class_id) = (input.read_i32()?, input.read_i32()?);
+        match self.serializer.classes.get(&(factory_id, class_id)) {
+            Some(type_id) if *type_id == TypeId::of::<T>() => {
+                let mut value = T::default();
+                value.read_data(&mut input)?;
+                Ok(value)
+            }
+            Some(_) => Err(DeserializationFailure(format!(
+                "factory id {} and class id {} are registered for another type",
+                factory_id, class_id
+            ))),
+            None => Err(DeserializationFailure(format!(
+                "no class registered for factory id {} and class id {}",
+                factory_id, class_id
+            ))),
+        }
+    }
+}
+
+pub trait ToData {
+    fn to_data(&self) -> Result<Vec<u8>>;
+}
+
+pub trait FromData: Sized {
+    fn from_data(data: &[u8]) -> Result<Self>;
+}
+
+// bytes are taken to be serialized already and are passed through as they are
+impl ToData for [u8] {
+    fn to_data(&self) -> Result<Vec<u8>> {
+        Ok(self.to_vec())
+    }
+}
+
+impl ToData for Vec<u8> {
+    fn to_data(&self) -> Result<Vec<u8>> {
+        Ok(self.clone())
+    }
+}
+
+impl FromData for Vec<u8> {
+    fn from_data(data: &[u8]) -> Result<Self> {
+        Ok(data.to_vec())
+    }
+}
+
+// a byte array value, as opposed to bytes already holding a serialized value
+#[derive(Eq, PartialEq, Debug, Clone)]
+pub struct ByteArray(pub Vec<u8>);
+
+macro_rules! primitive {
+    ($type:ty, $type_id:expr, $write:ident, $read:ident) => {
+        impl ToData for $type {
+            fn to_data(&self) -> Result<Vec<u8>> {
+                let mut output = DataOutput::with_header($type_id);
+                output.$write(*self);
+                Ok(output.into_bytes())
+            }
+        }
+
+        impl FromData for $type {
+            fn from_data(data: &[u8]) -> Result<Self> {
+                DataInput::with_header(data, $type_id)?.$read()
+            }
+        }
+    };
+}
+
+primitive!(bool, BOOLEAN_TYPE_ID, write_bool, read_bool);
+primitive!(i32, INTEGER_TYPE_ID, write_i32, read_i32);
+primitive!(i64, LONG_TYPE_ID, write_i64, read_i64);
+primitive!(f64, DOUBLE_TYPE_ID, write_f64, read_f64);
+
+impl ToData for str {
+    fn to_data(&self) -> Result<Vec<u8>> {
+        Ok(serialize_string(self))
+    }
+}
+
+impl ToData for String {
+    fn to_data(&self) -> Result<Vec<u8>> {
+        Ok(serialize_string(self))
+    }
+}
+
+impl FromData for String {
+    fn from_data(data: &[u8]) -> Result<Self> {
+        DataInput::with_header(data, STRING_TYPE_ID)?.read_string()
+    }
+}
+
+impl ToData for ByteArray {
+    fn to_data(&self) -> Result<Vec<u8>> {
+        let mut output = DataOutput::with_header(BYTE_ARRAY_TYPE_ID);
+        output.write_bytes(&self.0);
+        Ok(output.into_bytes())
+    }
+}
+
+impl FromData for ByteArray {
+    fn from_data(data: &[u8]) -> Result<Self> {
+        Ok(ByteArray(
+            DataInput::with_header(data, BYTE_ARRAY_TYPE_ID)?.read_bytes()?,
+        ))
+    }
+}
+
+// members keep the document as is and parse it only when it is queried
+#[derive(Eq, PartialEq, Debug, Clone)]
+pub struct HazelcastJsonValue(pub String);
+
+impl ToData for HazelcastJsonValue {
+    fn to_data(&self) -> Result<Vec<u8>> {
+        let mut output = DataOutput::with_header(JSON_TYPE_ID);
+        output.write_string(&self.0);
+        Ok(output.into_bytes())
+    }
+}
+
+impl FromData for HazelcastJsonValue {
+    fn from_data(data: &[u8]) -> Result<Self> {
+        Ok(HazelcastJsonValue(
+            DataInput::with_header(data, JSON_TYPE_ID)?.read_string()?,
+        ))
+    }
+}
+
+// the unscaled value is written as the two's complement bytes of java.math.BigInteger
+impl ToData for BigInt {
+    fn to_data(&self) -> Result<Vec<u8>> {
+        let mut output = DataOutput::with_header(BIG_INTEGER_TYPE_ID);
+        output.write_bytes(&self.to_signed_bytes_be());
+        Ok(output.into_bytes())
+    }
+}
+
+impl FromData for BigInt {
+    fn from_data(data: &[u8]) -> Result<Self> {
+        let bytes = DataInput::with_header(data, BIG_INTEGER_TYPE_ID)?.read_bytes()?;
+        Ok(BigInt::from_signed_bytes_be(&bytes))
+    }
+}
+
+impl ToData for BigDecimal {
+    fn to_data(&self) -> Result<Vec<u8>> {
+        let (unscaled, scale) = self.as_bigint_and_exponent();
+        let scale: i32 = scale
+            .try_into()
+            .map_err(|_| SerializationFailure(format!("scale {} is out of range", scale)))?;
+        let mut output = DataOutput::with_header(BIG_DECIMAL_TYPE_ID);
+        output.write_bytes(&unscaled.to_signed_bytes_be());
+        output.write_i32(scale);
+        Ok(output.into_bytes())
+    }
+}
+
+impl FromData for BigDecimal {
+    fn from_data(data: &[u8]) -> Result<Self> {
+        let mut input = DataInput::with_header(data, BIG_DECIMAL_TYPE_ID)?;
+        let unscaled = BigInt::from_signed_bytes_be(&input.read_bytes()?);
+        Ok(BigDecimal::new(unscaled, input.read_i32()?.into()))
+    }
+}
+
+// the layout and type ids of java.time.LocalDate and friends, which members only know as of Hazelcast 4 - older ones
+// keep such values as they are but cannot look into them, so they are not meant for queries or entry processors
+#[cfg(feature = "temporal")]
+impl ToData for NaiveDate {
+    fn to_data(&self) -> Result<Vec<u8>> {
+        let mut output = DataOutput::with_header(LOCAL_DATE_TYPE_ID);
+        output.write_date(self);
+        Ok(output.into_bytes())
+    }
+}
+
+#[cfg(feature = "temporal")]
+impl FromData for NaiveDate {
+    fn from_data(data: &[u8]) -> Result<Self> {
+        DataInput::with_header(data, LOCAL_DATE_TYPE_ID)?.read_date()
+    }
+}
+
+#[cfg(feature = "temporal")]
+impl ToData for NaiveTime {
+    fn to_data(&self) -> Result<Vec<u8>> {
+        let mut output = DataOutput::with_header(LOCAL_TIME_TYPE_ID);
+        output.write_time(self);
+        Ok(output.into_bytes())
+    }
+}
+
+#[cfg(feature = "temporal")]
+impl FromData for NaiveTime {
+    fn from_data(data: &[u8]) -> Result<Self> {
+        DataInput::with_header(data, LOCAL_TIME_TYPE_ID)?.read_time()
+    }
+}
+
+#[cfg(feature = "temporal")]
+impl ToData for NaiveDateTime {
+    fn to_data(&self) -> Result<Vec<u8>> {
+        let mut output = DataOutput::with_header(LOCAL_DATE_TIME_TYPE_ID);
+        output.write_date(&self.date());
+        output.write_time(&self.time());
+        Ok(output.into_bytes())
+    }
+}
+
+#[cfg(feature = "temporal")]
+impl FromData for NaiveDateTime {
+    fn from_data(data: &[u8]) -> Result<Self> {
+        let mut input = DataInput::with_header(data, LOCAL_DATE_TIME_TYPE_ID)?;
+        Ok(NaiveDateTime::new(input.read_date()?, input.read_time()?))
+    }
+}
+
+impl<E: IntEnum> ToData for E {
+    fn to_data(&self) -> Result<Vec<u8>> {
+        let mut output = DataOutput::with_header(E::TYPE_ID);
+        output.write_i32(self.discriminant());
+        Ok(output.into_bytes())
+    }
+}
+
+impl<E: IntEnum> FromData for E {
+    fn from_data(data: &[u8]) -> Result<Self> {
+        let discriminant = DataInput::with_header(data, E::TYPE_ID)?.read_i32()?;
+        E::from_discriminant(discriminant)
+            .ok_or_else(|| DeserializationFailure(format!("unknown discriminant {}", discriminant)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Copy, Clone, Eq, PartialEq, Debug)]
+    enum Color {
+        Red = 1,
+        Green = 2,
+        Blue = 4,
+    }
+
+    impl IntEnum for Color {
+        const TYPE_ID: i32 = 1001;
+
+        fn discriminant(&self) -> i32 {
+            *self as i32
+        }
+
+        fn from_discriminant(discriminant: i32) -> Option<Self> {
+            match discriminant {
+                1 => Some(Color::Red),
+                2 => Some(Color::Green),
+                4 => Some(Color::Blue),
+                _ => None,
+            }
+        }
+    }
+
+    #[derive(Default, Eq, PartialEq, Debug)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    impl IdentifiedDataSerializable for Point {
+        fn factory_id(&self) -> i32 {
+            1
+        }
+
+        fn class_id(&self) -> i32 {
+            2
+        }
+
+        fn write_data(&self, output: &mut DataOutput) {
+            output.write_i32(self.x);
+            output.write_i32(self.y);
+        }
+
+        fn read_data(&mut self, input: &mut DataInput) -> Result<()> {
+            self.x = input.read_i32()?;
+            self.y = input.read_i32()?;
+            Ok(())
+        }
+    }
+
+    #[derive(Default)]
+    struct Other;
+
+    impl IdentifiedDataSerializable for Other {
+        fn factory_id(&self) -> i32 {
+            1
+        }
+
+        fn class_id(&self) -> i32 {
+            2
+        }
+
+        fn write_data(&self, _: &mut DataOutput) {}
+
+        fn read_data(&mut self, _: &mut DataInput) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn should_serialize_and_deserialize_identified_data_serializable() {
+        let service = SerializationService::new(DataSerializer::new().register::<Point>());
+        let point = Point { x: 3, y: -4 };
+
+        let bytes = service.serialize(&point);
+
+        assert_eq!(
+            bytes,
+            vec![0, 0, 0, 0, 0xFF, 0xFF, 0xFF, 0xFE, 1, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 3, 0xFF, 0xFF, 0xFF, 0xFC]
+        );
+        assert_eq!(service.deserialize::<Point>(&bytes).unwrap(), point);
+    }
+
+    #[test]
+    fn should_fail_to_deserialize_unregistered_or_other_class() {
+        let bytes = SerializationService::new(DataSerializer::new()).serialize(&Point { x: 3, y: -4 });
+
+        let unregistered = SerializationService::new(DataSerializer::new());
+        assert!(unregistered.deserialize::<Point>(&bytes).is_err());
+
+        let other = SerializationService::new(DataSerializer::new().register::<Other>());
+        assert!(other.deserialize::<Point>(&bytes).is_err());
+
+        let truncated = SerializationService::new(DataSerializer::new().register::<Point>());
+        assert!(truncated.deserialize::<Point>(&bytes[..22]).is_err());
+    }
+
+    #[test]
+    fn should_write_and_read_fields() {
+        let mut output = DataOutput::default();
+        output.write_bool(true);
+        output.write_i64(-2);
+        output.write_f64(1.5);
+        output.write_string("zażółć 𝄞");
+
+        let bytes = output.into_bytes();
+        let mut input = DataInput::new(&bytes);
+        assert!(input.read_bool().unwrap());
+        assert_eq!(input.read_i64().unwrap(), -2);
+        assert_eq!(input.read_f64().unwrap(), 1.5);
+        assert_eq!(input.read_string().unwrap(), "zażółć 𝄞");
+        assert!(input.read_bool().is_err());
+    }
+
+    #[test]
+    fn should_serialize_string_and_long_with_type_id() {
+        assert_eq!(
+            "ab".to_string().to_data().unwrap(),
+            vec![0, 0, 0, 0, 0xFF, 0xFF, 0xFF, 0xF5, 0, 0, 0, 2, b'a', b'b']
+        );
+        assert_eq!(
+            7i64.to_data().unwrap(),
+            vec![0, 0, 0, 0, 0xFF, 0xFF, 0xFF, 0xF8, 0, 0, 0, 0, 0, 0, 0, 7]
+        );
+    }
+
+    #[test]
+    fn should_round_trip_primitives() {
+        assert_eq!(String::from_data(&"zażółć".to_data().unwrap()).unwrap(), "zażółć");
+        assert_eq!(i64::from_data(&i64::MIN.to_data().unwrap()).unwrap(), i64::MIN);
+        assert_eq!(i32::from_data(&(-3i32).to_data().unwrap()).unwrap(), -3);
+        assert_eq!(f64::from_data(&2.5f64.to_data().unwrap()).unwrap(), 2.5);
+        assert!(bool::from_data(&true.to_data().unwrap()).unwrap());
+        assert_eq!(
+            ByteArray::from_data(&ByteArray(vec![1, 2]).to_data().unwrap()).unwrap(),
+            ByteArray(vec![1, 2])
+        );
+        assert_eq!(Vec::<u8>::from_data(&[1, 2]).unwrap(), vec![1, 2]);
+    }
+
+    #[test]
+    fn should_fail_to_read_primitive_of_other_type() {
+        assert!(i32::from_data(&7i64.to_data().unwrap()).is_err());
+        assert!(String::from_data(&[0, 0, 0, 0]).is_err());
+    }
+
+    #[test]
+    fn should_round_trip_json_value() {
+        let json = HazelcastJsonValue(r#"{"a":1}"#.to_string());
+
+        let data = json.to_data().unwrap();
+
+        assert_eq!(&data[4..8], &(-130i32).to_be_bytes());
+        assert_eq!(HazelcastJsonValue::from_data(&data).unwrap(), json);
+        assert!(String::from_data(&data).is_err());
+    }
+
+    #[test]
+    fn should_serialize_big_numbers_like_java() {
+        assert_eq!(
+            BigInt::from(128).to_data().unwrap(),
+            [0, 0, 0, 0, 0xFF, 0xFF, 0xFF, 0xE9, 0, 0, 0, 2, 0x00, 0x80]
+        );
+        assert_eq!(
+            "1.5".parse::<BigDecimal>().unwrap().to_data().unwrap(),
+            [0, 0, 0, 0, 0xFF, 0xFF, 0xFF, 0xE8, 0, 0, 0, 1, 0x0F, 0, 0, 0, 1]
+        );
+    }
+
+    #[test]
+    fn should_round_trip_big_numbers() {
+        for value in [
+            "0",
+            "-128",
+            "123456789012345678901234567890",
+            "-123456789012345678901234567890",
+        ]
+        .iter()
+        {
+            let value: BigInt = value.parse().unwrap();
+            assert_eq!(BigInt::from_data(&value.to_data().unwrap()).unwrap(), value);
+        }
+        for value in ["0", "1.5", "-98765432109876543210.0123456789", "0.000001", "1E+5"].iter() {
+            let value: BigDecimal = value.parse().unwrap();
+            let read = BigDecimal::from_data(&value.to_data().unwrap()).unwrap();
+            assert_eq!(read.as_bigint_and_exponent(), value.as_bigint_and_exponent());
+        }
+    }
+
+    #[test]
+    fn should_fail_to_serialize_big_decimal_with_scale_out_of_int_range() {
+        let value = BigDecimal::new(BigInt::from(1), i64::from(i32::MAX) + 1);
+
+        assert!(matches!(value.to_data(), Err(SerializationFailure(_))));
+    }
+
+    #[test]
+    fn should_compute_murmur3_hash() {
+        assert_eq!(murmur3_x86_32(b"", 0), 0);
+        assert_eq!(murmur3_x86_32(b"", 1), 0x514e_28b7);
+        assert_eq!(murmur3_x86_32(b"", 0xffff_ffff), 0x81f1_6f39);
+        assert_eq!(murmur3_x86_32(b"test", 0), 0xba6b_d213);
+        assert_eq!(murmur3_x86_32(b"Hello, world!", 0), 0xc036_3e43);
+        assert_eq!(
+            murmur3_x86_32(b"The quick brown fox jumps over the lazy dog", 0),
+            0x2e4f_f723
+        );
+    }
+
+    #[test]
+    fn should_use_partition_hash_from_data_header() {
+        let data = Data::from(vec![0, 0, 0, 42, 0xff, 0xff, 0xff, 0xf5, 9, 9, 9]);
+
+        assert_eq!(data.partition_hash(), 42);
+        assert_eq!(data.type_id(), STRING_TYPE_ID);
+    }
+
+    #[test]
+    fn should_hash_payload_without_partition_hash() {
+        let data = Data::from(serialize_string("key"));
+
+        assert_eq!(data.type_id(), STRING_TYPE_ID);
+        assert_eq!(data.bytes()[HEADER_LENGTH..], [0, 0, 0, 3, b'k', b'e', b'y']);
+        assert_eq!(
+            data.partition_hash(),
+            murmur3_x86_32(&[0, 0, 0, 3, b'k', b'e', b'y'], MURMUR_SEED) as i32
+        );
+    }
+
+    #[test]
+    fn should_not_hash_truncated_data() {
+        let data = Data::from(&[0u8, 0, 0][..]);
+
+        assert_eq!(data.partition_hash(), 0);
+        assert_eq!(data.type_id(), 0);
+    }
+
+    #[test]
+    fn should_serialize_and_deserialize_enum() {
+        for color in &[Color::Red, Color::Green, Color::Blue] {
+            let bytes = color.to_data().unwrap();
+
+            assert_eq!(bytes.len(), 12);
+            assert_eq!(&bytes[4..8], &1001i32.to_be_bytes());
+            assert_eq!(Color::from_data(&bytes).unwrap(), *color);
+        }
+    }
+
+    #[test]
+    fn should_serialize_string_like_java() {
+        let bytes = [
+            &[0, 0, 0, 0, 0xFF, 0xFF, 0xFF, 0xF5, 0, 0, 0, 9][..],
+            b"za",
+            &[0xC5, 0xBC, 0xC3, 0xB3, 0xC5, 0x82, 0xC4, 0x87], // żółć
+            b" ",
+            &[0xED, 0xA0, 0xB4, 0xED, 0xB4, 0x9E], // 𝄞 as a surrogate pair
+        ]
+        .concat();
+
+        assert_eq!(serialize_string("zażółć 𝄞"), bytes);
+        assert_eq!(String::from_data(&bytes).unwrap(), "zażółć 𝄞");
+    }
+
+    #[test]
+    fn should_fail_to_deserialize_four_byte_sequence() {
+        let bytes = [0, 0, 0, 0, 0xFF, 0xFF, 0xFF, 0xF5, 0, 0, 0, 2, 0xF0, 0x9D, 0x84, 0x9E];
+
+        match String::from_data(&bytes) {
+            Err(DeserializationFailure(message)) => assert!(message.contains("lead byte")),
+            _ => panic!("expected deserialization failure!"),
+        }
+    }
+
+    #[test]
+    fn should_serialize_string() {
+        assert_eq!(
+            serialize_string("key"),
+            vec![0, 0, 0, 0, 0xFF, 0xFF, 0xFF, 0xF5, 0, 0, 0, 3, b'k', b'e', b'y']
+        );
+    }
+
+    #[test]
+    fn should_fail_to_deserialize_enum_with_other_type_id() {
+        let mut bytes = Color::Red.to_data().unwrap();
+        bytes[7] = 0;
+
+        match Color::from_data(&bytes) {
+            Err(DeserializationFailure(message)) => assert!(message.contains("type id")),
+            _ => panic!("expected deserialization failure!"),
+        }
+    }
+
+    #[test]
+    fn should_fail_to_deserialize_enum_with_unknown_discriminant() {
+        let mut bytes = Color::Red.to_data().unwrap();
+        bytes[11] = 3;
+
+        assert!(Color::from_data(&bytes).is_err());
+        assert!(Color::from_data(&bytes[..10]).is_err());
+    }
+
+    #[cfg(feature = "temporal")]
+    #[test]
+    fn should_serialize_date_like_java() {
+        let date = NaiveDate::from_ymd_opt(2020, 2, 29).unwrap();
+
+        let bytes = date.to_data().unwrap();
+
+        assert_eq!(Data::from(bytes.clone()).type_id(), LOCAL_DATE_TYPE_ID);
+        assert_eq!(bytes[HEADER_LENGTH..], [0x00, 0x00, 0x07, 0xE4, 2, 29]);
+    }
+
+    #[cfg(feature = "temporal")]
+    #[test]
+    fn should_serialize_leap_day() {
+        let date = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+
+        assert_eq!(NaiveDate::from_data(&date.to_data().unwrap()).unwrap(), date);
+    }
+
+    #[cfg(feature = "temporal")]
+    #[test]
+    fn should_serialize_time_with_nanosecond_precision() {
+        let time = NaiveTime::from_hms_nano_opt(23, 59, 59, 999_999_999).unwrap();
+
+        let bytes = time.to_data().unwrap();
+
+        assert_eq!(Data::from(bytes.clone()).type_id(), LOCAL_TIME_TYPE_ID);
+        assert_eq!(NaiveTime::from_data(&bytes).unwrap(), time);
+    }
+
+    #[cfg(feature = "temporal")]
+    #[test]
+    fn should_serialize_date_time() {
+        let date_time = NaiveDate::from_ymd_opt(-44, 3, 15)
+            .unwrap()
+            .and_hms_nano_opt(12, 30, 0, 1)
+            .unwrap();
+
+        let bytes = date_time.to_data().unwrap();
+
+        assert_eq!(bytes.len(), HEADER_LENGTH + 13);
+        assert_eq!(Data::from(bytes.clone()).type_id(), LOCAL_DATE_TIME_TYPE_ID);
+        assert_eq!(NaiveDateTime::from_data(&bytes).unwrap(), date_time);
+    }
+
+    #[cfg(feature = "temporal")]
+    #[test]
+    fn should_fail_to_deserialize_invalid_date() {
+        let mut bytes = NaiveDate::from_ymd_opt(2023, 2, 28).unwrap().to_data().unwrap();
+        *bytes.last_mut().unwrap() = 29;
+
+        assert!(NaiveDate::from_data(&bytes).is_err());
+        assert!(NaiveDateTime::from_data(&bytes).is_err());
+    }
+}
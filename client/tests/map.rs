@@ -0,0 +1,28 @@
+#![cfg(feature = "integration-tests")]
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use hazelcast_rust_client::HazelcastClient;
+
+#[tokio::test]
+async fn should_put_get_and_remove_against_cluster() {
+    let client = HazelcastClient::new(vec!["127.0.0.1:5701".parse().unwrap()], "dev", "dev-pass")
+        .await
+        .unwrap();
+    let mut map = client.map(&map_name());
+    let (value, other) = ("value".to_string(), "other-value".to_string());
+
+    assert_eq!(map.get::<_, String>("key").await.unwrap(), None);
+    assert_eq!(map.put("key", &value).await.unwrap(), None);
+    assert_eq!(map.get("key").await.unwrap(), Some(value.clone()));
+    assert_eq!(map.put("key", &other).await.unwrap(), Some(value));
+    assert_eq!(map.remove("key").await.unwrap(), Some(other));
+    assert_eq!(map.get::<_, String>("key").await.unwrap(), None);
+
+    client.shutdown().await;
+}
+
+fn map_name() -> String {
+    let nanos = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+    format!("map-{}", nanos)
+}
@@ -0,0 +1,40 @@
+#![cfg(feature = "integration-tests")]
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use hazelcast_rust_client::HazelcastClient;
+
+#[tokio::test]
+async fn should_get_and_add_against_cluster() {
+    let client = HazelcastClient::new(vec!["127.0.0.1:5701".parse().unwrap()], "dev", "dev-pass")
+        .await
+        .unwrap();
+    let mut counter = client.pn_counter(&counter_name());
+
+    assert_eq!(counter.get().await.unwrap(), 0);
+    assert_eq!(counter.get_and_add(1).await.unwrap(), 0);
+    assert_eq!(counter.get_and_add(2).await.unwrap(), 1);
+    assert_eq!(counter.add_and_get(-1).await.unwrap(), 2);
+    assert_eq!(counter.get().await.unwrap(), 2);
+
+    client.shutdown().await;
+}
+
+#[tokio::test]
+async fn should_get_replica_count_from_cluster() {
+    let client = HazelcastClient::new(vec!["127.0.0.1:5701".parse().unwrap()], "dev", "dev-pass")
+        .await
+        .unwrap();
+    let mut counter = client.pn_counter(&counter_name());
+
+    let replica_count = counter.replica_count(true).await.unwrap();
+    assert!(replica_count > 0);
+    assert_eq!(counter.replica_count(false).await.unwrap(), replica_count);
+
+    client.shutdown().await;
+}
+
+fn counter_name() -> String {
+    let nanos = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+    format!("counter-{}", nanos)
+}
@@ -0,0 +1,37 @@
+#![cfg(all(feature = "integration-tests", feature = "tls"))]
+
+use std::{
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use hazelcast_rust_client::{ClientConfig, HazelcastClient, RoutingMode, TlsConfig};
+
+// the member is reached through the TLS proxy only, so the client must not connect to the address it reports
+#[tokio::test]
+async fn should_put_and_get_over_tls() {
+    let tls = TlsConfig::builder(certificate("ca.pem"), "localhost")
+        .client_certificate(certificate("client.pem"), certificate("client.key"))
+        .build();
+    let config = ClientConfig::builder()
+        .addresses(&["127.0.0.1:5702".parse().unwrap()])
+        .routing_mode(RoutingMode::Unisocket)
+        .tls(tls)
+        .build();
+    let client = HazelcastClient::with_config(config).await.unwrap();
+    let mut map = client.map(&map_name());
+
+    assert_eq!(map.put("key", &"value".to_string()).await.unwrap(), None);
+    assert_eq!(map.get("key").await.unwrap(), Some("value".to_string()));
+
+    client.shutdown().await;
+}
+
+fn certificate(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/certs").join(name)
+}
+
+fn map_name() -> String {
+    let nanos = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+    format!("map-{}", nanos)
+}
@@ -24,7 +24,7 @@ async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
     assert_eq!(counter.add_and_get(-1).await?, 2);
     assert_eq!(counter.get().await?, 2);
 
-    let replica_count = counter.replica_count().await?;
+    let replica_count = counter.replica_count(false).await?;
     info!("Replica count for {}: {}", counter.name(), replica_count);
 
     Ok(())
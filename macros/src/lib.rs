@@ -1,11 +1,11 @@
 extern crate proc_macro;
 
 use proc_macro2::TokenStream;
-use syn::{parse_macro_input, spanned::Spanned, Attribute, Data, DeriveInput, Fields, Lit, Meta, Type};
+use syn::{parse_macro_input, spanned::Spanned, Attribute, Data, DeriveInput, Field, Fields, Lit, Meta, Type};
 
 use quote::{quote, quote_spanned};
 
-#[proc_macro_derive(Request, attributes(r#type))]
+#[proc_macro_derive(Request, attributes(r#type, partition_id))]
 pub fn derive_request(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
 
@@ -23,16 +23,39 @@ fn request_body(input: &DeriveInput) -> TokenStream {
     let name = &input.ident;
     let (impl_generics, ty_generics, where_clause) = &input.generics.split_for_impl();
     let type_value = find_attribute_value("r#type", &input.attrs).expect("missing 'type' attribute!");
+    let partition_id_body = partition_id_body(&input.data);
 
     quote! {
         impl #impl_generics crate::messaging::Request for #name #ty_generics #where_clause {
             fn r#type() -> u16 {
                 #type_value
             }
+
+            #partition_id_body
         }
     }
 }
 
+fn partition_id_body(data: &Data) -> TokenStream {
+    match *data {
+        Data::Struct(ref data) => match data.fields {
+            Fields::Named(ref fields) => match fields.named.iter().find(|field| is_partition_id(field)) {
+                Some(field) => {
+                    let name = &field.ident;
+                    quote_spanned! {field.span() =>
+                        fn partition_id(&self) -> i32 {
+                            self.#name
+                        }
+                    }
+                }
+                None => quote! {},
+            },
+            Fields::Unnamed(_) | Fields::Unit => unimplemented!(),
+        },
+        Data::Enum(_) | Data::Union(_) => unimplemented!(),
+    }
+}
+
 #[proc_macro_derive(Writer)]
 pub fn derive_writer(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
@@ -67,12 +90,17 @@ fn length_body(data: &Data) -> TokenStream {
     match *data {
         Data::Struct(ref data) => match data.fields {
             Fields::Named(ref fields) => {
-                let recurse = fields.named.iter().map(|field| {
-                    let name = &field.ident;
-                    quote_spanned! {field.span() =>
-                        self.#name.length()
-                    }
-                });
+                let recurse = fields
+                    .named
+                    .iter()
+                    .filter(|field| !is_partition_id(field))
+                    .map(|field| {
+                        let name = &field.ident;
+                        let ty = &field.ty;
+                        quote_spanned! {field.span() =>
+                            <#ty as crate::codec::Writer>::length(&self.#name)
+                        }
+                    });
                 quote! {
                     0 #(+ #recurse)*
                 }
@@ -87,12 +115,17 @@ fn write_to_body(data: &Data) -> TokenStream {
     match *data {
         Data::Struct(ref data) => match data.fields {
             Fields::Named(ref fields) => {
-                let recurse = fields.named.iter().map(|field| {
-                    let name = &field.ident;
-                    quote_spanned! {field.span() =>
-                        self.#name.write_to(writeable);
-                    }
-                });
+                let recurse = fields
+                    .named
+                    .iter()
+                    .filter(|field| !is_partition_id(field))
+                    .map(|field| {
+                        let name = &field.ident;
+                        let ty = &field.ty;
+                        quote_spanned! {field.span() =>
+                            <#ty as crate::codec::Writer>::write_to(&self.#name, writeable);
+                        }
+                    });
                 quote! {
                     #(#recurse)*
                 }
@@ -103,7 +136,7 @@ fn write_to_body(data: &Data) -> TokenStream {
     }
 }
 
-#[proc_macro_derive(Response, attributes(r#type))]
+#[proc_macro_derive(Response, attributes(r#type, trailing))]
 pub fn derive_response(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
 
@@ -131,7 +164,7 @@ fn response_body(input: &DeriveInput) -> TokenStream {
     }
 }
 
-#[proc_macro_derive(Reader)]
+#[proc_macro_derive(Reader, attributes(trailing))]
 pub fn derive_reader(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
 
@@ -149,10 +182,10 @@ fn reader_body(input: &DeriveInput) -> TokenStream {
 
     quote! {
         impl #impl_generics crate::codec::Reader for #name #ty_generics #where_clause {
-            fn read_from(readable: &mut dyn crate::codec::Readable) -> Self {
-                #name {
+            fn read_from(readable: &mut dyn crate::codec::Readable) -> crate::codec::DecodeResult<Self> {
+                Ok(#name {
                     #read_from_body
-                }
+                })
             }
         }
     }
@@ -163,11 +196,17 @@ fn read_from_body(data: &Data) -> TokenStream {
         Data::Struct(ref data) => match data.fields {
             Fields::Named(ref fields) => {
                 let recurse = fields.named.iter().map(|field| match &field.ty {
+                    Type::Path(_) if is_trailing(field) => {
+                        let name = &field.ident;
+                        quote_spanned! {field.span() =>
+                            #name: crate::codec::read_if_present(readable)?,
+                        }
+                    }
                     Type::Path(type_path) => {
                         let name = &field.ident;
                         let type_name = &type_path.path.segments.first().expect("missing first segment!").ident;
                         quote_spanned! {field.span() =>
-                            #name: #type_name::read_from(readable),
+                            #name: #type_name::read_from(readable)?,
                         }
                     }
                     Type::Array(_)
@@ -196,7 +235,19 @@ fn read_from_body(data: &Data) -> TokenStream {
     }
 }
 
-fn find_attribute_value(name: &str, attributes: &Vec<Attribute>) -> Option<Lit> {
+fn is_partition_id(field: &Field) -> bool {
+    field
+        .attrs
+        .iter()
+        .any(|attribute| attribute.path.is_ident("partition_id"))
+}
+
+// fields added in a later protocol version trail the message, which servers speaking an earlier one end before them
+fn is_trailing(field: &Field) -> bool {
+    field.attrs.iter().any(|attribute| attribute.path.is_ident("trailing"))
+}
+
+fn find_attribute_value(name: &str, attributes: &[Attribute]) -> Option<Lit> {
     attributes
         .iter()
         .map(|attribute| attribute.parse_meta().expect("unable to parse attribute!"))
@@ -208,6 +259,6 @@ fn find_attribute_value(name: &str, attributes: &Vec<Attribute>) -> Option<Lit>
                     None
                 }
             }
-            Meta::Path(_) | Meta::List(_) => unimplemented!(),
+            Meta::Path(_) | Meta::List(_) => None,
         })
 }